@@ -0,0 +1,100 @@
+use {
+    crate::{
+        rt::{
+            camera::Camera,
+            color::{Color, WHITE},
+            cylinder::Cylinder,
+            group::Group,
+            material::Material,
+            matrix::Matrix,
+            object::Object,
+            plane::Plane,
+            point_light::PointLight,
+            shape::Shape,
+            sphere::Sphere,
+            to_ppm::ToPPM,
+            world::World,
+        },
+        tuple::{point::Point, vector::Vector},
+    },
+    std::f64::consts::PI,
+};
+
+fn hexagon_material() -> Material {
+    let mut material = Material::default();
+    material.set_color(Color::new(0.8, 0.3, 0.2));
+    material.set_diffuse(0.7);
+    material.set_specular(0.3);
+    material
+}
+
+fn hexagon_corner() -> Object {
+    Sphere::new(
+        Point::default(),
+        Matrix::<4>::translation(0.0, 0.0, -1.0) * Matrix::<4>::scaling(0.25, 0.25, 0.25),
+        hexagon_material(),
+    )
+    .into()
+}
+
+fn hexagon_edge() -> Object {
+    Cylinder::new(
+        Matrix::<4>::translation(0.0, 0.0, -1.0)
+            * Matrix::<4>::rotation_y(-PI / 6.0)
+            * Matrix::<4>::rotation_z(-PI / 2.0)
+            * Matrix::<4>::scaling(0.25, 1.0, 0.25),
+        hexagon_material(),
+        0.0,
+        1.0,
+        false,
+    )
+    .into()
+}
+
+/// Each side of the hexagon is a group made of a corner and an edge,
+/// rotated into place around the y axis.
+fn hexagon_side(n: u8) -> Object {
+    Group::new(
+        Matrix::<4>::rotation_y(f64::from(n) * PI / 3.0),
+        vec![hexagon_corner(), hexagon_edge()],
+    )
+    .into()
+}
+
+pub fn hexagon() -> Result<(), std::io::Error> {
+    let mut floor = Plane::default();
+    let mut floor_material = Material::default();
+    floor_material.set_color(Color::new(0.9, 0.9, 0.9));
+    floor_material.set_specular(0.0);
+    floor.set_material(floor_material);
+    floor.set_transform(Matrix::<4>::translation(0.0, -0.5, 0.0));
+
+    // The whole hexagon is tilted towards the camera with a single transform.
+    let hexagon = Group::new(
+        Matrix::<4>::translation(0.0, 0.5, 0.0) * Matrix::<4>::rotation_x(-PI / 6.0),
+        (0..6).map(hexagon_side).collect(),
+    );
+
+    let world = World::new(
+        Some(vec![floor.into(), hexagon.into()]),
+        vec![PointLight::new(Point::new(-10.0, 10.0, -10.0), WHITE).into()],
+    );
+
+    let camera = Camera::new(
+        1280.0,
+        720.0,
+        PI / 3.0,
+        Some(Matrix::<4>::view_transform(
+            &Point::new(0.0, 2.5, -4.0),
+            &Point::new(0.0, 0.0, 0.0),
+            &Vector::new(0.0, 1.0, 0.0),
+        )),
+    );
+
+    let image = camera.render(&world);
+
+    let ppm = image.to_ppm();
+    ppm.save_to_disk("src/drawings/ppms/hexagon.ppm")?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 pub mod clock;
+pub mod hexagon;
 pub mod plane;
 pub mod projectile;
 pub mod ray_sphere;
@@ -149,10 +149,7 @@ pub fn spheres() -> Result<(), std::io::Error> {
             fifth_pane.into(),
             sixth_pane.into(),
         ]),
-        Some(PointLight::new(
-            Point::new(0.0, 1.0, -2.5),
-            Color::new(1.0, 1.0, 1.0),
-        )),
+        vec![PointLight::new(Point::new(0.0, 1.0, -2.5), Color::new(1.0, 1.0, 1.0)).into()],
     );
 
     let camera = Camera::new(
@@ -2,6 +2,7 @@ use crate::{
     rt::{
         canvas::Canvas,
         color::{Color, WHITE},
+        light::Light,
         material::Material,
         object::Object,
         point_light::PointLight,
@@ -48,7 +49,7 @@ pub fn ray_sphere_hit() -> Result<(), std::io::Error> {
     // Light source
     let light_position = Point::new(-10.0, 10.0, -10.0);
     let light_color = WHITE;
-    let light = PointLight::new(light_position, light_color);
+    let light: Light = PointLight::new(light_position, light_color).into();
 
     for y in 0..canvas.height() {
         // top = half, bottom = -half
@@ -65,7 +66,7 @@ pub fn ray_sphere_hit() -> Result<(), std::io::Error> {
             if let Some(xs) = intersections {
                 let hit = &xs[0];
                 let point = r.position(hit.t());
-                let normal = hit.object().normal_at(&point);
+                let normal = hit.object().normal_at(&point, hit);
                 let eye = -r.direction();
                 let color = hit.object().material().lighting(
                     hit.object(),
@@ -73,7 +74,7 @@ pub fn ray_sphere_hit() -> Result<(), std::io::Error> {
                     &point,
                     &eye,
                     &normal,
-                    false,
+                    WHITE,
                 );
                 canvas.write_pixel(x, y, color);
             }
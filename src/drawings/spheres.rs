@@ -102,10 +102,7 @@ pub fn spheres() -> Result<(), std::io::Error> {
             right.into(),
             left.into(),
         ]),
-        Some(PointLight::new(
-            Point::new(-10.0, 15.0, -10.0),
-            Color::new(1.0, 1.0, 1.0),
-        )),
+        vec![PointLight::new(Point::new(-10.0, 15.0, -10.0), Color::new(1.0, 1.0, 1.0)).into()],
     );
 
     let camera = Camera::new(
@@ -29,4 +29,7 @@ fn main() {
     //if let Err(e) = drawings::plane::spheres() {
     //    println!("{:#?}", e);
     //}
+    //if let Err(e) = drawings::hexagon::hexagon() {
+    //    println!("{:#?}", e);
+    //}
 }
@@ -0,0 +1,141 @@
+use super::{
+    color::{Color, BLACK},
+    random::Random,
+};
+
+/// How a `Camera` samples each pixel.
+///
+/// Sample positions are given as offsets from the top left corner of the pixel, in [0, 1].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AntiAliasing {
+    /// A single sample through the center of the pixel.
+    #[default]
+    None,
+    /// `n` x `n` samples through the centers of a regular grid of sub-pixels.
+    Grid(usize),
+    /// `grid` x `grid` samples, each at a random position inside its cell of a regular grid of sub-pixels.
+    /// The positions only depend on `seed` and on the pixel, so renders are reproducible.
+    Jittered { grid: usize, seed: u64 },
+    /// Samples the four corners of the pixel, and only subdivides it into four quadrants (sampled the same way)
+    /// when the corner colors differ by more than `threshold` on any channel, up to `max_depth` times.
+    Adaptive { threshold: f64, max_depth: usize },
+}
+
+impl AntiAliasing {
+    /// Returns the color of the pixel (`pixel_x`, `pixel_y`) by averaging the colors returned by `sample`,
+    /// which is called with sub-pixel offsets.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the grid size of `Grid` or `Jittered` is 0, or does not fit in a `u32`.
+    pub fn sample(
+        &self,
+        pixel_x: usize,
+        pixel_y: usize,
+        sample: impl Fn(f64, f64) -> Color,
+    ) -> Color {
+        match *self {
+            Self::None => sample(0.5, 0.5),
+            Self::Grid(n) => Self::average_grid(n, |_, _| (0.5, 0.5), sample),
+            Self::Jittered { grid, seed } => {
+                let mut random = Random::new(Self::pixel_seed(seed, pixel_x, pixel_y));
+                let mut jitter = |_, _| (random.next_f64(), random.next_f64());
+                Self::average_grid(grid, &mut jitter, sample)
+            }
+            Self::Adaptive {
+                threshold,
+                max_depth,
+            } => {
+                let corners = [
+                    sample(0.0, 0.0),
+                    sample(1.0, 0.0),
+                    sample(0.0, 1.0),
+                    sample(1.0, 1.0),
+                ];
+                Self::refine(&sample, threshold, max_depth, (0.0, 0.0), 1.0, corners)
+            }
+        }
+    }
+
+    /// Averages `n` x `n` samples, taken in each cell of a regular grid at the position (relative to the cell)
+    /// returned by `position`.
+    fn average_grid(
+        n: usize,
+        mut position: impl FnMut(u32, u32) -> (f64, f64),
+        sample: impl Fn(f64, f64) -> Color,
+    ) -> Color {
+        assert!(n > 0, "Anti-aliasing grid size must be at least 1");
+        let n = u32::try_from(n).expect("Anti-aliasing grid size must fit in a u32");
+        let cell_size = 1.0 / f64::from(n);
+        let mut color = BLACK;
+        for j in 0..n {
+            for i in 0..n {
+                let (dx, dy) = position(i, j);
+                color = color
+                    + sample(
+                        (f64::from(i) + dx) * cell_size,
+                        (f64::from(j) + dy) * cell_size,
+                    );
+            }
+        }
+        color * (1.0 / (f64::from(n) * f64::from(n)))
+    }
+
+    /// Adaptive supersampling of the square going from `origin` to `origin` + (`size`, `size`), whose
+    /// corner colors are `corners` (top left, top right, bottom left, bottom right).
+    fn refine(
+        sample: &impl Fn(f64, f64) -> Color,
+        threshold: f64,
+        depth: usize,
+        origin: (f64, f64),
+        size: f64,
+        corners: [Color; 4],
+    ) -> Color {
+        let [top_left, top_right, bottom_left, bottom_right] = corners;
+        if depth == 0 || Self::max_difference(&corners) <= threshold {
+            return (top_left + top_right + bottom_left + bottom_right) * 0.25;
+        }
+
+        let (x, y) = origin;
+        let half = size / 2.0;
+        let top = sample(x + half, y);
+        let left = sample(x, y + half);
+        let center = sample(x + half, y + half);
+        let right = sample(x + size, y + half);
+        let bottom = sample(x + half, y + size);
+
+        let quadrants = [
+            ((x, y), [top_left, top, left, center]),
+            ((x + half, y), [top, top_right, center, right]),
+            ((x, y + half), [left, center, bottom_left, bottom]),
+            ((x + half, y + half), [center, right, bottom, bottom_right]),
+        ];
+        quadrants
+            .into_iter()
+            .fold(BLACK, |color, (origin, corners)| {
+                color + Self::refine(sample, threshold, depth - 1, origin, half, corners)
+            })
+            * 0.25
+    }
+
+    /// Largest difference between two of the `colors`, on any channel.
+    fn max_difference(colors: &[Color]) -> f64 {
+        let channel_range = |channel: fn(&Color) -> f64| {
+            let (min, max) = colors
+                .iter()
+                .map(channel)
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), c| {
+                    (min.min(c), max.max(c))
+                });
+            max - min
+        };
+        channel_range(Color::red)
+            .max(channel_range(Color::green))
+            .max(channel_range(Color::blue))
+    }
+
+    /// Mixes the pixel coordinates into the seed, so that every pixel gets its own sequence of jitters.
+    fn pixel_seed(seed: u64, pixel_x: usize, pixel_y: usize) -> u64 {
+        Random::new(seed ^ ((pixel_y as u64) << 32 | pixel_x as u64)).next_u64()
+    }
+}
@@ -0,0 +1,163 @@
+use {
+    super::{
+        attenuation::Attenuation,
+        color::Color,
+        light_source::{LightSample, LightSource},
+        random::Random,
+    },
+    crate::tuple::{point::Point, vector::Vector},
+};
+
+/// A rectangular light, made of `usteps` x `vsteps` cells. The light is sampled once per cell,
+/// either at the center of the cell or, when jittered, at a random position inside it.
+#[derive(PartialEq, Debug)]
+pub struct AreaLight {
+    corner: Point,
+    /// Edge of a single cell along the first side of the light.
+    uvec: Vector,
+    usteps: usize,
+    /// Edge of a single cell along the second side of the light.
+    vvec: Vector,
+    vsteps: usize,
+    intensity: Color,
+    /// Center of the light.
+    position: Point,
+    /// Seed of the jitter applied to the sample in each cell, if any.
+    jitter: Option<u64>,
+    attenuation: Attenuation,
+}
+
+impl AreaLight {
+    /// Creates a light whose sides go from `corner` to `corner` + `side_along_u` and `corner` + `side_along_v`,
+    /// split into `steps_along_u` and `steps_along_v` cells respectively.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `steps_along_u` or `steps_along_v` is 0, or does not fit in a `u32`.
+    pub fn new(
+        corner: Point,
+        side_along_u: Vector,
+        steps_along_u: usize,
+        side_along_v: Vector,
+        steps_along_v: usize,
+        intensity: Color,
+    ) -> Self {
+        assert!(
+            steps_along_u > 0 && steps_along_v > 0,
+            "An area light must have at least one cell"
+        );
+        let position = &corner + (&side_along_u / 2.0) + (&side_along_v / 2.0);
+        Self {
+            corner,
+            uvec: side_along_u / f64::from(Self::checked_steps(steps_along_u)),
+            usteps: steps_along_u,
+            vvec: side_along_v / f64::from(Self::checked_steps(steps_along_v)),
+            vsteps: steps_along_v,
+            intensity,
+            position,
+            jitter: None,
+            attenuation: Attenuation::None,
+        }
+    }
+
+    /// Samples each cell at a random position instead of its center. The positions only depend on `seed`
+    /// and on the point being lit, so renders are reproducible.
+    pub const fn with_jitter(mut self, seed: u64) -> Self {
+        self.jitter = Some(seed);
+        self
+    }
+
+    /// Makes the light fade with distance.
+    pub const fn with_attenuation(mut self, attenuation: Attenuation) -> Self {
+        self.attenuation = attenuation;
+        self
+    }
+
+    pub const fn corner(&self) -> &Point {
+        &self.corner
+    }
+
+    pub const fn uvec(&self) -> &Vector {
+        &self.uvec
+    }
+
+    pub const fn usteps(&self) -> usize {
+        self.usteps
+    }
+
+    pub const fn vvec(&self) -> &Vector {
+        &self.vvec
+    }
+
+    pub const fn vsteps(&self) -> usize {
+        self.vsteps
+    }
+
+    pub const fn sample_count(&self) -> usize {
+        self.usteps * self.vsteps
+    }
+
+    pub const fn position(&self) -> &Point {
+        &self.position
+    }
+
+    pub const fn jitter(&self) -> Option<u64> {
+        self.jitter
+    }
+
+    /// Returns the point of the cell (`u`, `v`) at (`offset_u`, `offset_v`) from its corner,
+    /// offsets being in [0, 1].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `u` or `v` does not fit in a `u32`.
+    pub fn point_on_light(&self, u: usize, v: usize, offset_u: f64, offset_v: f64) -> Point {
+        &self.corner
+            + (&self.uvec * (f64::from(Self::checked_steps(u)) + offset_u))
+            + (&self.vvec * (f64::from(Self::checked_steps(v)) + offset_v))
+    }
+
+    /// Returns one sample per cell, to light `point`.
+    pub fn sample_points(&self, point: &Point) -> Vec<Point> {
+        let mut random = self.jitter.map(|seed| {
+            // Mix the point being lit into the seed, so that neighbouring points get different jitters
+            // and the penumbra looks like noise rather than bands.
+            let seed = [point.x(), point.y(), point.z()]
+                .iter()
+                .fold(seed, |seed, c| Random::new(seed ^ c.to_bits()).next_u64());
+            Random::new(seed)
+        });
+        let mut points = Vec::with_capacity(self.sample_count());
+        for v in 0..self.vsteps {
+            for u in 0..self.usteps {
+                let (offset_u, offset_v) = random
+                    .as_mut()
+                    .map_or((0.5, 0.5), |r| (r.next_f64(), r.next_f64()));
+                points.push(self.point_on_light(u, v, offset_u, offset_v));
+            }
+        }
+        points
+    }
+
+    fn checked_steps(steps: usize) -> u32 {
+        u32::try_from(steps)
+            .expect("An area light cannot have more than u32::MAX cells along a side")
+    }
+}
+
+impl LightSource for AreaLight {
+    fn intensity(&self) -> &Color {
+        &self.intensity
+    }
+
+    fn samples(&self, point: &Point) -> Vec<LightSample> {
+        self.sample_points(point)
+            .iter()
+            .map(|position| LightSample::towards(point, position, self.intensity))
+            .collect()
+    }
+
+    fn attenuation(&self) -> Attenuation {
+        self.attenuation
+    }
+}
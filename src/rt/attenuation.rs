@@ -0,0 +1,87 @@
+use crate::float::epsilon::EPSILON;
+
+/// How the intensity of a light decreases with the distance to the lit point.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Attenuation {
+    /// The intensity is the same at any distance.
+    #[default]
+    None,
+    /// The intensity is divided by the square of the distance, as for a physical point light.
+    InverseSquare,
+    /// The intensity is divided by `constant` + `linear` * distance + `quadratic` * distance².
+    Coefficients(AttenuationCoefficients),
+}
+
+impl Attenuation {
+    /// Creates an attenuation dividing the intensity by `constant` + `linear` * distance + `quadratic` * distance².
+    ///
+    /// # Panics
+    ///
+    /// Will panic if a coefficient is negative or not finite, or if they are all 0.
+    pub fn coefficients(constant: f64, linear: f64, quadratic: f64) -> Self {
+        Self::Coefficients(AttenuationCoefficients::new(constant, linear, quadratic))
+    }
+
+    /// Returns the factor to apply to the intensity of a light reaching a point at `distance` from it.
+    ///
+    /// Distances shorter than `EPSILON` are treated as `EPSILON`, so that a point on the light itself
+    /// does not get an infinite intensity.
+    pub fn factor(&self, distance: f64) -> f64 {
+        let distance = distance.max(EPSILON);
+        match *self {
+            Self::None => 1.0,
+            Self::InverseSquare => 1.0 / (distance * distance),
+            Self::Coefficients(coefficients) => {
+                1.0 / coefficients.quadratic.mul_add(
+                    distance * distance,
+                    coefficients.linear.mul_add(distance, coefficients.constant),
+                )
+            }
+        }
+    }
+}
+
+/// Coefficients of `Attenuation::Coefficients`, checked when they are created so that the attenuation
+/// factor is always finite and positive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttenuationCoefficients {
+    constant: f64,
+    linear: f64,
+    quadratic: f64,
+}
+
+impl AttenuationCoefficients {
+    /// # Panics
+    ///
+    /// Will panic if a coefficient is negative or not finite, or if they are all 0.
+    pub fn new(constant: f64, linear: f64, quadratic: f64) -> Self {
+        let coefficients = [constant, linear, quadratic];
+        assert!(
+            coefficients
+                .iter()
+                .all(|coefficient| coefficient.is_finite() && *coefficient >= 0.0),
+            "Attenuation coefficients must be finite and non-negative"
+        );
+        assert!(
+            coefficients.iter().any(|coefficient| *coefficient > 0.0),
+            "Attenuation coefficients cannot all be 0"
+        );
+        Self {
+            constant,
+            linear,
+            quadratic,
+        }
+    }
+
+    pub const fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    pub const fn linear(&self) -> f64 {
+        self.linear
+    }
+
+    pub const fn quadratic(&self) -> f64 {
+        self.quadratic
+    }
+}
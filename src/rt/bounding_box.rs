@@ -0,0 +1,137 @@
+use {
+    super::matrix::Matrix,
+    crate::{approx_eq::ApproxEq, tuple::point::Point},
+    std::ops::{Add, AddAssign},
+};
+
+/// Axis-aligned bounding box. Boxes can be infinite along some axes (for instance for planes),
+/// and are empty when `min` is greater than `max`.
+#[derive(Debug, Clone)]
+pub struct BoundingBox {
+    min: Point,
+    max: Point,
+}
+
+impl PartialEq for BoundingBox {
+    /// Points cannot be compared when their coordinates are infinite
+    /// (since `f64::INFINITY - f64::INFINITY` is `NaN`), so we compare the coordinates ourselves.
+    fn eq(&self, rhs: &Self) -> bool {
+        (0..3).all(|axis| {
+            [
+                (self.min[axis], rhs.min[axis]),
+                (self.max[axis], rhs.max[axis]),
+            ]
+            .into_iter()
+            .all(|(a, b)| a == b || a.approx_eq(b))
+        })
+    }
+}
+
+impl BoundingBox {
+    pub const fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a box that contains nothing, and that can be grown by adding points or other boxes to it.
+    pub const fn empty() -> Self {
+        Self {
+            min: Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            max: Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+        }
+    }
+
+    pub const fn min(&self) -> &Point {
+        &self.min
+    }
+
+    pub const fn max(&self) -> &Point {
+        &self.max
+    }
+
+    /// Returns true if the box is finite along all axes.
+    pub fn is_finite(&self) -> bool {
+        (0..3).all(|axis| self.min[axis].is_finite() && self.max[axis].is_finite())
+    }
+
+    pub fn centroid(&self) -> Point {
+        Point::new(
+            f64::midpoint(self.min.x(), self.max.x()),
+            f64::midpoint(self.min.y(), self.max.y()),
+            f64::midpoint(self.min.z(), self.max.z()),
+        )
+    }
+
+    /// Grows the box so that it contains the point.
+    pub fn add_point(&mut self, point: &Point) {
+        for axis in 0..3 {
+            self.min[axis] = self.min[axis].min(point[axis]);
+            self.max[axis] = self.max[axis].max(point[axis]);
+        }
+    }
+
+    pub fn contains_point(&self, point: &Point) -> bool {
+        (0..3).all(|axis| self.min[axis] <= point[axis] && point[axis] <= self.max[axis])
+    }
+
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains_point(&other.min) && self.contains_point(&other.max)
+    }
+
+    /// Returns the box containing the eight corners of this box once transformed.
+    /// Infinite components are handled so that an infinite extent never turns into `NaN`:
+    /// zero entries of the matrix are skipped, and an axis on which infinities of opposite
+    /// signs are summed becomes infinite in both directions.
+    pub fn transform(&self, transform: &Matrix<4>) -> Self {
+        let mut transformed = Self::empty();
+        for corner in 0..8 {
+            let point = Point::new(
+                if corner & 1 == 0 {
+                    self.min.x()
+                } else {
+                    self.max.x()
+                },
+                if corner & 2 == 0 {
+                    self.min.y()
+                } else {
+                    self.max.y()
+                },
+                if corner & 4 == 0 {
+                    self.min.z()
+                } else {
+                    self.max.z()
+                },
+            );
+            for axis in 0..3 {
+                let value = (0..4)
+                    .filter(|&column| transform[[axis, column]] != 0.0)
+                    .map(|column| transform[[axis, column]] * point[column])
+                    .sum::<f64>();
+                if value.is_nan() {
+                    transformed.min[axis] = f64::NEG_INFINITY;
+                    transformed.max[axis] = f64::INFINITY;
+                } else {
+                    transformed.min[axis] = transformed.min[axis].min(value);
+                    transformed.max[axis] = transformed.max[axis].max(value);
+                }
+            }
+        }
+        transformed
+    }
+}
+
+impl AddAssign<&Self> for BoundingBox {
+    /// Grows the box so that it contains the other box.
+    fn add_assign(&mut self, rhs: &Self) {
+        self.add_point(&rhs.min);
+        self.add_point(&rhs.max);
+    }
+}
+
+impl Add<&BoundingBox> for BoundingBox {
+    type Output = Self;
+
+    fn add(mut self, rhs: &Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
@@ -0,0 +1,110 @@
+use super::{
+    bounding_box::BoundingBox,
+    object::Object,
+    ray::{Intersect, Ray},
+    shape::Shape,
+};
+
+/// Maximum number of objects in a leaf of the hierarchy.
+pub const BVH_LEAF_SIZE: usize = 4;
+
+#[derive(Debug)]
+enum BvhNode {
+    /// Indices of the objects bounded by the node.
+    Leaf(Vec<usize>),
+    /// Indices of the child nodes.
+    Branch(usize, usize),
+}
+
+/// Bounding volume hierarchy over a list of objects (the objects of a `World` or the children of a `Group`).
+/// Objects are recursively split in two halves along the axis on which they are the most spread out,
+/// so that a ray missing the box bounding a node can skip all the objects below it.
+/// The hierarchy only stores indices into the list it was built from, so it must be rebuilt
+/// whenever the list (or the transform of one of its objects) changes.
+#[derive(Debug)]
+pub struct Bvh {
+    /// `nodes[0]` is the root, if any object is bounded.
+    nodes: Vec<(BoundingBox, BvhNode)>,
+    /// Objects with infinite bounds (like planes) cannot be split, so they are always tested.
+    unbounded: Vec<usize>,
+    bounds: BoundingBox,
+}
+
+impl Bvh {
+    pub fn new(objects: &[Object]) -> Self {
+        let bounds: Vec<BoundingBox> = objects.iter().map(Shape::parent_space_bounds).collect();
+        let (mut bounded, unbounded): (Vec<usize>, Vec<usize>) =
+            (0..objects.len()).partition(|&index| bounds[index].is_finite());
+        let mut bvh = Self {
+            nodes: Vec::new(),
+            unbounded,
+            bounds: bounds
+                .iter()
+                .fold(BoundingBox::empty(), |total, bounds| total + bounds),
+        };
+        if !bounded.is_empty() {
+            bvh.build_node(&mut bounded, &bounds);
+        }
+        bvh
+    }
+
+    /// Returns the index of the node created for `indices`.
+    fn build_node(&mut self, indices: &mut [usize], bounds: &[BoundingBox]) -> usize {
+        let node_bounds = indices
+            .iter()
+            .fold(BoundingBox::empty(), |total, &index| total + &bounds[index]);
+        let node_index = self.nodes.len();
+        if indices.len() <= BVH_LEAF_SIZE {
+            self.nodes
+                .push((node_bounds, BvhNode::Leaf(indices.to_vec())));
+            return node_index;
+        }
+        // Reserve the slot of this node so that it comes before its children.
+        self.nodes.push((node_bounds, BvhNode::Leaf(Vec::new())));
+
+        let mut centroids = BoundingBox::empty();
+        for &index in indices.iter() {
+            centroids.add_point(&bounds[index].centroid());
+        }
+        let axis = (0..3)
+            .map(|axis| (axis, centroids.max()[axis] - centroids.min()[axis]))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map_or(0, |(axis, _)| axis);
+        indices.sort_by(|&a, &b| bounds[a].centroid()[axis].total_cmp(&bounds[b].centroid()[axis]));
+
+        let (left, right) = indices.split_at_mut(indices.len() / 2);
+        let left = self.build_node(left, bounds);
+        let right = self.build_node(right, bounds);
+        self.nodes[node_index].1 = BvhNode::Branch(left, right);
+        node_index
+    }
+
+    /// Returns the box bounding all the objects.
+    pub const fn bounds(&self) -> &BoundingBox {
+        &self.bounds
+    }
+
+    /// Returns the indices, in ascending order, of the objects whose bounding box is intersected
+    /// by the ray, as well as those of the unbounded objects. Keeping the original order makes the
+    /// intersections identical to the ones obtained by testing every object.
+    pub fn candidates(&self, ray: &Ray) -> Vec<usize> {
+        let mut candidates = self.unbounded.clone();
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+        while let Some(node_index) = stack.pop() {
+            let (bounds, node) = &self.nodes[node_index];
+            if ray.intersect(bounds).is_none() {
+                continue;
+            }
+            match node {
+                BvhNode::Leaf(indices) => candidates.extend(indices),
+                BvhNode::Branch(left, right) => stack.extend([right, left]),
+            }
+        }
+        candidates.sort_unstable();
+        candidates
+    }
+}
@@ -1,12 +1,38 @@
 use {
     super::{
+        anti_aliasing::AntiAliasing,
         canvas::Canvas,
+        color::Color,
         matrix::Matrix,
         ray::Ray,
         world::{World, MAX_REFLECTION_DEPTH},
     },
     crate::tuple::point::Point,
+    std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+    },
 };
+
+/// Width and height (in pixels) of the square tiles handed out to the worker threads
+/// of `Camera::render_parallel`. Tiles on the right and bottom edges of the canvas may be smaller.
+pub const RENDER_TILE_SIZE: usize = 16;
+
+/// Number of threads `Camera::render_parallel` can usefully run on this machine, falling back to 1
+/// when it cannot be determined.
+pub fn available_threads() -> usize {
+    thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
+}
+
+/// A rectangular region of the canvas, from (`x0`, `y0`) included to (`x1`, `y1`) excluded.
+#[derive(Clone, Copy, Debug)]
+struct Tile {
+    x0: usize,
+    y0: usize,
+    x1: usize,
+    y1: usize,
+}
+
 /// Note: Pixel sizes are of type `f64`, even though they will always be positive integers (`usize`).
 /// This is to make the computations in `pixel_size` more accurate.
 pub struct Camera {
@@ -23,6 +49,8 @@ pub struct Camera {
     half_width: f64,
     half_height: f64,
     pixel_size: f64,
+    /// How each pixel is sampled.
+    anti_aliasing: AntiAliasing,
 }
 
 impl Camera {
@@ -37,9 +65,15 @@ impl Camera {
             half_width,
             half_height,
             pixel_size,
+            anti_aliasing: AntiAliasing::default(),
         }
     }
 
+    pub fn with_anti_aliasing(mut self, anti_aliasing: AntiAliasing) -> Self {
+        self.set_anti_aliasing(anti_aliasing);
+        self
+    }
+
     pub const fn hsize(&self) -> f64 {
         self.hsize
     }
@@ -103,10 +137,40 @@ impl Camera {
         self.pixel_size
     }
 
+    pub const fn anti_aliasing(&self) -> &AntiAliasing {
+        &self.anti_aliasing
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if the grid size of `AntiAliasing::Grid` or `AntiAliasing::Jittered` is 0.
+    pub fn set_anti_aliasing(&mut self, anti_aliasing: AntiAliasing) {
+        if let AntiAliasing::Grid(n) | AntiAliasing::Jittered { grid: n, .. } = anti_aliasing {
+            assert!(n > 0, "Anti-aliasing grid size must be at least 1");
+        }
+        self.anti_aliasing = anti_aliasing;
+    }
+
     pub fn ray_for_pixel(&self, pixel_x: f64, pixel_y: f64) -> Ray {
-        // The offset from the edge of the canvas to the pixel's center.
-        let x_offset = (pixel_x + 0.5) * self.pixel_size;
-        let y_offset = (pixel_y + 0.5) * self.pixel_size;
+        self.ray_for_subpixel(pixel_x, pixel_y, 0.5, 0.5)
+    }
+
+    /// Same as `ray_for_pixel`, but the ray goes through the point of the pixel at (`offset_x`, `offset_y`)
+    /// from its top left corner, instead of its center. Offsets are in pixels, usually in [0, 1].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the transform of the camera is not invertible.
+    pub fn ray_for_subpixel(
+        &self,
+        pixel_x: f64,
+        pixel_y: f64,
+        offset_x: f64,
+        offset_y: f64,
+    ) -> Ray {
+        // The offset from the edge of the canvas to the sample's position in the pixel.
+        let x_offset = (pixel_x + offset_x) * self.pixel_size;
+        let y_offset = (pixel_y + offset_y) * self.pixel_size;
         // The untransformed coordinates of the pixel in world space.
         // Note: the camera looks towards -z, so +x is to the "left".
         let world_x = self.half_width - x_offset;
@@ -123,16 +187,132 @@ impl Camera {
         Ray::new(origin, direction)
     }
 
+    /// Width and height of the rendered canvas, in pixels.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn canvas_size(&self) -> (usize, usize) {
+        // `hsize` and `vsize` are whole numbers of pixels, stored as `f64` for the computations of the camera.
+        (self.hsize as usize, self.vsize as usize)
+    }
+
+    fn color_at_pixel(&self, world: &World, x: usize, y: usize) -> Color {
+        self.anti_aliasing.sample(x, y, |offset_x, offset_y| {
+            let ray = self.ray_for_subpixel(
+                f64::from(u32::try_from(x).expect("Pixel coordinates fit in a u32")),
+                f64::from(u32::try_from(y).expect("Pixel coordinates fit in a u32")),
+                offset_x,
+                offset_y,
+            );
+            world.color_at(&ray, MAX_REFLECTION_DEPTH)
+        })
+    }
+
     pub fn render(&self, world: &World) -> Canvas {
-        let (vsize, hsize) = (self.vsize as usize, self.hsize as usize);
+        let (hsize, vsize) = self.canvas_size();
+        self.render_region(world, 0, 0, hsize, vsize)
+    }
+
+    /// Renders the sub-rectangle of the image going from (`x0`, `y0`) included to (`x1`, `y1`) excluded,
+    /// into a `Canvas` of (`x1` - `x0`) x (`y1` - `y0`) pixels. Pixel (`x`, `y`) of the crop is pixel
+    /// (`x0` + `x`, `y0` + `y`) of the full image, with exactly the same color.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the region is inverted or does not fit in the camera's canvas.
+    pub fn render_region(
+        &self,
+        world: &World,
+        x0: usize,
+        y0: usize,
+        x1: usize,
+        y1: usize,
+    ) -> Canvas {
+        let (hsize, vsize) = self.canvas_size();
+        assert!(
+            x0 <= x1 && y0 <= y1 && x1 <= hsize && y1 <= vsize,
+            "Region ({x0}, {y0}) -> ({x1}, {y1}) is not within the {hsize}x{vsize} canvas"
+        );
+        let mut image = Canvas::new(x1 - x0, y1 - y0);
+        for y in y0..y1 {
+            for x in x0..x1 {
+                image.write_pixel(x - x0, y - y0, self.color_at_pixel(world, x, y));
+            }
+        }
+        image
+    }
+
+    /// Splits the canvas into tiles of `RENDER_TILE_SIZE` x `RENDER_TILE_SIZE` pixels and renders them
+    /// on `threads` worker threads. Each worker pulls the next tile to render from a shared queue
+    /// (an atomic index into the list of tiles) until the queue is empty, so that threads which get
+    /// cheap tiles simply render more of them.
+    ///
+    /// Every pixel is computed exactly as in `render`, so the resulting canvas is bit-identical
+    /// whatever the number of threads.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `threads` is 0, or if a worker thread panics.
+    pub fn render_parallel(&self, world: &World, threads: usize) -> Canvas {
+        assert!(threads > 0, "Cannot render with 0 threads");
+        let (hsize, vsize) = self.canvas_size();
+        let tiles = Self::tiles(hsize, vsize);
+        let next_tile = AtomicUsize::new(0);
+
+        let rendered_tiles = thread::scope(|scope| {
+            let workers = (0..threads.min(tiles.len()))
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut rendered = Vec::new();
+                        while let Some(&tile) = tiles.get(next_tile.fetch_add(1, Ordering::Relaxed))
+                        {
+                            rendered.push((tile, self.render_tile(world, tile)));
+                        }
+                        rendered
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("A render worker thread panicked"))
+                .collect::<Vec<_>>()
+        });
+
         let mut image = Canvas::new(hsize, vsize);
-        for y in 0..vsize - 1 {
-            for x in 0..hsize - 1 {
-                let ray = self.ray_for_pixel(x as f64, y as f64);
-                let color = world.color_at(&ray, MAX_REFLECTION_DEPTH);
-                image.write_pixel(x, y, color);
+        for (tile, colors) in rendered_tiles {
+            let mut colors = colors.into_iter();
+            for y in tile.y0..tile.y1 {
+                for x in tile.x0..tile.x1 {
+                    // `render_tile` yields exactly one color per pixel of the tile.
+                    image.write_pixel(x, y, colors.next().unwrap());
+                }
             }
         }
         image
     }
+
+    /// Renders the pixels of a `Tile` in row-major order.
+    fn render_tile(&self, world: &World, tile: Tile) -> Vec<Color> {
+        let mut colors = Vec::with_capacity((tile.x1 - tile.x0) * (tile.y1 - tile.y0));
+        for y in tile.y0..tile.y1 {
+            for x in tile.x0..tile.x1 {
+                colors.push(self.color_at_pixel(world, x, y));
+            }
+        }
+        colors
+    }
+
+    /// Covers a canvas of `hsize` x `vsize` pixels with tiles, row by row.
+    fn tiles(hsize: usize, vsize: usize) -> Vec<Tile> {
+        (0..vsize)
+            .step_by(RENDER_TILE_SIZE)
+            .flat_map(|y0| {
+                (0..hsize).step_by(RENDER_TILE_SIZE).map(move |x0| Tile {
+                    x0,
+                    y0,
+                    x1: (x0 + RENDER_TILE_SIZE).min(hsize),
+                    y1: (y0 + RENDER_TILE_SIZE).min(vsize),
+                })
+            })
+            .collect()
+    }
 }
@@ -1,10 +1,19 @@
-use super::{
-    color::{Color, BLACK},
-    ppm::{Ppm, PPM_MAX_CHARACTERS_PER_LINE, PPM_MAX_COLOR_VALUE, PPM_MIN_COLOR_VALUE},
-    to_ppm::ToPPM,
+use {
+    super::{
+        color::{Color, BLACK},
+        image_encoder::ImageEncoder,
+        ppm::{Ppm, PpmError},
+        ppm_reader::PpmReader,
+        ppm_writer::{PpmDepth, PpmEncoder},
+        to_ppm::ToPPM,
+    },
+    std::{
+        fs::{self, File},
+        io::{BufWriter, Result as IoResult, Write},
+    },
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Canvas {
     width: usize,
     height: usize,
@@ -36,6 +45,12 @@ impl Canvas {
         y * self.width + x
     }
 
+    /// Returns the pixels of the row `y`, from left to right.
+    pub fn row(&self, y: usize) -> &[Color] {
+        let start = self.pixel_index(0, y);
+        &self.pixels[start..start + self.width]
+    }
+
     pub fn write_pixel(&mut self, x: usize, y: usize, color: Color) {
         let index = self.pixel_index(x, y);
         self.pixels[index] = color;
@@ -46,6 +61,63 @@ impl Canvas {
         &self.pixels[index]
     }
 
+    /// Same as `from_ppm_bytes`, for the content of a plain (P3) PPM file held in a string.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if `data` is not a valid PPM image.
+    pub fn from_ppm(data: &str) -> Result<Self, PpmError> {
+        Self::from_ppm_bytes(data.as_bytes())
+    }
+
+    /// Creates a canvas from the content of a PPM (P3 or P6) or PFM (PF or Pf) file.
+    /// See `PpmReader` for the details of each format.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if `data` is not a valid image in one of these formats.
+    pub fn from_ppm_bytes(data: &[u8]) -> Result<Self, PpmError> {
+        PpmReader::new(data).read()
+    }
+
+    /// Same as `from_ppm_bytes`, reading the image from the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the file cannot be read or is not a valid PPM or PFM image.
+    pub fn from_ppm_file(path: &str) -> Result<Self, PpmError> {
+        Self::from_ppm_bytes(&fs::read(path)?)
+    }
+
+    /// Streams the canvas to `writer` as a binary (P6) PPM image, one row at a time.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if `writer` fails.
+    pub fn write_ppm_binary(&self, mut writer: impl Write, depth: PpmDepth) -> IoResult<()> {
+        PpmEncoder::binary(depth).encode(self, &mut writer)
+    }
+
+    /// Saves the canvas to the file `filename` as a binary (P6) PPM image.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the file cannot be created or written.
+    pub fn save_ppm_binary(&self, filename: &str, depth: PpmDepth) -> IoResult<()> {
+        self.write_ppm_binary(BufWriter::new(File::create(filename)?), depth)
+    }
+
+    /// Saves the canvas to the file `filename`, in the format of `encoder`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the file cannot be created or written, or if the canvas cannot be encoded.
+    pub fn save(&self, filename: &str, encoder: &dyn ImageEncoder) -> IoResult<()> {
+        let mut writer = BufWriter::new(File::create(filename)?);
+        encoder.encode(self, &mut writer)?;
+        writer.flush()
+    }
+
     // Bonus
     pub fn set_all_pixels_to_color(&mut self, color: &Color) {
         for pixel in &mut self.pixels {
@@ -55,76 +127,11 @@ impl Canvas {
 }
 
 impl ToPPM for Canvas {
-    fn process_color(color: f64) -> String {
-        ((color * PPM_MAX_COLOR_VALUE)
-            .clamp(PPM_MIN_COLOR_VALUE, PPM_MAX_COLOR_VALUE)
-            .ceil() as usize)
-            .to_string()
-    }
-
-    fn split_lines_too_long(pixel_data: &str) -> String {
-        let mut cleaned_pixel_data = String::with_capacity(pixel_data.len());
-        let mut it_lines = pixel_data.split('\n').peekable();
-        let mut last_line_start_index: usize = 0;
-
-        while let Some(line) = it_lines.next() {
-            let mut it_colors = line.split(' ').peekable();
-            while let Some(color) = it_colors.next() {
-                let last_line_length = cleaned_pixel_data[last_line_start_index..].len();
-                cleaned_pixel_data.push_str(color);
-                if let Some(next_color) = it_colors.peek() {
-                    // can_insert_next_color_into_line is true if we can insert a space and the next color without exceeding 70 chars
-                    // If true, insert a space, else insert a new line.
-                    let can_insert_next_color_into_line =
-                        (last_line_length + color.len() + 1 + next_color.len())
-                            < PPM_MAX_CHARACTERS_PER_LINE;
-                    if can_insert_next_color_into_line {
-                        cleaned_pixel_data.push(' ');
-                    } else {
-                        cleaned_pixel_data.push('\n');
-                        last_line_start_index = cleaned_pixel_data.len() - 1;
-                    }
-                }
-            }
-            if it_lines.peek().is_some() {
-                cleaned_pixel_data.push('\n');
-                last_line_start_index = cleaned_pixel_data.len() - 1;
-            }
-        }
-
-        cleaned_pixel_data
-    }
-
     fn build_pixel_data(&self) -> String {
-        // TODO - String::with_capacity to avoid reallocations (compute capacity: char (colors + spaces + new lines) -> take maximum possible size of pixel_data?
-        let mut pixel_data = String::new();
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let index = self.pixel_index(x, y);
-                pixel_data.push_str(&Self::process_color(self.pixels[index].red()));
-                pixel_data.push(' ');
-                pixel_data.push_str(&Self::process_color(self.pixels[index].green()));
-                pixel_data.push(' ');
-                pixel_data.push_str(&Self::process_color(self.pixels[index].blue()));
-
-                // If we haven't reached the end of the line, insert a space.
-                if x < self.width - 1 {
-                    pixel_data.push(' ');
-                }
-            }
-            pixel_data.push('\n');
-        }
-
-        Self::split_lines_too_long(&pixel_data)
+        PpmEncoder::plain().plain_pixel_data(self)
     }
 
     fn to_ppm(&self) -> Ppm {
-        Ppm::new(
-            "P3",
-            &self.width.to_string(),
-            &self.height.to_string(),
-            &PPM_MAX_COLOR_VALUE.to_string(),
-            self.build_pixel_data(),
-        )
+        PpmEncoder::plain().plain_ppm(self)
     }
 }
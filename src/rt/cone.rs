@@ -0,0 +1,170 @@
+use {
+    super::{
+        bounding_box::BoundingBox, intersection::Intersection, material::Material, matrix::Matrix,
+        shape::Shape,
+    },
+    crate::{
+        float::epsilon::EPSILON,
+        tuple::{point::Point, vector::Vector},
+    },
+};
+
+/// A double-napped cone centered on the y axis, whose two nappes meet at the origin.
+/// Its radius at any y is `y.abs()`. It can be truncated between `minimum` and `maximum`
+/// (both excluded) along the y axis, and closed with caps at both ends.
+#[derive(PartialEq, Debug)]
+pub struct Cone {
+    transform: Matrix<4>,
+    material: Material,
+    casts_shadow: bool,
+    minimum: f64,
+    maximum: f64,
+    closed: bool,
+}
+
+impl Cone {
+    pub const fn new(
+        transform: Matrix<4>,
+        material: Material,
+        minimum: f64,
+        maximum: f64,
+        closed: bool,
+    ) -> Self {
+        Self {
+            transform,
+            material,
+            casts_shadow: true,
+            minimum,
+            maximum,
+            closed,
+        }
+    }
+
+    /// Creates a cone truncated between `minimum` and `maximum`. All other fields are set to default values.
+    pub fn truncated(minimum: f64, maximum: f64, closed: bool) -> Self {
+        Self {
+            minimum,
+            maximum,
+            closed,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a cone with a given material. All other fields are set to default values.
+    pub fn with_material(material: Material) -> Self {
+        Self {
+            material,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a cone with a given transform. All other fields are set to default values.
+    pub fn with_transform(transform: Matrix<4>) -> Self {
+        Self {
+            transform,
+            ..Self::default()
+        }
+    }
+
+    pub const fn minimum(&self) -> f64 {
+        self.minimum
+    }
+
+    pub const fn maximum(&self) -> f64 {
+        self.maximum
+    }
+
+    pub const fn closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn set_minimum(&mut self, minimum: f64) {
+        self.minimum = minimum;
+    }
+
+    pub fn set_maximum(&mut self, maximum: f64) {
+        self.maximum = maximum;
+    }
+
+    pub fn set_closed(&mut self, closed: bool) {
+        self.closed = closed;
+    }
+}
+
+impl Shape for Cone {
+    fn transform(&self) -> &Matrix<4> {
+        &self.transform
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn material_mut(&mut self) -> &mut Material {
+        &mut self.material
+    }
+
+    fn set_transform(&mut self, transform: Matrix<4>) {
+        self.transform = transform;
+    }
+
+    fn casts_shadow(&self) -> bool {
+        self.casts_shadow
+    }
+
+    fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        self.casts_shadow = casts_shadow;
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    /// Points on the caps (within the radius of the cap and at the truncation bounds) have a normal
+    /// pointing along the y axis, points on the side have a normal pointing away from the y axis
+    /// and tilted according to the slope of the nappe they lie on.
+    fn normal_at(&self, point: &Point, _: &Intersection) -> Vector {
+        let object_point = &self.transform.inverse().unwrap() * point;
+        let distance = object_point.x().powi(2) + object_point.z().powi(2);
+        let object_normal = if distance < self.maximum.powi(2)
+            && object_point.y() >= self.maximum - EPSILON
+        {
+            Vector::new(0.0, 1.0, 0.0)
+        } else if distance < self.minimum.powi(2) && object_point.y() <= self.minimum + EPSILON {
+            Vector::new(0.0, -1.0, 0.0)
+        } else {
+            let y = if object_point.y() > 0.0 {
+                -distance.sqrt()
+            } else {
+                distance.sqrt()
+            };
+            Vector::new(object_point.x(), y, object_point.z())
+        };
+        let world_normal = self.transform.inverse().unwrap().transpose() * object_normal;
+
+        world_normal.normalized()
+    }
+
+    /// The radius of a cone is the largest at the truncation bound farthest from the origin.
+    fn bounds(&self) -> BoundingBox {
+        let limit = self.minimum.abs().max(self.maximum.abs());
+        BoundingBox::new(
+            Point::new(-limit, self.minimum, -limit),
+            Point::new(limit, self.maximum, limit),
+        )
+    }
+}
+
+impl Default for Cone {
+    /// Creates an infinite, open double-napped cone centered on the y axis.
+    fn default() -> Self {
+        Self {
+            transform: Matrix::<4>::identity(),
+            material: Material::default(),
+            casts_shadow: true,
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use {
+    super::{
+        bounding_box::BoundingBox, intersection::Intersection, intersections::Intersections,
+        material::Material, matrix::Matrix, object::Object, shape::Shape,
+    },
+    crate::tuple::{point::Point, vector::Vector},
+};
+
+/// Operations used to combine the two operands of a `Csg`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum CsgOperation {
+    /// Keeps every part of both operands, but removes the surfaces that lie inside the other operand.
+    Union,
+    /// Only keeps the parts where both operands overlap.
+    Intersection,
+    /// Removes the right operand from the left one.
+    Difference,
+}
+
+impl CsgOperation {
+    /// Returns true if an intersection should be kept, knowing whether it is on the left operand
+    /// (`left_hit`), and whether it occurs inside the left (`inside_left`) and right (`inside_right`) operands.
+    pub const fn intersection_allowed(
+        self,
+        left_hit: bool,
+        inside_left: bool,
+        inside_right: bool,
+    ) -> bool {
+        match self {
+            Self::Union => (left_hit && !inside_right) || (!left_hit && !inside_left),
+            Self::Intersection => (left_hit && inside_right) || (!left_hit && inside_left),
+            Self::Difference => (left_hit && !inside_right) || (!left_hit && inside_left),
+        }
+    }
+}
+
+/// Constructive Solid Geometry: combines two objects (which can themselves be groups or CSGs) with an operation.
+#[derive(PartialEq, Debug)]
+pub struct Csg {
+    operation: CsgOperation,
+    left: Box<Object>,
+    right: Box<Object>,
+    transform: Matrix<4>,
+    material: Material,
+    casts_shadow: bool,
+}
+
+impl Csg {
+    pub fn new(operation: CsgOperation, left: Object, right: Object) -> Self {
+        Self {
+            operation,
+            left: Box::new(left),
+            right: Box::new(right),
+            transform: Matrix::<4>::identity(),
+            material: Material::default(),
+            casts_shadow: true,
+        }
+    }
+
+    pub const fn operation(&self) -> CsgOperation {
+        self.operation
+    }
+
+    pub fn left(&self) -> &Object {
+        &self.left
+    }
+
+    pub fn right(&self) -> &Object {
+        &self.right
+    }
+
+    /// Returns true if the intersection is on `object` or on one of its descendants.
+    fn includes(object: &Object, intersection: &Intersection) -> bool {
+        std::ptr::eq(intersection.object(), object)
+            || intersection
+                .parents()
+                .iter()
+                .any(|&parent| std::ptr::eq(parent, object))
+    }
+
+    /// Only keeps the intersections allowed by the operation. Since intersections are sorted,
+    /// each one toggles whether the following ones are inside the operand it belongs to.
+    pub fn filter_intersections<'object>(
+        &self,
+        intersections: Intersections<'object>,
+    ) -> Intersections<'object> {
+        let (mut inside_left, mut inside_right) = (false, false);
+        let mut filtered = Vec::with_capacity(intersections.len());
+        for intersection in intersections {
+            let left_hit = Self::includes(&self.left, &intersection);
+            if self
+                .operation
+                .intersection_allowed(left_hit, inside_left, inside_right)
+            {
+                filtered.push(intersection);
+            }
+            if left_hit {
+                inside_left = !inside_left;
+            } else {
+                inside_right = !inside_right;
+            }
+        }
+
+        Intersections::new(filtered)
+    }
+}
+
+impl Shape for Csg {
+    fn transform(&self) -> &Matrix<4> {
+        &self.transform
+    }
+
+    /// The material of a CSG is not used for rendering,
+    /// each operand is rendered with its own material.
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn material_mut(&mut self) -> &mut Material {
+        &mut self.material
+    }
+
+    fn set_transform(&mut self, transform: Matrix<4>) {
+        self.transform = transform;
+    }
+
+    fn casts_shadow(&self) -> bool {
+        self.casts_shadow
+    }
+
+    fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        self.casts_shadow = casts_shadow;
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    /// Like a group, a CSG is never hit itself, intersections always refer to its operands.
+    ///
+    /// # Panics
+    ///
+    /// Always panics: a CSG has no surface of its own, so it has no normal.
+    fn normal_at(&self, _: &Point, _: &Intersection) -> Vector {
+        unreachable!("A CSG has no normal, only its operands do.")
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.left.parent_space_bounds() + &self.right.parent_space_bounds()
+    }
+}
@@ -0,0 +1,107 @@
+use {
+    super::{
+        bounding_box::BoundingBox, intersection::Intersection, material::Material, matrix::Matrix,
+        shape::Shape,
+    },
+    crate::{
+        approx_eq::ApproxEq,
+        tuple::{point::Point, vector::Vector},
+    },
+};
+
+/// An axis-aligned cube centered at the origin, extending from -1.0 to 1.0 on each axis.
+#[derive(PartialEq, Debug)]
+pub struct Cube {
+    transform: Matrix<4>,
+    material: Material,
+    casts_shadow: bool,
+}
+
+impl Cube {
+    pub const fn new(transform: Matrix<4>, material: Material) -> Self {
+        Self {
+            transform,
+            material,
+            casts_shadow: true,
+        }
+    }
+
+    /// Creates a cube with a given material. All other fields are set to default values.
+    pub fn with_material(material: Material) -> Self {
+        Self {
+            material,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a cube with a given transform. All other fields are set to default values.
+    pub fn with_transform(transform: Matrix<4>) -> Self {
+        Self {
+            transform,
+            ..Self::default()
+        }
+    }
+}
+
+impl Shape for Cube {
+    fn transform(&self) -> &Matrix<4> {
+        &self.transform
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn material_mut(&mut self) -> &mut Material {
+        &mut self.material
+    }
+
+    fn set_transform(&mut self, transform: Matrix<4>) {
+        self.transform = transform;
+    }
+
+    fn casts_shadow(&self) -> bool {
+        self.casts_shadow
+    }
+
+    fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        self.casts_shadow = casts_shadow;
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    /// The normal of a cube is the normal of the face the point lies on, which is
+    /// given by the component of the point with the largest absolute value.
+    fn normal_at(&self, point: &Point, _: &Intersection) -> Vector {
+        let object_point = &self.transform.inverse().unwrap() * point;
+        let (x, y, z) = (object_point.x(), object_point.y(), object_point.z());
+        let max_component = x.abs().max(y.abs()).max(z.abs());
+        let object_normal = if max_component.approx_eq(x.abs()) {
+            Vector::new(x, 0.0, 0.0)
+        } else if max_component.approx_eq(y.abs()) {
+            Vector::new(0.0, y, 0.0)
+        } else {
+            Vector::new(0.0, 0.0, z)
+        };
+        let world_normal = self.transform.inverse().unwrap().transpose() * object_normal;
+
+        world_normal.normalized()
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0))
+    }
+}
+
+impl Default for Cube {
+    /// Creates a cube centered at the origin, with sides of length 2.0.
+    fn default() -> Self {
+        Self {
+            transform: Matrix::<4>::identity(),
+            material: Material::default(),
+            casts_shadow: true,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use {
+    super::{
+        color::Color,
+        light_source::{LightSample, LightSource},
+    },
+    crate::tuple::{point::Point, vector::Vector},
+};
+
+/// A light infinitely far away, like the sun: it lights every point from the same direction,
+/// with the same intensity, and its shadow rays are parallel.
+#[derive(PartialEq, Debug)]
+pub struct DirectionalLight {
+    /// Direction in which the light travels.
+    direction: Vector,
+    intensity: Color,
+}
+
+impl DirectionalLight {
+    pub fn new(direction: &Vector, intensity: Color) -> Self {
+        Self {
+            direction: direction.normalized(),
+            intensity,
+        }
+    }
+
+    pub const fn direction(&self) -> &Vector {
+        &self.direction
+    }
+}
+
+impl LightSource for DirectionalLight {
+    fn intensity(&self) -> &Color {
+        &self.intensity
+    }
+
+    fn samples(&self, _point: &Point) -> Vec<LightSample> {
+        vec![LightSample::new(
+            -&self.direction,
+            f64::INFINITY,
+            self.intensity,
+        )]
+    }
+}
@@ -0,0 +1,175 @@
+use {super::random::Random, std::sync::OnceLock};
+
+/// Side of the (tiled) blue noise texture.
+const BLUE_NOISE_SIZE: usize = 32;
+const BLUE_NOISE_PIXELS: usize = BLUE_NOISE_SIZE * BLUE_NOISE_SIZE;
+// The ranks of the pixels of the texture are stored as `u16`.
+const _: () = assert!(BLUE_NOISE_PIXELS <= u16::MAX as usize + 1);
+/// Standard deviation of the Gaussian filter measuring how clustered the points of the texture are.
+const BLUE_NOISE_SIGMA: f64 = 1.5;
+const BAYER_SIZE: usize = 8;
+
+/// Noise added to colors before they are rounded to 8-bit values, which turns the banding of smooth
+/// gradients into a fine, much less visible grain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dithering {
+    #[default]
+    None,
+    /// 8 x 8 Bayer matrix: a regular, cross-hatched pattern.
+    Ordered,
+    /// 32 x 32 blue noise texture, made by the void-and-cluster method: an irregular grain without
+    /// low-frequency clumps.
+    BlueNoise,
+}
+
+impl Dithering {
+    /// Returns the offset, in [-0.5, 0.5), added to the pixel (`x`, `y`) before rounding, in units of
+    /// the smallest step between two values.
+    pub fn offset(self, x: usize, y: usize) -> f64 {
+        match self {
+            Self::None => 0.0,
+            Self::Ordered => {
+                let rank = bayer(x % BAYER_SIZE, y % BAYER_SIZE);
+                (exact_f64(rank) + 0.5) / exact_f64(BAYER_SIZE * BAYER_SIZE) - 0.5
+            }
+            Self::BlueNoise => {
+                let rank =
+                    blue_noise()[(y % BLUE_NOISE_SIZE) * BLUE_NOISE_SIZE + x % BLUE_NOISE_SIZE];
+                (f64::from(rank) + 0.5) / exact_f64(BLUE_NOISE_PIXELS) - 0.5
+            }
+        }
+    }
+}
+
+/// Rank of (`x`, `y`) in the 8 x 8 Bayer matrix: the bits of `x` ^ `y` and `y`, interleaved and reversed.
+fn bayer(x: usize, y: usize) -> usize {
+    (0..3).fold(0, |rank, bit| {
+        rank << 2 | ((x ^ y) >> bit & 1) << 1 | (y >> bit & 1)
+    })
+}
+
+/// Converts a rank or an index of the textures, which are small, to a `f64`.
+fn exact_f64(value: usize) -> f64 {
+    f64::from(u32::try_from(value).expect("The textures have fewer than u32::MAX pixels"))
+}
+
+/// Ranks of the pixels of the blue noise texture, computed once.
+fn blue_noise() -> &'static [u16] {
+    static RANKS: OnceLock<Vec<u16>> = OnceLock::new();
+    RANKS.get_or_init(void_and_cluster)
+}
+
+/// Ranks the pixels of the texture so that, for any threshold, the pixels ranked below it are spread
+/// as evenly as possible (Ulichney's void-and-cluster method).
+fn void_and_cluster() -> Vec<u16> {
+    let mut field = PointField::new();
+    // Initial pattern: a tenth of the pixels, picked at random (with a fixed seed, so that the texture
+    // and the renders are always the same).
+    let mut random = Random::new(0x5EED);
+    let initial_points = BLUE_NOISE_PIXELS / 10;
+    while field.count < initial_points {
+        let pixel = usize::try_from(random.next_u64() % BLUE_NOISE_PIXELS as u64)
+            .expect("The remainder is a pixel of the texture");
+        if !field.points[pixel] {
+            field.toggle(pixel);
+        }
+    }
+    // Move the points from the tightest clusters to the largest voids until they are evenly spread.
+    for _ in 0..BLUE_NOISE_PIXELS {
+        let cluster = field.tightest_cluster();
+        field.toggle(cluster);
+        let void = field.largest_void();
+        field.toggle(void);
+        if void == cluster {
+            break;
+        }
+    }
+
+    let mut ranks = vec![0; BLUE_NOISE_PIXELS];
+    // The points of the initial pattern get the lowest ranks, the tightest clusters being ranked last...
+    let mut removing = field.clone();
+    for rank in (0..initial_points).rev() {
+        let cluster = removing.tightest_cluster();
+        removing.toggle(cluster);
+        ranks[cluster] = u16::try_from(rank).expect("Ranks fit in a u16");
+    }
+    // ...and the other pixels are ranked in the order they fill the largest voids.
+    for rank in initial_points..BLUE_NOISE_PIXELS {
+        let void = field.largest_void();
+        field.toggle(void);
+        ranks[void] = u16::try_from(rank).expect("Ranks fit in a u16");
+    }
+    ranks
+}
+
+/// Points of the blue noise texture, with the Gaussian-weighted density of points around each pixel.
+#[derive(Clone)]
+struct PointField {
+    points: Vec<bool>,
+    count: usize,
+    density: Vec<f64>,
+    /// Gaussian weight of every offset between two pixels, the texture wrapping around.
+    kernel: Vec<f64>,
+}
+
+impl PointField {
+    fn new() -> Self {
+        let wrapped = |delta: usize| exact_f64(delta.min(BLUE_NOISE_SIZE - delta));
+        let kernel = (0..BLUE_NOISE_PIXELS)
+            .map(|offset| {
+                let (dx, dy) = (
+                    wrapped(offset % BLUE_NOISE_SIZE),
+                    wrapped(offset / BLUE_NOISE_SIZE),
+                );
+                (-dx.mul_add(dx, dy * dy) / (2.0 * BLUE_NOISE_SIGMA * BLUE_NOISE_SIGMA)).exp()
+            })
+            .collect();
+        Self {
+            points: vec![false; BLUE_NOISE_PIXELS],
+            count: 0,
+            density: vec![0.0; BLUE_NOISE_PIXELS],
+            kernel,
+        }
+    }
+
+    /// Adds a point on `pixel`, or removes it if there is one.
+    fn toggle(&mut self, pixel: usize) {
+        let sign = if self.points[pixel] { -1.0 } else { 1.0 };
+        self.points[pixel] = !self.points[pixel];
+        if self.points[pixel] {
+            self.count += 1;
+        } else {
+            self.count -= 1;
+        }
+        let (x, y) = (pixel % BLUE_NOISE_SIZE, pixel / BLUE_NOISE_SIZE);
+        for (other, density) in self.density.iter_mut().enumerate() {
+            let dx = (other % BLUE_NOISE_SIZE + BLUE_NOISE_SIZE - x) % BLUE_NOISE_SIZE;
+            let dy = (other / BLUE_NOISE_SIZE + BLUE_NOISE_SIZE - y) % BLUE_NOISE_SIZE;
+            *density += sign * self.kernel[dy * BLUE_NOISE_SIZE + dx];
+        }
+    }
+
+    /// The point with the most points around it.
+    fn tightest_cluster(&self) -> usize {
+        self.extreme(true, |a, b| a > b)
+    }
+
+    /// The pixel without a point that has the fewest points around it.
+    fn largest_void(&self) -> usize {
+        self.extreme(false, |a, b| a < b)
+    }
+
+    /// Returns the first pixel whose point state is `point` and whose density is the best by `is_better`.
+    fn extreme(&self, point: bool, is_better: impl Fn(f64, f64) -> bool) -> usize {
+        (0..BLUE_NOISE_PIXELS)
+            .filter(|pixel| self.points[*pixel] == point)
+            .reduce(|best, pixel| {
+                if is_better(self.density[pixel], self.density[best]) {
+                    pixel
+                } else {
+                    best
+                }
+            })
+            .expect("The field has both points and empty pixels")
+    }
+}
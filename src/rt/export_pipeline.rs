@@ -0,0 +1,145 @@
+use super::{
+    color::Color, dithering::Dithering, image_encoder::quantize, tone_mapping::ToneMapping,
+};
+
+/// How the values written to an image file relate to the light they represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransferFunction {
+    /// Values proportional to the light, which most viewers display too dark.
+    #[default]
+    Linear,
+    /// The sRGB curve, which spends more values on the dark tones, as screens and viewers expect.
+    Srgb,
+}
+
+impl TransferFunction {
+    /// Encodes a linear `value`. Negative values are encoded as 0 by the sRGB curve.
+    pub fn encode(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Srgb => {
+                let value = value.max(0.0);
+                if value <= 0.003_130_8 {
+                    12.92 * value
+                } else {
+                    1.055_f64.mul_add(value.powf(1.0 / 2.4), -0.055)
+                }
+            }
+        }
+    }
+}
+
+/// Processing applied by image encoders to the colors of a canvas before writing them: exposure, then
+/// tone mapping, then the transfer function, then (for 8-bit formats) dithering and rounding.
+///
+/// Float formats skip the dithering and rounding, but apply the rest of the pipeline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExportPipeline {
+    exposure: f64,
+    tone_mapping: ToneMapping,
+    transfer: TransferFunction,
+    dithering: Dithering,
+}
+
+impl Default for ExportPipeline {
+    fn default() -> Self {
+        Self::linear()
+    }
+}
+
+impl ExportPipeline {
+    /// `exposure` is in stops: each stop doubles the brightness of the image.
+    pub const fn new(
+        exposure: f64,
+        tone_mapping: ToneMapping,
+        transfer: TransferFunction,
+        dithering: Dithering,
+    ) -> Self {
+        Self {
+            exposure,
+            tone_mapping,
+            transfer,
+            dithering,
+        }
+    }
+
+    /// Pipeline that writes the colors as they are, linearly, rounded to the nearest value.
+    pub const fn linear() -> Self {
+        Self::new(
+            0.0,
+            ToneMapping::None,
+            TransferFunction::Linear,
+            Dithering::None,
+        )
+    }
+
+    /// Pipeline for images meant to be looked at: ACES filmic tone mapping, sRGB encoding and blue noise
+    /// dithering.
+    pub const fn display() -> Self {
+        Self::new(
+            0.0,
+            ToneMapping::AcesFilmic,
+            TransferFunction::Srgb,
+            Dithering::BlueNoise,
+        )
+    }
+
+    pub const fn with_exposure(mut self, exposure: f64) -> Self {
+        self.exposure = exposure;
+        self
+    }
+
+    pub const fn with_tone_mapping(mut self, tone_mapping: ToneMapping) -> Self {
+        self.tone_mapping = tone_mapping;
+        self
+    }
+
+    pub const fn with_transfer(mut self, transfer: TransferFunction) -> Self {
+        self.transfer = transfer;
+        self
+    }
+
+    pub const fn with_dithering(mut self, dithering: Dithering) -> Self {
+        self.dithering = dithering;
+        self
+    }
+
+    pub const fn exposure(&self) -> f64 {
+        self.exposure
+    }
+
+    pub const fn tone_mapping(&self) -> ToneMapping {
+        self.tone_mapping
+    }
+
+    pub const fn transfer(&self) -> TransferFunction {
+        self.transfer
+    }
+
+    pub const fn dithering(&self) -> Dithering {
+        self.dithering
+    }
+
+    /// Applies the exposure, the tone mapping and the transfer function to `color`.
+    pub fn map(&self, color: &Color) -> Color {
+        let color = self.tone_mapping.apply(*color * self.exposure.exp2());
+        Color::new(
+            self.transfer.encode(color.red()),
+            self.transfer.encode(color.green()),
+            self.transfer.encode(color.blue()),
+        )
+    }
+
+    /// Maps `color`, the pixel (`x`, `y`) of the image, to integer channels in [0, `max`].
+    ///
+    /// Dithering is only applied when `max` is at most 255: 16-bit values are fine enough not to band.
+    pub fn quantize(&self, color: &Color, x: usize, y: usize, max: u16) -> [u16; 3] {
+        let color = self.map(color);
+        let offset = if u8::try_from(max).is_ok() {
+            self.dithering.offset(x, y) / f64::from(max)
+        } else {
+            0.0
+        };
+        [color.red(), color.green(), color.blue()].map(|value| quantize(value + offset, max))
+    }
+}
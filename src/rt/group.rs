@@ -0,0 +1,133 @@
+use {
+    super::{
+        bounding_box::BoundingBox, bvh::Bvh, intersection::Intersection, material::Material,
+        matrix::Matrix, object::Object, shape::Shape,
+    },
+    crate::tuple::{point::Point, vector::Vector},
+    std::sync::OnceLock,
+};
+
+/// A collection of objects sharing a common transform, so that a whole
+/// assembly can be transformed at once. Children keep their own transform,
+/// which is applied relatively to the group transform.
+/// Groups can be nested.
+#[derive(Debug)]
+pub struct Group {
+    transform: Matrix<4>,
+    material: Material,
+    casts_shadow: bool,
+    children: Vec<Object>,
+    /// Built on the first intersection, and reset whenever the children are modified.
+    bvh: OnceLock<Bvh>,
+}
+
+impl PartialEq for Group {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.transform == rhs.transform
+            && self.material == rhs.material
+            && self.children == rhs.children
+    }
+}
+
+impl Group {
+    pub fn new(transform: Matrix<4>, children: Vec<Object>) -> Self {
+        Self {
+            transform,
+            material: Material::default(),
+            casts_shadow: true,
+            children,
+            bvh: OnceLock::new(),
+        }
+    }
+
+    /// Creates a group with a given transform and no children.
+    pub fn with_transform(transform: Matrix<4>) -> Self {
+        Self {
+            transform,
+            ..Self::default()
+        }
+    }
+
+    pub fn children(&self) -> &[Object] {
+        &self.children
+    }
+
+    pub fn children_mut(&mut self) -> &mut [Object] {
+        self.bvh.take();
+        &mut self.children
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn add_child(&mut self, child: Object) {
+        self.bvh.take();
+        self.children.push(child);
+    }
+
+    /// Returns the bounding volume hierarchy built over the children, in group space.
+    pub fn bvh(&self) -> &Bvh {
+        self.bvh.get_or_init(|| Bvh::new(&self.children))
+    }
+}
+
+impl Shape for Group {
+    fn transform(&self) -> &Matrix<4> {
+        &self.transform
+    }
+
+    /// The material of a group is not used for rendering,
+    /// each child is rendered with its own material.
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn material_mut(&mut self) -> &mut Material {
+        &mut self.material
+    }
+
+    fn set_transform(&mut self, transform: Matrix<4>) {
+        self.transform = transform;
+    }
+
+    fn casts_shadow(&self) -> bool {
+        self.casts_shadow
+    }
+
+    fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        self.casts_shadow = casts_shadow;
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    /// A group is never hit itself, intersections always refer to its children,
+    /// whose normals are converted to world space by walking the parent chain
+    /// (see `Intersection::normal_at`).
+    ///
+    /// # Panics
+    ///
+    /// Always panics: a group has no surface, so it has no normal.
+    fn normal_at(&self, _: &Point, _: &Intersection) -> Vector {
+        unreachable!("A group has no normal, only its children do.")
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bvh().bounds().clone()
+    }
+}
+
+impl Default for Group {
+    /// Creates an empty group with an identity transform.
+    fn default() -> Self {
+        Self {
+            transform: Matrix::<4>::identity(),
+            material: Material::default(),
+            casts_shadow: true,
+            children: Vec::new(),
+            bvh: OnceLock::new(),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use {
+    super::{
+        canvas::Canvas, color::Color, export_pipeline::ExportPipeline, image_encoder::ImageEncoder,
+    },
+    std::io::{Result as IoResult, Write},
+};
+
+/// Scanlines can only be run-length encoded when their width is in this range.
+const RLE_WIDTHS: std::ops::Range<usize> = 8..0x8000;
+/// Shortest run of identical bytes worth encoding as a run.
+const MIN_RUN: usize = 4;
+const MAX_RUN: usize = 127;
+const MAX_NON_RUN: usize = 128;
+
+/// Encodes canvases as Radiance RGBE (`.hdr`) images, which keep the colors above 1.0.
+///
+/// Each pixel is stored as three 8-bit mantissas sharing an 8-bit exponent, so the relative precision
+/// is about 1%, whatever the brightness. Negative channels are stored as 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HdrEncoder {
+    run_length_encoding: bool,
+    pipeline: ExportPipeline,
+}
+
+impl Default for HdrEncoder {
+    /// Creates an encoder that run-length encodes the scanlines.
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+impl HdrEncoder {
+    /// Creates an encoder that stores the scanlines as they are, if `run_length_encoding` is false.
+    pub const fn new(run_length_encoding: bool) -> Self {
+        Self {
+            run_length_encoding,
+            pipeline: ExportPipeline::linear(),
+        }
+    }
+
+    /// Makes the encoder apply `pipeline`, which should usually keep the colors linear and unclamped.
+    pub const fn with_pipeline(mut self, pipeline: ExportPipeline) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+
+    pub const fn run_length_encoding(&self) -> bool {
+        self.run_length_encoding
+    }
+
+    /// Writes a scanline as four run-length encoded channels: the red mantissas, then the green and blue
+    /// mantissas, then the exponents.
+    ///
+    /// The width of the scanline must be in `RLE_WIDTHS`: wider scanlines are written flat.
+    fn write_rle_scanline(writer: &mut dyn Write, pixels: &[[u8; 4]]) -> IoResult<()> {
+        assert!(
+            RLE_WIDTHS.contains(&pixels.len()),
+            "Run-length encoded scanlines are 8 to 0x7fff pixels wide"
+        );
+        let width = u16::try_from(pixels.len()).expect("The width is at most 0x7fff");
+        writer.write_all(&[2, 2])?;
+        writer.write_all(&width.to_be_bytes())?;
+        let mut channel = Vec::with_capacity(pixels.len());
+        let mut encoded = Vec::with_capacity(pixels.len() * 2);
+        for index in 0..4 {
+            channel.clear();
+            channel.extend(pixels.iter().map(|pixel| pixel[index]));
+            encoded.clear();
+            Self::run_length_encode(&channel, &mut encoded);
+            writer.write_all(&encoded)?;
+        }
+        Ok(())
+    }
+
+    /// Encodes `data` as runs (a count above 128, then the repeated byte) and non-runs (a count up to
+    /// 128, then the bytes).
+    fn run_length_encode(data: &[u8], encoded: &mut Vec<u8>) {
+        let run_length = |start: usize| {
+            data[start..]
+                .iter()
+                .take(MAX_RUN)
+                .take_while(|byte| **byte == data[start])
+                .count()
+        };
+        let mut position = 0;
+        while position < data.len() {
+            let run = run_length(position);
+            if run >= MIN_RUN {
+                encoded.extend_from_slice(&[
+                    128 + u8::try_from(run).expect("Runs are at most 127 bytes long"),
+                    data[position],
+                ]);
+                position += run;
+                continue;
+            }
+            let start = position;
+            while position < data.len()
+                && position - start < MAX_NON_RUN
+                && run_length(position) < MIN_RUN
+            {
+                position += 1;
+            }
+            encoded
+                .push(u8::try_from(position - start).expect("Non-runs are at most 128 bytes long"));
+            encoded.extend_from_slice(&data[start..position]);
+        }
+    }
+}
+
+impl ImageEncoder for HdrEncoder {
+    fn encode(&self, canvas: &Canvas, writer: &mut dyn Write) -> IoResult<()> {
+        write!(
+            writer,
+            "#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y {} +X {}\n",
+            canvas.height(),
+            canvas.width()
+        )?;
+        let use_rle = self.run_length_encoding && RLE_WIDTHS.contains(&canvas.width());
+        let mut pixels = Vec::with_capacity(canvas.width());
+        for y in 0..canvas.height() {
+            pixels.clear();
+            pixels.extend(
+                canvas
+                    .row(y)
+                    .iter()
+                    .map(|color| rgbe(&self.pipeline.map(color))),
+            );
+            if use_rle {
+                Self::write_rle_scanline(writer, &pixels)?;
+            } else {
+                writer.write_all(pixels.as_flattened())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn extension(&self) -> &'static str {
+        "hdr"
+    }
+
+    fn pipeline(&self) -> ExportPipeline {
+        self.pipeline
+    }
+}
+
+/// Converts `color` to its RGBE representation: each channel is `mantissa` * 2^(`exponent` - 136).
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn rgbe(color: &Color) -> [u8; 4] {
+    let rgb = [color.red(), color.green(), color.blue()].map(|value| value.max(0.0));
+    let max = rgb[0].max(rgb[1]).max(rgb[2]);
+    if max < 1e-32 {
+        return [0; 4];
+    }
+    // The largest RGBE exponent is 127.
+    let max = max.min(f64::from(u8::MAX) / 256.0 * 2_f64.powi(127));
+    // `max` = `fraction` * 2^`exponent`, with `fraction` in [0.5, 1).
+    // `max` is in [1e-32, 2^127), so `exponent` is a whole number in [-105, 127].
+    let exponent = max.log2().floor() as i32 + 1;
+    let scale = 256.0 / 2_f64.powi(exponent);
+    // The channels are at most `max`, so their mantissas are in [0, 256), stored truncated.
+    let [red, green, blue] = rgb.map(|value| (value * scale).min(255.0) as u8);
+    [red, green, blue, (exponent + 128) as u8]
+}
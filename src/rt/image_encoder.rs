@@ -0,0 +1,31 @@
+use {
+    super::{canvas::Canvas, export_pipeline::ExportPipeline},
+    std::io::{Result as IoResult, Write},
+};
+
+/// An image file format that a `Canvas` can be exported to.
+///
+/// Encoders only hold the settings of their format, so that the same encoder can export many canvases,
+/// and can be picked at runtime as a `&dyn ImageEncoder`.
+pub trait ImageEncoder {
+    /// Writes `canvas` to `writer` in this format.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the canvas cannot be stored in this format, or if `writer` fails.
+    fn encode(&self, canvas: &Canvas, writer: &mut dyn Write) -> IoResult<()>;
+
+    /// Usual file extension of this format, without the leading dot.
+    fn extension(&self) -> &'static str;
+
+    /// Processing applied to the colors of the canvas before they are written.
+    fn pipeline(&self) -> ExportPipeline;
+}
+
+/// Scales `value` from [0, 1] to [0, `max`], clamping it and rounding it to the nearest integer.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn quantize(value: f64, max: u16) -> u16 {
+    let max = f64::from(max);
+    // The rounded value is a whole number in [0, `max`], so it always fits in a `u16`.
+    (value * max).clamp(0.0, max).round() as u16
+}
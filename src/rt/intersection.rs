@@ -1,29 +1,113 @@
 use {
     super::{computation::Computation, object::Object, ray::Ray, shape::Shape},
-    crate::{float::epsilon::EPSILON, tuple::vector::Vector},
+    crate::{
+        float::epsilon::EPSILON,
+        tuple::{point::Point, vector::Vector},
+    },
 };
 
 #[derive(PartialEq, Clone, Debug)]
 #[non_exhaustive]
 /// Represents the intersection between a ray and an object at point `t` along the ray.
+/// `u` and `v` locate the intersection relatively to the edges of a triangle.
+/// They are only meaningful for intersections with triangles, and are `0.0` otherwise.
 pub struct Intersection<'object> {
     t: f64,
     object: &'object Object,
+    u: f64,
+    v: f64,
+    /// Groups containing the object, from the innermost to the outermost one.
+    parents: Vec<&'object Object>,
 }
 
 impl<'object> Intersection<'object> {
     pub const fn new(t: f64, object: &'object Object) -> Self {
-        Self { t, object }
+        Self {
+            t,
+            object,
+            u: 0.0,
+            v: 0.0,
+            parents: Vec::new(),
+        }
+    }
+
+    /// Creates an intersection that also records the barycentric coordinates `u` and `v` of the hit.
+    pub const fn with_uv(t: f64, object: &'object Object, u: f64, v: f64) -> Self {
+        Self {
+            t,
+            object,
+            u,
+            v,
+            parents: Vec::new(),
+        }
     }
 
     pub const fn t(&self) -> f64 {
         self.t
     }
 
+    pub const fn u(&self) -> f64 {
+        self.u
+    }
+
+    pub const fn v(&self) -> f64 {
+        self.v
+    }
+
     pub const fn object(&self) -> &Object {
         self.object
     }
 
+    pub fn parents(&self) -> &[&Object] {
+        &self.parents
+    }
+
+    /// Records that the intersected object belongs to `parent`. Since groups
+    /// are intersected from the outermost to the innermost one, but return
+    /// their intersections in the opposite order, parents must be added from
+    /// the innermost to the outermost one.
+    pub fn add_parent(&mut self, parent: &'object Object) {
+        self.parents.push(parent);
+    }
+
+    /// Returns the normal vector at a specified point of the intersected object.
+    /// If the object belongs to groups, the point is first converted from world space
+    /// to the space of its direct parent by walking the parent chain from the outermost group.
+    /// The normal returned by the object is then converted back to world space by walking
+    /// the parent chain in the opposite direction.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the transform of a parent is not invertible.
+    pub fn normal_at(&self, point: &Point) -> Vector {
+        let normal = self.object.normal_at(&self.world_to_parent(point), self);
+
+        self.parents.iter().fold(normal, |normal, parent| {
+            (parent.transform().inverse().unwrap().transpose() * normal).normalized()
+        })
+    }
+
+    /// Converts `point` from world space to the space of the intersected object, through the transforms of
+    /// its parents (from the outermost one) and its own transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if one of these transforms is not invertible.
+    pub fn world_to_object(&self, point: &Point) -> Point {
+        self.object.transform().inverse().unwrap() * self.world_to_parent(point)
+    }
+
+    /// Converts `point` from world space to the space of the direct parent of the intersected object, or
+    /// returns it as it is if the object does not belong to a group.
+    fn world_to_parent(&self, point: &Point) -> Point {
+        self.parents
+            .iter()
+            .rev()
+            .fold(point.clone(), |point, parent| {
+                parent.transform().inverse().unwrap() * point
+            })
+    }
+
     fn is_inside(eye_vector: &Vector, normal: &Vector) -> bool {
         eye_vector.dot(normal) < 0.0
     }
@@ -66,7 +150,7 @@ impl<'object> Intersection<'object> {
     ) -> Computation<'object> {
         let point = ray.position(self.t());
         let eye_vector = -ray.direction();
-        let normal_vector = self.object().normal_at(&point);
+        let normal_vector = self.normal_at(&point);
         let inside = Self::is_inside(&eye_vector, &normal_vector);
         // If the hit occurs inside the shape, we inverse the normal to get the reflection on the "inside" material.
         let normal_vector = if inside {
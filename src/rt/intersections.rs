@@ -16,6 +16,25 @@ impl<'object> From<([f64; 2], &'object Object)> for Intersections<'object> {
     }
 }
 
+impl<'object> From<(Vec<f64>, &'object Object)> for Intersections<'object> {
+    /// Same as above, for objects that can be intersected an arbitrary number of times.
+    fn from((intersections, object): (Vec<f64>, &'object Object)) -> Intersections<'object> {
+        Self::new(
+            intersections
+                .into_iter()
+                .map(|t| Intersection::new(t, object))
+                .collect(),
+        )
+    }
+}
+
+impl<'object> From<([f64; 3], &'object Object)> for Intersections<'object> {
+    /// Used to transform the `[t, u, v]` intersection computed from a triangle to an intersection refering the Object enum.
+    fn from(([t, u, v], object): ([f64; 3], &'object Object)) -> Intersections<'object> {
+        Self::new(vec![Intersection::with_uv(t, object, u, v)])
+    }
+}
+
 type Idx = usize;
 
 impl<'objects> Index<Idx> for Intersections<'objects> {
@@ -66,6 +85,14 @@ impl<'objects> Intersections<'objects> {
         self.0.capacity()
     }
 
+    /// Records `parent` as the innermost group containing the objects of all intersections.
+    pub fn with_parent(mut self, parent: &'objects Object) -> Self {
+        for intersection in &mut self.0 {
+            intersection.add_parent(parent);
+        }
+        self
+    }
+
     /* If all intersections are positive, the iterator will stop at the first intersection in the list and return it,
      * so we don't have to check if all intersections are positive and then return the first element of the list.*/
     /// Returns the first positive intersection or `None` if there is none.
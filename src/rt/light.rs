@@ -0,0 +1,73 @@
+use {
+    super::{
+        area_light::AreaLight,
+        attenuation::Attenuation,
+        color::Color,
+        directional_light::DirectionalLight,
+        light_source::{LightSample, LightSource},
+        point_light::PointLight,
+        spot_light::SpotLight,
+    },
+    crate::tuple::point::Point,
+};
+
+#[derive(PartialEq, Debug)]
+pub enum Light {
+    Point(PointLight),
+    Area(AreaLight),
+    Spot(SpotLight),
+    Directional(DirectionalLight),
+}
+
+impl From<PointLight> for Light {
+    fn from(light: PointLight) -> Self {
+        Self::Point(light)
+    }
+}
+
+impl From<AreaLight> for Light {
+    fn from(light: AreaLight) -> Self {
+        Self::Area(light)
+    }
+}
+
+impl From<SpotLight> for Light {
+    fn from(light: SpotLight) -> Self {
+        Self::Spot(light)
+    }
+}
+
+impl From<DirectionalLight> for Light {
+    fn from(light: DirectionalLight) -> Self {
+        Self::Directional(light)
+    }
+}
+
+impl LightSource for Light {
+    fn intensity(&self) -> &Color {
+        match self {
+            Self::Point(light) => light.intensity(),
+            Self::Area(light) => light.intensity(),
+            Self::Spot(light) => light.intensity(),
+            Self::Directional(light) => light.intensity(),
+        }
+    }
+
+    fn samples(&self, point: &Point) -> Vec<LightSample> {
+        match self {
+            Self::Point(light) => light.samples(point),
+            Self::Area(light) => light.samples(point),
+            Self::Spot(light) => light.samples(point),
+            Self::Directional(light) => light.samples(point),
+        }
+    }
+
+    fn attenuation(&self) -> Attenuation {
+        match self {
+            Self::Point(light) => light.attenuation(),
+            Self::Area(light) => light.attenuation(),
+            Self::Spot(light) => light.attenuation(),
+            Self::Directional(light) => light.attenuation(),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use {
+    super::{attenuation::Attenuation, color::Color},
+    crate::tuple::{point::Point, vector::Vector},
+};
+
+/// How a point is lit by one sample of a light source.
+#[derive(PartialEq, Debug)]
+pub struct LightSample {
+    /// Normalized vector going from the lit point towards the light.
+    direction: Vector,
+    /// Distance from the lit point to the light, `f64::INFINITY` for lights that are infinitely far.
+    distance: f64,
+    /// Intensity of the light reaching the point (before shadows are taken into account).
+    intensity: Color,
+}
+
+impl LightSample {
+    pub const fn new(direction: Vector, distance: f64, intensity: Color) -> Self {
+        Self {
+            direction,
+            distance,
+            intensity,
+        }
+    }
+
+    /// Sample of a light located at `position`, lighting `point` with `intensity`.
+    pub fn towards(point: &Point, position: &Point, intensity: Color) -> Self {
+        let point_to_light = position - point;
+        Self::new(
+            point_to_light.normalized(),
+            point_to_light.magnitude(),
+            intensity,
+        )
+    }
+
+    pub const fn direction(&self) -> &Vector {
+        &self.direction
+    }
+
+    pub const fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    pub const fn intensity(&self) -> &Color {
+        &self.intensity
+    }
+}
+
+/// How a light reaches a point: the samples it arrives through, computed once per point, and the fraction
+/// of their light that is not blocked by shadows.
+#[derive(PartialEq, Debug)]
+pub struct Illumination {
+    samples: Vec<LightSample>,
+    /// Fraction of the light that reaches the point on each channel, averaged over the samples,
+    /// from `BLACK` when the point is completely in shadow to `WHITE` when it is fully lit.
+    transmission: Color,
+}
+
+impl Illumination {
+    pub const fn new(samples: Vec<LightSample>, transmission: Color) -> Self {
+        Self {
+            samples,
+            transmission,
+        }
+    }
+
+    pub fn samples(&self) -> &[LightSample] {
+        &self.samples
+    }
+
+    pub const fn transmission(&self) -> &Color {
+        &self.transmission
+    }
+}
+
+/// Methods common to all lights.
+pub trait LightSource {
+    /// Returns the nominal intensity (color) of the light, used for ambient lighting.
+    fn intensity(&self) -> &Color;
+    /// Returns the samples through which the light reaches `point`.
+    /// The contribution of the light is the average of the contributions of its samples,
+    /// each sample having its own shadow test.
+    fn samples(&self, point: &Point) -> Vec<LightSample>;
+    /// Returns how the light fades with distance. Lights do not fade by default.
+    fn attenuation(&self) -> Attenuation {
+        Attenuation::None
+    }
+}
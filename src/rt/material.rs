@@ -1,9 +1,11 @@
 use {
     super::{
         color::{Color, BLACK, WHITE},
+        intersection::Intersection,
+        light::Light,
+        light_source::{Illumination, LightSource},
         object::Object,
         pattern::Pattern,
-        point_light::PointLight,
     },
     crate::{
         approx_eq::ApproxEq,
@@ -11,7 +13,7 @@ use {
     },
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Material {
     color: Color,
     pattern: Option<Pattern>,
@@ -26,40 +28,15 @@ pub struct Material {
 
 impl PartialEq for Material {
     fn eq(&self, rhs: &Self) -> bool {
-        // Since patterns are actually functions,
-        // we cannot compare them.
-        if self.pattern().is_some() {
-            false
-        } else {
-            self.color == rhs.color
-                && self.ambient.approx_eq(rhs.ambient)
-                && self.diffuse.approx_eq(rhs.diffuse)
-                && self.specular.approx_eq(rhs.specular)
-                && self.shininess.approx_eq(rhs.shininess)
-                && self.reflective.approx_eq(rhs.reflective)
-                && self.transparency.approx_eq(rhs.transparency)
-                && self.refractive_index.approx_eq(rhs.refractive_index)
-        }
-    }
-}
-
-impl Clone for Material {
-    fn clone(&self) -> Self {
-        if self.pattern().is_some() {
-            panic!("Cannot duplicate a Material containing a Pattern.");
-        } else {
-            Self {
-                color: self.color,
-                pattern: None,
-                ambient: self.ambient,
-                diffuse: self.diffuse,
-                specular: self.specular,
-                shininess: self.shininess,
-                reflective: self.reflective,
-                transparency: self.transparency,
-                refractive_index: self.refractive_index,
-            }
-        }
+        self.color == rhs.color
+            && self.pattern == rhs.pattern
+            && self.ambient.approx_eq(rhs.ambient)
+            && self.diffuse.approx_eq(rhs.diffuse)
+            && self.specular.approx_eq(rhs.specular)
+            && self.shininess.approx_eq(rhs.shininess)
+            && self.reflective.approx_eq(rhs.reflective)
+            && self.transparency.approx_eq(rhs.transparency)
+            && self.refractive_index.approx_eq(rhs.refractive_index)
     }
 }
 
@@ -190,33 +167,85 @@ impl Material {
         self.refractive_index = refractive_index;
     }
 
+    /// Returns the color of the material at a specified point of `object`, taking its pattern into account.
+    pub fn color_at(&self, object: &Object, point: &Point) -> Color {
+        self.pattern
+            .as_ref()
+            .map_or_else(|| self.color, |p| p.at_object(object, point))
+    }
+
+    /// Same as `color_at`, for the world space `point` of the object hit by `intersection`: the pattern
+    /// also follows the transforms of the groups containing the object.
+    pub fn color_at_intersection(&self, intersection: &Intersection, point: &Point) -> Color {
+        self.pattern
+            .as_ref()
+            .map_or_else(|| self.color, |p| p.at_intersection(intersection, point))
+    }
+
     /// Returns the color of the material at a specified point from a specified view point.
+    ///
+    /// `light_intensity` is the fraction of the light that reaches the point on each channel
+    /// (see `World::light_intensity_at`), from `BLACK` when it is completely in shadow to `WHITE` when it is fully lit.
+    /// The samples of the light are computed here: `lighting_with` reuses the ones of an `Illumination`.
     pub fn lighting(
         &self,
         object: &Object,
-        light: &PointLight,
+        light: &Light,
         point: &Point,
         eye: &Vector,
         normal: &Vector,
-        in_shadow: bool,
+        light_intensity: Color,
     ) -> Color {
-        let color = self
-            .pattern
-            .as_ref()
-            .map_or_else(|| self.color, |p| p.at_object(object, point));
-        // Combine the surface color with the light intensity
-        let effective_color = &color * light.intensity();
-        // Find the direction to the light source (point -> light source)
-        let point_to_light = (light.position() - point).normalized();
-        // Compute the ambient contribution
-        let ambient = effective_color * self.ambient;
-        /* light_dot_normal represents the cosine of the angle between the
-         * light vector and the normal vector. A negative number means
-         * the light is on the other side of the surface. */
-        let light_dot_normal = point_to_light.dot(normal);
-        let (diffuse, specular) = if in_shadow || light_dot_normal < 0.0 {
-            (BLACK, BLACK)
-        } else {
+        let illumination = Illumination::new(light.samples(point), light_intensity);
+        self.lighting_with(
+            &self.color_at(object, point),
+            light,
+            eye,
+            normal,
+            &illumination,
+        )
+    }
+
+    /// Returns the color of the material at the point lit through the samples of `illumination` (see
+    /// `World::illumination`), from a specified view point.
+    ///
+    /// `color` is the color of the surface at the point, as given by `color_at` or `color_at_intersection`.
+    ///
+    /// The diffuse and specular contributions are attenuated with the distance to each sample of the light,
+    /// and averaged over all the samples.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `illumination` has more than `u32::MAX` samples.
+    pub fn lighting_with(
+        &self,
+        color: &Color,
+        light: &Light,
+        eye: &Vector,
+        normal: &Vector,
+        illumination: &Illumination,
+    ) -> Color {
+        // Compute the ambient contribution, from the nominal intensity of the light
+        let ambient = color * light.intensity() * self.ambient;
+        let light_intensity = illumination.transmission();
+        if light_intensity == &BLACK {
+            return ambient;
+        }
+
+        let attenuation = light.attenuation();
+        let samples = illumination.samples();
+        let sum = samples.iter().fold(BLACK, |sum, sample| {
+            // Combine the surface color with the intensity of the light reaching the point
+            let effective_color = color * sample.intensity();
+            // The direction to the light sample (point -> light sample)
+            let point_to_light = sample.direction();
+            /* light_dot_normal represents the cosine of the angle between the
+             * light vector and the normal vector. A negative number means
+             * the light is on the other side of the surface. */
+            let light_dot_normal = point_to_light.dot(normal);
+            if light_dot_normal < 0.0 {
+                return sum;
+            }
             // Compute the diffuse contribution
             let diffuse = effective_color * self.diffuse * light_dot_normal;
             /* reflect_dot_eye represents the cosine of the angle between the
@@ -229,12 +258,13 @@ impl Material {
             } else {
                 // Compute the specular contribution
                 let factor = reflect_dot_eye.powf(self.shininess); // NOTE - Use a factor power of 2 for faster computation?
-                light.intensity() * self.specular * factor
+                sample.intensity() * self.specular * factor
             };
 
-            (diffuse, specular)
-        };
+            sum + (diffuse + specular) * attenuation.factor(sample.distance())
+        });
 
-        ambient + diffuse + specular
+        let sample_count = u32::try_from(samples.len()).expect("Too many light samples");
+        ambient + sum * *light_intensity * (1.0 / f64::from(sample_count))
     }
 }
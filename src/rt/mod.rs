@@ -1,19 +1,50 @@
+pub mod anti_aliasing;
+pub mod area_light;
+pub mod attenuation;
+pub mod bounding_box;
+pub mod bvh;
 pub mod camera;
 pub mod canvas;
 pub mod color;
 pub mod computation;
+pub mod cone;
+pub mod csg;
+pub mod cube;
+pub mod cylinder;
+pub mod directional_light;
+pub mod dithering;
+pub mod export_pipeline;
+pub mod group;
+pub mod hdr;
+pub mod image_encoder;
 pub mod intersection;
 pub mod intersections;
+pub mod light;
+pub mod light_source;
 pub mod material;
 pub mod matrix;
+pub mod noise;
+pub mod obj_parser;
 pub mod object;
 pub mod pattern;
+pub mod pfm;
 pub mod plane;
+pub mod png;
 pub mod point_light;
 pub mod ppm;
+pub mod ppm_reader;
+pub mod ppm_writer;
+pub mod random;
 pub mod ray;
 pub mod shape;
+pub mod smooth_triangle;
 pub mod sphere;
+pub mod spot_light;
 pub mod tests;
 pub mod to_ppm;
+pub mod tone_mapping;
+pub mod triangle;
+pub mod uv_mapping;
+pub mod uv_pattern;
 pub mod world;
+pub mod zlib;
@@ -0,0 +1,133 @@
+use {super::random::Random, crate::tuple::point::Point};
+
+/// Seedable 3D gradient noise (Ken Perlin's improved noise).
+///
+/// The noise is smooth, is 0 on every integer lattice point and stays roughly within [-1, 1]. The same seed always
+/// produces the same noise, so patterns built on it are reproducible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Noise {
+    /// Shuffled 0..256, repeated twice to avoid wrapping the indices.
+    permutation: [u8; 512],
+}
+
+impl Default for Noise {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Noise {
+    /// Creates the noise for `seed`, by shuffling the permutation table with it.
+    ///
+    /// # Panics
+    ///
+    /// Never: the shuffled indices are all below 256.
+    pub fn new(seed: u64) -> Self {
+        let mut values = [0_u8; 256];
+        for (value, index) in values.iter_mut().zip(0..=u8::MAX) {
+            *value = index;
+        }
+        let mut random = Random::new(seed);
+        // Fisher-Yates shuffle.
+        for i in (1..=u8::MAX).rev() {
+            let j = random.next_u64() % (u64::from(i) + 1);
+            values.swap(usize::from(i), usize::try_from(j).expect("j is at most i"));
+        }
+
+        Self {
+            permutation: std::array::from_fn(|i| values[i % 256]),
+        }
+    }
+
+    /// Returns the noise at `point`, roughly in [-1, 1].
+    pub fn at(&self, point: &Point) -> f64 {
+        let (x, y, z) = (point.x(), point.y(), point.z());
+        let (xf, yf, zf) = (x.floor(), y.floor(), z.floor());
+        // Lattice coordinates of the cell containing the point, and position of the point inside it.
+        let (xi, yi, zi) = (Self::wrap(xf), Self::wrap(yf), Self::wrap(zf));
+        let (x, y, z) = (x - xf, y - yf, z - zf);
+        let (fade_x, fade_y, fade_z) = (Self::fade(x), Self::fade(y), Self::fade(z));
+
+        let table = &self.permutation;
+        let hash =
+            |i: usize, j: usize, k: usize| table[usize::from(table[usize::from(table[i]) + j]) + k];
+        let corner = |di: u8, dj: u8, dk: u8| {
+            Self::gradient(
+                hash(
+                    xi + usize::from(di),
+                    yi + usize::from(dj),
+                    zi + usize::from(dk),
+                ),
+                x - f64::from(di),
+                y - f64::from(dj),
+                z - f64::from(dk),
+            )
+        };
+
+        Self::lerp(
+            fade_z,
+            Self::lerp(
+                fade_y,
+                Self::lerp(fade_x, corner(0, 0, 0), corner(1, 0, 0)),
+                Self::lerp(fade_x, corner(0, 1, 0), corner(1, 1, 0)),
+            ),
+            Self::lerp(
+                fade_y,
+                Self::lerp(fade_x, corner(0, 0, 1), corner(1, 0, 1)),
+                Self::lerp(fade_x, corner(0, 1, 1), corner(1, 1, 1)),
+            ),
+        )
+    }
+
+    /// Sums `octaves` layers of noise, each one with twice the frequency and half the amplitude of the
+    /// previous one (fractional Brownian motion). Gives a smooth, cloud-like noise, roughly in [-2, 2].
+    pub fn fractal_sum(&self, point: &Point, octaves: usize) -> f64 {
+        self.octaves(point, octaves, |noise| noise)
+    }
+
+    /// Like `fractal_sum`, but sums the absolute value of each layer, which creates the sharp creases
+    /// used for marble or fire. The result is roughly in [0, 2].
+    pub fn turbulence(&self, point: &Point, octaves: usize) -> f64 {
+        self.octaves(point, octaves, f64::abs)
+    }
+
+    fn octaves(&self, point: &Point, octaves: usize, layer: impl Fn(f64) -> f64) -> f64 {
+        let mut sum = 0.0;
+        let mut frequency = 1.0;
+        for _ in 0..octaves {
+            sum += layer(self.at(&(point * frequency))) / frequency;
+            frequency *= 2.0;
+        }
+        sum
+    }
+
+    /// Index of a lattice coordinate in the permutation table.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn wrap(coordinate: f64) -> usize {
+        // `coordinate` is an integer, so its remainder is a whole number in [0, 255] and converts exactly.
+        coordinate.rem_euclid(256.0) as usize
+    }
+
+    /// Smoothstep-like curve (6t^5 - 15t^4 + 10t^3), whose first and second derivatives are 0 at 0 and 1.
+    fn fade(t: f64) -> f64 {
+        t * t * t * t.mul_add(t.mul_add(6.0, -15.0), 10.0)
+    }
+
+    fn lerp(t: f64, a: f64, b: f64) -> f64 {
+        t.mul_add(b - a, a)
+    }
+
+    /// Dot product of (`dx`, `dy`, `dz`) and one of 12 gradient directions (the middles of the edges of a
+    /// cube), picked by `hash`.
+    fn gradient(hash: u8, dx: f64, dy: f64, dz: f64) -> f64 {
+        let direction = hash & 15;
+        let first = if direction < 8 { dx } else { dy };
+        let second = match direction {
+            0..=3 => dy,
+            12 | 14 => dx,
+            _ => dz,
+        };
+        (if direction & 1 == 0 { first } else { -first })
+            + (if direction & 2 == 0 { second } else { -second })
+    }
+}
@@ -0,0 +1,275 @@
+use {
+    super::{group::Group, object::Object, smooth_triangle::SmoothTriangle, triangle::Triangle},
+    crate::tuple::{point::Point, vector::Vector},
+    std::{
+        cmp::Ordering,
+        error::Error,
+        fmt::{Display, Formatter, Result as FmtResult},
+        fs,
+        io::Error as IoError,
+    },
+};
+
+#[derive(Debug)]
+pub enum ObjError {
+    /// The file could not be read.
+    Io(IoError),
+    /// The line is not a statement supported by the parser. Such lines
+    /// do not stop the parsing, they are collected in `ObjParser::ignored_lines`.
+    IgnoredLine { line: usize, content: String },
+    /// A vertex (`v`) or vertex normal (`vn`) statement does not have three valid coordinates.
+    InvalidCoordinates { line: usize, content: String },
+    /// A face (`f`) statement has less than three vertices.
+    InvalidFace { line: usize, content: String },
+    /// A face refers to a vertex or a normal that is either malformed or does not exist.
+    InvalidIndex { line: usize, index: String },
+}
+
+impl Display for ObjError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Io(error) => write!(f, "could not read OBJ file: {error}"),
+            Self::IgnoredLine { line, content } => {
+                write!(
+                    f,
+                    "line {line}: ignored unsupported statement \"{content}\""
+                )
+            }
+            Self::InvalidCoordinates { line, content } => {
+                write!(f, "line {line}: invalid coordinates in \"{content}\"")
+            }
+            Self::InvalidFace { line, content } => {
+                write!(
+                    f,
+                    "line {line}: a face needs at least 3 vertices in \"{content}\""
+                )
+            }
+            Self::InvalidIndex { line, index } => {
+                write!(f, "line {line}: invalid or out of range index \"{index}\"")
+            }
+        }
+    }
+}
+
+impl Error for ObjError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<IoError> for ObjError {
+    fn from(error: IoError) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Parses Wavefront OBJ files. Supported statements are vertices (`v`), vertex normals (`vn`),
+/// faces (`f`) and named groups (`g`). Faces with more than three vertices are triangulated
+/// as a fan, and faces whose vertices all have a normal (`v//vn` or `v/vt/vn`) produce smooth triangles.
+/// Every other line is ignored and recorded.
+#[derive(Debug)]
+pub struct ObjParser {
+    vertices: Vec<Point>,
+    normals: Vec<Vector>,
+    default_group: Group,
+    named_groups: Vec<(String, Group)>,
+    ignored_lines: Vec<ObjError>,
+}
+
+impl ObjParser {
+    /// Parses the OBJ data contained in `input`.
+    ///
+    /// # Errors
+    /// Returns an error if a vertex, a normal or a face is malformed, or if a face refers to a missing vertex or normal.
+    pub fn parse(input: &str) -> Result<Self, ObjError> {
+        let mut parser = Self {
+            vertices: Vec::new(),
+            normals: Vec::new(),
+            default_group: Group::default(),
+            named_groups: Vec::new(),
+            ignored_lines: Vec::new(),
+        };
+        // Index of the named group faces are currently added to, if any.
+        let mut current_group: Option<usize> = None;
+
+        for (index, content) in input.lines().enumerate() {
+            let line = index + 1;
+            let mut tokens = content.split_whitespace();
+            match tokens.next() {
+                Some("v") => {
+                    let [x, y, z] = Self::parse_coordinates(tokens, line, content)?;
+                    parser.vertices.push(Point::new(x, y, z));
+                }
+                Some("vn") => {
+                    let [x, y, z] = Self::parse_coordinates(tokens, line, content)?;
+                    parser.normals.push(Vector::new(x, y, z));
+                }
+                Some("f") => {
+                    let triangles = parser.parse_face(tokens, line, content)?;
+                    let group = match current_group {
+                        Some(index) => &mut parser.named_groups[index].1,
+                        None => &mut parser.default_group,
+                    };
+                    for triangle in triangles {
+                        group.add_child(triangle);
+                    }
+                }
+                Some("g") => {
+                    let name = tokens.collect::<Vec<_>>().join(" ");
+                    current_group = Some(
+                        parser
+                            .named_groups
+                            .iter()
+                            .position(|(group_name, _)| group_name == &name)
+                            .unwrap_or_else(|| {
+                                parser.named_groups.push((name, Group::default()));
+                                parser.named_groups.len() - 1
+                            }),
+                    );
+                }
+                // Blank lines are not worth reporting.
+                None => {}
+                Some(_) => parser.ignored_lines.push(ObjError::IgnoredLine {
+                    line,
+                    content: content.to_owned(),
+                }),
+            }
+        }
+
+        Ok(parser)
+    }
+
+    /// Reads and parses the OBJ file located at `filename`.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, or for the same reasons as `parse`.
+    pub fn parse_file(filename: &str) -> Result<Self, ObjError> {
+        Self::parse(&fs::read_to_string(filename)?)
+    }
+
+    fn parse_coordinates<'a>(
+        mut tokens: impl Iterator<Item = &'a str>,
+        line: usize,
+        content: &str,
+    ) -> Result<[f64; 3], ObjError> {
+        let mut coordinates = [0.0; 3];
+        for coordinate in &mut coordinates {
+            *coordinate = tokens
+                .next()
+                .and_then(|token| token.parse().ok())
+                .ok_or_else(|| ObjError::InvalidCoordinates {
+                    line,
+                    content: content.to_owned(),
+                })?;
+        }
+        Ok(coordinates)
+    }
+
+    /// Converts a 1-based (or negative, relative to the end) OBJ index into an index of a list of `len` elements.
+    fn resolve_index(index: &str, len: usize, line: usize) -> Result<usize, ObjError> {
+        let invalid_index = || ObjError::InvalidIndex {
+            line,
+            index: index.to_owned(),
+        };
+        let value: isize = index.parse().map_err(|_| invalid_index())?;
+        let resolved = match value.cmp(&0) {
+            Ordering::Greater => value.unsigned_abs() - 1,
+            Ordering::Less => len
+                .checked_sub(value.unsigned_abs())
+                .ok_or_else(invalid_index)?,
+            Ordering::Equal => return Err(invalid_index()),
+        };
+
+        if resolved < len {
+            Ok(resolved)
+        } else {
+            Err(invalid_index())
+        }
+    }
+
+    /// Parses the vertices of a face, each one having the form `v`, `v/vt`, `v//vn` or `v/vt/vn`,
+    /// and triangulates it as a fan around its first vertex.
+    fn parse_face<'a>(
+        &self,
+        tokens: impl Iterator<Item = &'a str>,
+        line: usize,
+        content: &str,
+    ) -> Result<Vec<Object>, ObjError> {
+        let mut vertices = Vec::new();
+        let mut normals = Vec::new();
+        for token in tokens {
+            let mut indices = token.split('/');
+            let vertex = indices.next().unwrap_or_default();
+            vertices.push(Self::resolve_index(vertex, self.vertices.len(), line)?);
+            // Texture vertices are not supported, so we skip them.
+            match indices.nth(1) {
+                Some(normal) if !normal.is_empty() => {
+                    normals.push(Self::resolve_index(normal, self.normals.len(), line)?);
+                }
+                _ => {}
+            }
+        }
+        if vertices.len() < 3 {
+            return Err(ObjError::InvalidFace {
+                line,
+                content: content.to_owned(),
+            });
+        }
+
+        let smooth = normals.len() == vertices.len();
+        Ok((1..vertices.len() - 1)
+            .map(|index| {
+                let [p1, p2, p3] = [vertices[0], vertices[index], vertices[index + 1]]
+                    .map(|vertex| self.vertices[vertex].clone());
+                if smooth {
+                    let [n1, n2, n3] = [normals[0], normals[index], normals[index + 1]]
+                        .map(|normal| self.normals[normal].clone());
+                    SmoothTriangle::new(p1, p2, p3, n1, n2, n3).into()
+                } else {
+                    Triangle::new(p1, p2, p3).into()
+                }
+            })
+            .collect())
+    }
+
+    /// Returns the vertices in the order they were declared.
+    /// Note that OBJ indices start at 1 while this slice starts at 0.
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Returns the vertex normals in the order they were declared.
+    pub fn normals(&self) -> &[Vector] {
+        &self.normals
+    }
+
+    /// Returns the group containing the faces declared before any named group.
+    pub const fn default_group(&self) -> &Group {
+        &self.default_group
+    }
+
+    pub fn group(&self, name: &str) -> Option<&Group> {
+        self.named_groups
+            .iter()
+            .find(|(group_name, _)| group_name == name)
+            .map(|(_, group)| group)
+    }
+
+    /// Returns an `ObjError::IgnoredLine` for each line that was not understood by the parser.
+    pub fn ignored_lines(&self) -> &[ObjError] {
+        &self.ignored_lines
+    }
+
+    /// Converts the parsed data to a single group containing the faces of the default group,
+    /// and each named group as a child group.
+    pub fn into_group(self) -> Group {
+        let mut group = self.default_group;
+        for (_, named_group) in self.named_groups {
+            group.add_child(named_group.into());
+        }
+        group
+    }
+}
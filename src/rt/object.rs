@@ -1,5 +1,9 @@
 use {
-    super::{material::Material, matrix::Matrix, plane::Plane, shape::Shape, sphere::Sphere},
+    super::{
+        bounding_box::BoundingBox, cone::Cone, csg::Csg, cube::Cube, cylinder::Cylinder,
+        group::Group, intersection::Intersection, material::Material, matrix::Matrix, plane::Plane,
+        shape::Shape, smooth_triangle::SmoothTriangle, sphere::Sphere, triangle::Triangle,
+    },
     crate::tuple::{point::Point, vector::Vector},
 };
 
@@ -13,6 +17,13 @@ use {
 pub enum Object {
     Sphere(Sphere),
     Plane(Plane),
+    Cube(Cube),
+    Cylinder(Cylinder),
+    Cone(Cone),
+    Triangle(Triangle),
+    SmoothTriangle(SmoothTriangle),
+    Group(Group),
+    Csg(Csg),
 }
 
 impl From<Sphere> for Object {
@@ -27,11 +38,60 @@ impl From<Plane> for Object {
     }
 }
 
+impl From<Cube> for Object {
+    fn from(cube: Cube) -> Self {
+        Self::Cube(cube)
+    }
+}
+
+impl From<Cylinder> for Object {
+    fn from(cylinder: Cylinder) -> Self {
+        Self::Cylinder(cylinder)
+    }
+}
+
+impl From<Cone> for Object {
+    fn from(cone: Cone) -> Self {
+        Self::Cone(cone)
+    }
+}
+
+impl From<Triangle> for Object {
+    fn from(triangle: Triangle) -> Self {
+        Self::Triangle(triangle)
+    }
+}
+
+impl From<SmoothTriangle> for Object {
+    fn from(triangle: SmoothTriangle) -> Self {
+        Self::SmoothTriangle(triangle)
+    }
+}
+
+impl From<Group> for Object {
+    fn from(group: Group) -> Self {
+        Self::Group(group)
+    }
+}
+
+impl From<Csg> for Object {
+    fn from(csg: Csg) -> Self {
+        Self::Csg(csg)
+    }
+}
+
 impl Shape for Object {
     fn material(&self) -> &Material {
         match self {
             Self::Sphere(sphere) => sphere.material(),
             Self::Plane(plane) => plane.material(),
+            Self::Cube(cube) => cube.material(),
+            Self::Cylinder(cylinder) => cylinder.material(),
+            Self::Cone(cone) => cone.material(),
+            Self::Triangle(triangle) => triangle.material(),
+            Self::SmoothTriangle(triangle) => triangle.material(),
+            Self::Group(group) => group.material(),
+            Self::Csg(csg) => csg.material(),
         }
     }
 
@@ -39,6 +99,13 @@ impl Shape for Object {
         match self {
             Self::Sphere(sphere) => sphere.material_mut(),
             Self::Plane(plane) => plane.material_mut(),
+            Self::Cube(cube) => cube.material_mut(),
+            Self::Cylinder(cylinder) => cylinder.material_mut(),
+            Self::Cone(cone) => cone.material_mut(),
+            Self::Triangle(triangle) => triangle.material_mut(),
+            Self::SmoothTriangle(triangle) => triangle.material_mut(),
+            Self::Group(group) => group.material_mut(),
+            Self::Csg(csg) => csg.material_mut(),
         }
     }
 
@@ -46,13 +113,30 @@ impl Shape for Object {
         match self {
             Self::Sphere(sphere) => sphere.transform(),
             Self::Plane(plane) => plane.transform(),
+            Self::Cube(cube) => cube.transform(),
+            Self::Cylinder(cylinder) => cylinder.transform(),
+            Self::Cone(cone) => cone.transform(),
+            Self::Triangle(triangle) => triangle.transform(),
+            Self::SmoothTriangle(triangle) => triangle.transform(),
+            Self::Group(group) => group.transform(),
+            Self::Csg(csg) => csg.transform(),
         }
     }
 
-    fn normal_at(&self, point: &Point) -> Vector {
+    /// # Panics
+    ///
+    /// Will panic if the object is a group or a CSG: intersections only ever refer to the shapes inside them.
+    fn normal_at(&self, point: &Point, hit: &Intersection) -> Vector {
         match self {
-            Self::Sphere(sphere) => sphere.normal_at(point),
-            Self::Plane(plane) => plane.normal_at(point),
+            Self::Sphere(sphere) => sphere.normal_at(point, hit),
+            Self::Plane(plane) => plane.normal_at(point, hit),
+            Self::Cube(cube) => cube.normal_at(point, hit),
+            Self::Cylinder(cylinder) => cylinder.normal_at(point, hit),
+            Self::Cone(cone) => cone.normal_at(point, hit),
+            Self::Triangle(triangle) => triangle.normal_at(point, hit),
+            Self::SmoothTriangle(triangle) => triangle.normal_at(point, hit),
+            Self::Group(_) => unreachable!("A group has no normal, only its children do."),
+            Self::Csg(_) => unreachable!("A CSG has no normal, only its operands do."),
         }
     }
 
@@ -60,6 +144,41 @@ impl Shape for Object {
         match self {
             Self::Sphere(sphere) => sphere.set_transform(transform),
             Self::Plane(plane) => plane.set_transform(transform),
+            Self::Cube(cube) => cube.set_transform(transform),
+            Self::Cylinder(cylinder) => cylinder.set_transform(transform),
+            Self::Cone(cone) => cone.set_transform(transform),
+            Self::Triangle(triangle) => triangle.set_transform(transform),
+            Self::SmoothTriangle(triangle) => triangle.set_transform(transform),
+            Self::Group(group) => group.set_transform(transform),
+            Self::Csg(csg) => csg.set_transform(transform),
+        }
+    }
+
+    fn casts_shadow(&self) -> bool {
+        match self {
+            Self::Sphere(sphere) => sphere.casts_shadow(),
+            Self::Plane(plane) => plane.casts_shadow(),
+            Self::Cube(cube) => cube.casts_shadow(),
+            Self::Cylinder(cylinder) => cylinder.casts_shadow(),
+            Self::Cone(cone) => cone.casts_shadow(),
+            Self::Triangle(triangle) => triangle.casts_shadow(),
+            Self::SmoothTriangle(triangle) => triangle.casts_shadow(),
+            Self::Group(group) => group.casts_shadow(),
+            Self::Csg(csg) => csg.casts_shadow(),
+        }
+    }
+
+    fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        match self {
+            Self::Sphere(sphere) => sphere.set_casts_shadow(casts_shadow),
+            Self::Plane(plane) => plane.set_casts_shadow(casts_shadow),
+            Self::Cube(cube) => cube.set_casts_shadow(casts_shadow),
+            Self::Cylinder(cylinder) => cylinder.set_casts_shadow(casts_shadow),
+            Self::Cone(cone) => cone.set_casts_shadow(casts_shadow),
+            Self::Triangle(triangle) => triangle.set_casts_shadow(casts_shadow),
+            Self::SmoothTriangle(triangle) => triangle.set_casts_shadow(casts_shadow),
+            Self::Group(group) => group.set_casts_shadow(casts_shadow),
+            Self::Csg(csg) => csg.set_casts_shadow(casts_shadow),
         }
     }
 
@@ -67,6 +186,27 @@ impl Shape for Object {
         match self {
             Self::Sphere(sphere) => sphere.set_material(material),
             Self::Plane(plane) => plane.set_material(material),
+            Self::Cube(cube) => cube.set_material(material),
+            Self::Cylinder(cylinder) => cylinder.set_material(material),
+            Self::Cone(cone) => cone.set_material(material),
+            Self::Triangle(triangle) => triangle.set_material(material),
+            Self::SmoothTriangle(triangle) => triangle.set_material(material),
+            Self::Group(group) => group.set_material(material),
+            Self::Csg(csg) => csg.set_material(material),
+        }
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        match self {
+            Self::Sphere(sphere) => sphere.bounds(),
+            Self::Plane(plane) => plane.bounds(),
+            Self::Cube(cube) => cube.bounds(),
+            Self::Cylinder(cylinder) => cylinder.bounds(),
+            Self::Cone(cone) => cone.bounds(),
+            Self::Triangle(triangle) => triangle.bounds(),
+            Self::SmoothTriangle(triangle) => triangle.bounds(),
+            Self::Group(group) => group.bounds(),
+            Self::Csg(csg) => csg.bounds(),
         }
     }
 }
@@ -1,100 +1,390 @@
 use {
-    super::{color::Color, matrix::Matrix, object::Object, shape::Shape},
-    crate::tuple::point::Point,
-    std::fmt::{Debug, Formatter, Result as FmtResult},
+    super::{
+        color::Color,
+        intersection::Intersection,
+        matrix::Matrix,
+        noise::Noise,
+        object::Object,
+        shape::Shape,
+        uv_mapping::{CubeFace, UvMapping},
+        uv_pattern::UvPattern,
+    },
+    crate::tuple::{point::Point, vector::Vector},
+    std::{
+        fmt::{Debug, Formatter, Result as FmtResult},
+        sync::Arc,
+    },
 };
 
-pub struct Pattern {
-    pattern: Box<dyn Fn(&Point) -> Color + 'static>,
-    transform: Matrix<4>,
-    /// Used to satsify some tests  that requires access
-    /// to `a` and `b`, but can be too restrictive when
-    /// creating patterns.
-    colors: Option<(Color, Color)>,
+/// One of the two arms of a pattern: either a solid color, or a sub-pattern with its own transform.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Paint {
+    Solid(Color),
+    Pattern(Box<Pattern>),
+}
+
+impl From<Color> for Paint {
+    fn from(color: Color) -> Self {
+        Self::Solid(color)
+    }
+}
+
+impl From<Pattern> for Paint {
+    fn from(pattern: Pattern) -> Self {
+        Self::Pattern(Box::new(pattern))
+    }
 }
 
-impl Debug for Pattern {
+impl PartialEq<Color> for Paint {
+    fn eq(&self, other: &Color) -> bool {
+        self.color() == Some(other)
+    }
+}
+
+impl Paint {
+    /// Returns the `Color` at `point`, given in the space of the pattern this paint is an arm of.
+    pub fn at(&self, point: &Point) -> Color {
+        match self {
+            Self::Solid(color) => *color,
+            Self::Pattern(pattern) => pattern.at_pattern_space(point),
+        }
+    }
+
+    /// Returns the color of a solid paint, or `None` for a sub-pattern.
+    pub const fn color(&self) -> Option<&Color> {
+        match self {
+            Self::Solid(color) => Some(color),
+            Self::Pattern(_) => None,
+        }
+    }
+
+    /// Returns the sub-pattern, or `None` for a solid color.
+    pub fn pattern(&self) -> Option<&Pattern> {
+        match self {
+            Self::Solid(_) => None,
+            Self::Pattern(pattern) => Some(pattern),
+        }
+    }
+}
+
+/// How the points of a perturbed pattern are moved by its noise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Displacement {
+    /// A single layer of noise.
+    Noise,
+    /// A fractal sum of the given number of octaves.
+    Fractal(usize),
+    /// The turbulence of the given number of octaves.
+    Turbulence(usize),
+}
+
+impl Displacement {
+    fn at(self, noise: &Noise, point: &Point) -> f64 {
+        match self {
+            Self::Noise => noise.at(point),
+            Self::Fractal(octaves) => noise.fractal_sum(point, octaves),
+            Self::Turbulence(octaves) => noise.turbulence(point, octaves),
+        }
+    }
+}
+
+/// A pattern defined by a closure, for colorings that have no `PatternKind` of their own.
+/// Custom patterns can be cloned and shared between threads, but are only equal to their own clones.
+#[derive(Clone)]
+pub struct CustomPattern(Arc<dyn Fn(&Point) -> Color + Send + Sync + 'static>);
+
+impl Debug for CustomPattern {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.debug_struct("Pattern")
-            .field("pattern", &"Box<dyn Fn(&Point) -> Color + 'static>")
-            .field("transform", &self.transform)
-            .field("colors", &self.colors)
-            .finish()
+        f.write_str("CustomPattern(Fn(&Point) -> Color)")
     }
 }
 
+impl PartialEq for CustomPattern {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The kinds of patterns, with their parameters. All of them are evaluated in pattern space.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatternKind {
+    /// Alternates between `a` and `b` on every unit of x.
+    Stripe {
+        a: Paint,
+        b: Paint,
+    },
+    /// Goes from `a` to `b` along every unit of x.
+    Gradient {
+        a: Paint,
+        b: Paint,
+    },
+    /// Goes from `a` to `b` along every unit of the distance from the y axis.
+    RadialGradient {
+        a: Paint,
+        b: Paint,
+    },
+    /// Alternates between `a` and `b` on every unit of the distance from the y axis.
+    Ring {
+        a: Paint,
+        b: Paint,
+    },
+    /// Alternates between `a` and `b` on every unit cube.
+    Checkers {
+        a: Paint,
+        b: Paint,
+    },
+    /// Weighted average of `a` and `b`, `weight` being the share of `b`.
+    Blend {
+        a: Paint,
+        b: Paint,
+        weight: f64,
+    },
+    /// Goes from `a` to `b` following a fractal sum of `octaves` layers of `noise`.
+    Noise {
+        a: Paint,
+        b: Paint,
+        noise: Noise,
+        octaves: usize,
+    },
+    /// Evaluates `pattern` at points moved by up to `scale` on each coordinate by `noise`.
+    Perturbed {
+        pattern: Box<Pattern>,
+        noise: Noise,
+        displacement: Displacement,
+        scale: f64,
+    },
+    /// Wraps a 2D pattern around objects, using `mapping` to convert points to texture coordinates.
+    TextureMap {
+        uv_pattern: UvPattern,
+        mapping: UvMapping,
+    },
+    /// Textures each face of a unit cube, in the order of the `CubeFace` variants.
+    CubeMap(Box<[UvPattern; 6]>),
+    Custom(CustomPattern),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    kind: PatternKind,
+    transform: Matrix<4>,
+}
+
 impl Pattern {
+    /// Constructs a custom pattern from a closure.
     pub fn new(
-        pattern: impl Fn(&Point) -> Color + 'static,
+        pattern: impl Fn(&Point) -> Color + Send + Sync + 'static,
         transform: Option<Matrix<4>>,
-        colors: Option<(Color, Color)>,
     ) -> Self {
+        Self::from_kind(
+            PatternKind::Custom(CustomPattern(Arc::new(pattern))),
+            transform,
+        )
+    }
+
+    pub fn from_kind(kind: PatternKind, transform: Option<Matrix<4>>) -> Self {
         Self {
-            pattern: Box::new(pattern),
-            transform: transform.map_or_else(Matrix::identity, |t| t),
-            colors,
+            kind,
+            transform: transform.unwrap_or_else(Matrix::identity),
         }
     }
 
-    /// Constructs a stripe pattern.
-    pub fn stripe(a: Color, b: Color, transform: Option<Matrix<4>>) -> Self {
-        Self::new(
-            move |point: &Point| {
-                if point.x().floor() % 2.0 == 0.0 {
-                    a
-                } else {
-                    b
-                }
+    /// Constructs a stripe pattern. Each arm can be a `Color` or a `Pattern`.
+    pub fn stripe(a: impl Into<Paint>, b: impl Into<Paint>, transform: Option<Matrix<4>>) -> Self {
+        Self::from_kind(
+            PatternKind::Stripe {
+                a: a.into(),
+                b: b.into(),
             },
             transform,
-            Some((a, b)),
         )
     }
 
-    /// Constructs a gradient pattern.
-    pub fn gradient(a: Color, b: Color, transform: Option<Matrix<4>>) -> Self {
-        Self::new(
-            move |point: &Point| {
-                let distance = b - a;
-                let fraction = point.x() - point.x().floor();
+    /// Constructs a gradient pattern. Each arm can be a `Color` or a `Pattern`.
+    pub fn gradient(
+        a: impl Into<Paint>,
+        b: impl Into<Paint>,
+        transform: Option<Matrix<4>>,
+    ) -> Self {
+        Self::from_kind(
+            PatternKind::Gradient {
+                a: a.into(),
+                b: b.into(),
+            },
+            transform,
+        )
+    }
 
-                a + (distance * fraction)
+    /// Constructs a gradient pattern that goes from `a` to `b` along concentric circles in x and z.
+    /// Each arm can be a `Color` or a `Pattern`.
+    pub fn radial_gradient(
+        a: impl Into<Paint>,
+        b: impl Into<Paint>,
+        transform: Option<Matrix<4>>,
+    ) -> Self {
+        Self::from_kind(
+            PatternKind::RadialGradient {
+                a: a.into(),
+                b: b.into(),
             },
             transform,
-            Some((a, b)),
         )
     }
 
-    /// Constructs a ring pattern.
-    pub fn ring(a: Color, b: Color, transform: Option<Matrix<4>>) -> Self {
-        Self::new(
-            move |point: &Point| {
-                if point.x().hypot(point.z()) == 0.0 {
-                    a
-                } else {
-                    b
-                }
+    /// Constructs a ring pattern. Each arm can be a `Color` or a `Pattern`.
+    pub fn ring(a: impl Into<Paint>, b: impl Into<Paint>, transform: Option<Matrix<4>>) -> Self {
+        Self::from_kind(
+            PatternKind::Ring {
+                a: a.into(),
+                b: b.into(),
             },
             transform,
-            Some((a, b)),
         )
     }
 
-    /// Constructs a checkers pattern.
-    pub fn checkers(a: Color, b: Color, transform: Option<Matrix<4>>) -> Self {
-        Self::new(
-            move |point: &Point| {
-                if (point.x().floor() + point.y().floor() + point.z().floor()) % 2.0 == 0.0 {
-                    a
-                } else {
-                    b
-                }
+    /// Constructs a checkers pattern. Each arm can be a `Color` or a `Pattern`.
+    pub fn checkers(
+        a: impl Into<Paint>,
+        b: impl Into<Paint>,
+        transform: Option<Matrix<4>>,
+    ) -> Self {
+        Self::from_kind(
+            PatternKind::Checkers {
+                a: a.into(),
+                b: b.into(),
             },
             transform,
-            Some((a, b)),
         )
     }
 
+    /// Constructs a pattern that is the weighted average of `a` and `b`: `weight` is the share of `b`,
+    /// from 0 (only `a`) to 1 (only `b`). Each arm can be a `Color` or a `Pattern`.
+    pub fn blend(
+        a: impl Into<Paint>,
+        b: impl Into<Paint>,
+        weight: f64,
+        transform: Option<Matrix<4>>,
+    ) -> Self {
+        Self::from_kind(
+            PatternKind::Blend {
+                a: a.into(),
+                b: b.into(),
+                weight,
+            },
+            transform,
+        )
+    }
+
+    /// Constructs a pattern that wraps a 2D `UvPattern` around objects, using `mapping` to convert points
+    /// to texture coordinates.
+    pub fn texture_map(
+        uv_pattern: UvPattern,
+        mapping: UvMapping,
+        transform: Option<Matrix<4>>,
+    ) -> Self {
+        Self::from_kind(
+            PatternKind::TextureMap {
+                uv_pattern,
+                mapping,
+            },
+            transform,
+        )
+    }
+
+    /// Constructs a pattern that textures each face of a unit cube with its own `UvPattern`.
+    /// `faces` are in the order of the `CubeFace` variants: left, front, right, back, up and down.
+    pub fn cube_map(faces: [UvPattern; 6], transform: Option<Matrix<4>>) -> Self {
+        Self::from_kind(PatternKind::CubeMap(Box::new(faces)), transform)
+    }
+
+    /// Constructs a pattern that blends from `a` to `b` following a fractal sum of `octaves` layers of
+    /// `noise`, which gives cloud-like patches. Each arm can be a `Color` or a `Pattern`.
+    pub fn noise(
+        a: impl Into<Paint>,
+        b: impl Into<Paint>,
+        noise: Noise,
+        octaves: usize,
+        transform: Option<Matrix<4>>,
+    ) -> Self {
+        Self::from_kind(
+            PatternKind::Noise {
+                a: a.into(),
+                b: b.into(),
+                noise,
+                octaves,
+            },
+            transform,
+        )
+    }
+
+    /// Constructs a pattern that evaluates `pattern` at points jittered by `noise`, each coordinate
+    /// being moved by up to `scale`. `pattern` keeps its own transform, applied before the jitter.
+    pub fn perturbed(
+        pattern: Self,
+        noise: Noise,
+        scale: f64,
+        transform: Option<Matrix<4>>,
+    ) -> Self {
+        Self::displaced(pattern, noise, Displacement::Noise, scale, transform)
+    }
+
+    /// Like `perturbed`, but jitters the points by a fractal sum of `octaves` layers of `noise`, for more
+    /// detailed, cloud-like distortions.
+    pub fn fractal(
+        pattern: Self,
+        noise: Noise,
+        octaves: usize,
+        scale: f64,
+        transform: Option<Matrix<4>>,
+    ) -> Self {
+        Self::displaced(
+            pattern,
+            noise,
+            Displacement::Fractal(octaves),
+            scale,
+            transform,
+        )
+    }
+
+    /// Like `perturbed`, but jitters the points by the turbulence of `octaves` layers of `noise`. Applied to
+    /// stripes or rings, it gives marble and wood looks.
+    pub fn turbulent(
+        pattern: Self,
+        noise: Noise,
+        octaves: usize,
+        scale: f64,
+        transform: Option<Matrix<4>>,
+    ) -> Self {
+        Self::displaced(
+            pattern,
+            noise,
+            Displacement::Turbulence(octaves),
+            scale,
+            transform,
+        )
+    }
+
+    fn displaced(
+        pattern: Self,
+        noise: Noise,
+        displacement: Displacement,
+        scale: f64,
+        transform: Option<Matrix<4>>,
+    ) -> Self {
+        Self::from_kind(
+            PatternKind::Perturbed {
+                pattern: Box::new(pattern),
+                noise,
+                displacement,
+                scale,
+            },
+            transform,
+        )
+    }
+
+    pub const fn kind(&self) -> &PatternKind {
+        &self.kind
+    }
+
     pub const fn transform(&self) -> &Matrix<4> {
         &self.transform
     }
@@ -105,30 +395,136 @@ impl Pattern {
 
     /// Returns the `Color`at the specified `Point`
     pub fn at(&self, point: &Point) -> Color {
-        (self.pattern)(point)
+        match &self.kind {
+            PatternKind::Stripe { a, b } => Self::pick(a, b, point, point.x().floor() % 2.0 == 0.0),
+            PatternKind::Gradient { a, b } => Self::mix(a, b, point, point.x() - point.x().floor()),
+            PatternKind::RadialGradient { a, b } => {
+                let distance = point.x().hypot(point.z());
+                Self::mix(a, b, point, distance - distance.floor())
+            }
+            PatternKind::Ring { a, b } => {
+                Self::pick(a, b, point, point.x().hypot(point.z()).floor() % 2.0 == 0.0)
+            }
+            PatternKind::Checkers { a, b } => Self::pick(
+                a,
+                b,
+                point,
+                (point.x().floor() + point.y().floor() + point.z().floor()) % 2.0 == 0.0,
+            ),
+            PatternKind::Blend { a, b, weight } => Self::mix(a, b, point, *weight),
+            PatternKind::Noise {
+                a,
+                b,
+                noise,
+                octaves,
+            } => {
+                let fraction = noise
+                    .fractal_sum(point, *octaves)
+                    .mul_add(0.25, 0.5)
+                    .clamp(0.0, 1.0);
+                Self::mix(a, b, point, fraction)
+            }
+            PatternKind::Perturbed {
+                pattern,
+                noise,
+                displacement,
+                scale,
+            } => {
+                // Sampling the noise at three distant offsets moves each coordinate independently.
+                let offset = Vector::new(
+                    displacement.at(noise, point),
+                    displacement.at(noise, &(point + Vector::new(31.416, 0.0, 0.0))),
+                    displacement.at(noise, &(point + Vector::new(0.0, 0.0, 27.183))),
+                );
+                pattern.at_pattern_space(&(point + offset * *scale))
+            }
+            PatternKind::TextureMap {
+                uv_pattern,
+                mapping,
+            } => {
+                let (u, v) = mapping.map(point);
+                uv_pattern.at(u, v)
+            }
+            PatternKind::CubeMap(faces) => {
+                let face = CubeFace::of(point);
+                let (u, v) = face.uv(point);
+                faces[face as usize].at(u, v)
+            }
+            PatternKind::Custom(CustomPattern(pattern)) => pattern(point),
+        }
+    }
+
+    /// Returns the color of `a` if `is_a`, or the color of `b`.
+    fn pick(a: &Paint, b: &Paint, point: &Point, is_a: bool) -> Color {
+        if is_a {
+            a.at(point)
+        } else {
+            b.at(point)
+        }
+    }
+
+    /// Goes from the color of `a` to the color of `b` as `fraction` goes from 0 to 1.
+    fn mix(a: &Paint, b: &Paint, point: &Point, fraction: f64) -> Color {
+        let a = a.at(point);
+        let distance = b.at(point) - a;
+
+        a + (distance * fraction)
     }
 
     pub fn at_object(&self, object: &Object, point: &Point) -> Color {
         let object_point = object.transform().inverse().unwrap() * point;
-        let pattern_point = self.transform().inverse().unwrap() * object_point;
+
+        self.at_pattern_space(&object_point)
+    }
+
+    /// Returns the `Color` at the world space `point` of the object hit by `intersection`. Unlike
+    /// `at_object`, the transforms of the groups containing the object are taken into account.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the transform of the object, of one of its groups or of the pattern is not invertible.
+    pub fn at_intersection(&self, intersection: &Intersection, point: &Point) -> Color {
+        self.at_pattern_space(&intersection.world_to_object(point))
+    }
+
+    /// Returns the `Color` at `point`, given in the space this pattern is transformed from (object space,
+    /// or the space of the enclosing pattern).
+    fn at_pattern_space(&self, point: &Point) -> Color {
+        let pattern_point = self.transform().inverse().unwrap() * point;
 
         self.at(&pattern_point)
     }
 
-    pub fn a(&self) -> &Color {
-        &self
-            .colors
-            .as_ref()
-            .expect("Colors are not set on the Pattern")
-            .0
+    /// Returns the two arms of the pattern, or `None` if it is not built from two paints.
+    /// A perturbed pattern has the arms of the pattern it perturbs.
+    pub fn arms(&self) -> Option<(&Paint, &Paint)> {
+        match &self.kind {
+            PatternKind::Stripe { a, b }
+            | PatternKind::Gradient { a, b }
+            | PatternKind::RadialGradient { a, b }
+            | PatternKind::Ring { a, b }
+            | PatternKind::Checkers { a, b }
+            | PatternKind::Blend { a, b, .. }
+            | PatternKind::Noise { a, b, .. } => Some((a, b)),
+            PatternKind::Perturbed { pattern, .. } => pattern.arms(),
+            PatternKind::TextureMap { .. } | PatternKind::CubeMap(_) | PatternKind::Custom(_) => {
+                None
+            }
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if the pattern has no arms.
+    pub fn a(&self) -> &Paint {
+        self.arms().expect("Arms are not set on the Pattern").0
     }
 
-    pub fn b(&self) -> &Color {
-        &self
-            .colors
-            .as_ref()
-            .expect("Colors are not set on the Pattern")
-            .1
+    /// # Panics
+    ///
+    /// Will panic if the pattern has no arms.
+    pub fn b(&self) -> &Paint {
+        self.arms().expect("Arms are not set on the Pattern").1
     }
 }
 
@@ -138,6 +534,5 @@ pub fn test_pattern() -> Pattern {
     Pattern::new(
         move |point: &Point| Color::new(point.x(), point.y(), point.z()),
         Some(Matrix::identity()),
-        None,
     )
 }
@@ -0,0 +1,52 @@
+use {
+    super::{canvas::Canvas, export_pipeline::ExportPipeline, image_encoder::ImageEncoder},
+    std::io::{Result as IoResult, Write},
+};
+
+/// Encodes canvases as color PFM (portable float map) images, which store each channel as a 32-bit
+/// float, without clamping.
+///
+/// Values are little-endian, and the rows are written from the bottom to the top of the image, as the
+/// format requires.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PfmEncoder {
+    pipeline: ExportPipeline,
+}
+
+impl PfmEncoder {
+    /// Makes the encoder apply `pipeline`, which should usually keep the colors linear and unclamped.
+    pub const fn with_pipeline(mut self, pipeline: ExportPipeline) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+}
+
+impl ImageEncoder for PfmEncoder {
+    fn encode(&self, canvas: &Canvas, writer: &mut dyn Write) -> IoResult<()> {
+        // A negative scale means that the values are little-endian.
+        write!(writer, "PF\n{} {}\n-1.0\n", canvas.width(), canvas.height())?;
+        let mut row = Vec::with_capacity(canvas.width() * 3 * 4);
+        for y in (0..canvas.height()).rev() {
+            row.clear();
+            for color in canvas.row(y) {
+                let color = self.pipeline.map(color);
+                for value in [color.red(), color.green(), color.blue()] {
+                    // PFM stores single precision floats, so the values are meant to lose precision.
+                    #[allow(clippy::cast_possible_truncation)]
+                    let value = value as f32;
+                    row.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            writer.write_all(&row)?;
+        }
+        Ok(())
+    }
+
+    fn extension(&self) -> &'static str {
+        "pfm"
+    }
+
+    fn pipeline(&self) -> ExportPipeline {
+        self.pipeline
+    }
+}
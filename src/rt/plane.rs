@@ -1,5 +1,8 @@
 use {
-    super::{material::Material, matrix::Matrix, shape::Shape},
+    super::{
+        bounding_box::BoundingBox, intersection::Intersection, material::Material, matrix::Matrix,
+        shape::Shape,
+    },
     crate::tuple::{point::Point, vector::Vector},
 };
 
@@ -10,6 +13,7 @@ const PLANE_NORMAL: Vector = Vector::new(0.0, 1.0, 0.0);
 pub struct Plane {
     transform: Matrix<4>,
     material: Material,
+    casts_shadow: bool,
 }
 
 impl PartialEq for Plane {
@@ -23,6 +27,7 @@ impl Default for Plane {
         Self {
             transform: Matrix::identity(),
             material: Material::default(),
+            casts_shadow: true,
         }
     }
 }
@@ -44,13 +49,29 @@ impl Shape for Plane {
         self.transform = transform;
     }
 
+    fn casts_shadow(&self) -> bool {
+        self.casts_shadow
+    }
+
+    fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        self.casts_shadow = casts_shadow;
+    }
+
     fn set_material(&mut self, material: Material) {
         self.material = material;
     }
 
-    fn normal_at(&self, _: &Point) -> Vector {
+    fn normal_at(&self, _: &Point, _: &Intersection) -> Vector {
         let world_normal = self.transform.inverse().unwrap().transpose() * PLANE_NORMAL;
 
         world_normal.normalized()
     }
+
+    /// A plane is infinite in x and z, and has no thickness in y.
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, 0.0, f64::INFINITY),
+        )
+    }
 }
@@ -0,0 +1,319 @@
+use {
+    super::{
+        canvas::Canvas,
+        export_pipeline::{ExportPipeline, TransferFunction},
+        image_encoder::ImageEncoder,
+        zlib::{self, Compression},
+    },
+    std::io::{Error as IoError, ErrorKind, Result as IoResult, Write},
+};
+
+pub const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+/// Largest amount of compressed data written in a single IDAT chunk.
+const IDAT_CHUNK_SIZE: usize = 1 << 16;
+/// Gamma of linear values, in the units of the gAMA chunk.
+const LINEAR_GAMMA: u32 = 100_000;
+
+/// Channels stored for each pixel of a PNG image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PngColorType {
+    #[default]
+    Rgb,
+    /// RGB with an alpha channel, which is always opaque since canvases have no transparency.
+    Rgba,
+}
+
+impl PngColorType {
+    pub const fn channels(self) -> usize {
+        match self {
+            Self::Rgb => 3,
+            Self::Rgba => 4,
+        }
+    }
+
+    /// Value of the color type field of the IHDR chunk.
+    const fn code(self) -> u8 {
+        match self {
+            Self::Rgb => 2,
+            Self::Rgba => 6,
+        }
+    }
+}
+
+/// Number of bits used to store each channel of a PNG image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PngBitDepth {
+    #[default]
+    Eight,
+    /// Two big-endian bytes per channel.
+    Sixteen,
+}
+
+impl PngBitDepth {
+    pub const fn bits(self) -> u8 {
+        match self {
+            Self::Eight => 8,
+            Self::Sixteen => 16,
+        }
+    }
+
+    pub const fn max_value(self) -> u16 {
+        match self {
+            Self::Eight => u8::MAX as u16,
+            Self::Sixteen => u16::MAX,
+        }
+    }
+}
+
+/// Encodes canvases as PNG images, using only the standard library.
+///
+/// Each row is filtered with the PNG filter that gives the smallest sum of absolute differences, which
+/// usually compresses best, and the rows are then compressed into a zlib stream.
+///
+/// The transfer function of the pipeline is recorded in the image, by an sRGB chunk or a gAMA chunk of
+/// 1.0 for linear values, so that viewers display it with the right brightness.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PngEncoder {
+    color_type: PngColorType,
+    bit_depth: PngBitDepth,
+    compression: Compression,
+    pipeline: ExportPipeline,
+}
+
+impl PngEncoder {
+    pub const fn new(
+        color_type: PngColorType,
+        bit_depth: PngBitDepth,
+        compression: Compression,
+    ) -> Self {
+        Self {
+            color_type,
+            bit_depth,
+            compression,
+            pipeline: ExportPipeline::linear(),
+        }
+    }
+
+    pub const fn with_color_type(mut self, color_type: PngColorType) -> Self {
+        self.color_type = color_type;
+        self
+    }
+
+    pub const fn with_bit_depth(mut self, bit_depth: PngBitDepth) -> Self {
+        self.bit_depth = bit_depth;
+        self
+    }
+
+    pub const fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    pub const fn with_pipeline(mut self, pipeline: ExportPipeline) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+
+    pub const fn color_type(&self) -> PngColorType {
+        self.color_type
+    }
+
+    pub const fn bit_depth(&self) -> PngBitDepth {
+        self.bit_depth
+    }
+
+    pub const fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Number of bytes of each pixel.
+    const fn bytes_per_pixel(self) -> usize {
+        self.color_type.channels() * self.bit_depth.bits() as usize / 8
+    }
+
+    /// Returns the bytes of the row `y` of `canvas`, before filtering.
+    fn raw_row(self, canvas: &Canvas, y: usize) -> Vec<u8> {
+        let max = self.bit_depth.max_value();
+        let mut row = Vec::with_capacity(canvas.width() * self.bytes_per_pixel());
+        for (x, color) in canvas.row(y).iter().enumerate() {
+            let rgb = self.pipeline.quantize(color, x, y, max);
+            let alpha = (self.color_type == PngColorType::Rgba).then_some(max);
+            for value in rgb.into_iter().chain(alpha) {
+                match self.bit_depth {
+                    PngBitDepth::Eight => {
+                        row.push(u8::try_from(value).expect("8-bit values are at most 255"));
+                    }
+                    PngBitDepth::Sixteen => row.extend_from_slice(&value.to_be_bytes()),
+                }
+            }
+        }
+        row
+    }
+
+    /// Returns the filtered rows of `canvas`, each one starting with its filter type.
+    fn filtered_data(self, canvas: &Canvas) -> Vec<u8> {
+        let bytes_per_pixel = self.bytes_per_pixel();
+        let row_length = canvas.width() * bytes_per_pixel;
+        let mut data = Vec::with_capacity((row_length + 1) * canvas.height());
+        let mut previous = vec![0; row_length];
+        for y in 0..canvas.height() {
+            let row = self.raw_row(canvas, y);
+            let (filter, filtered) = Filter::ALL
+                .into_iter()
+                .map(|filter| (filter, filter.apply(&row, &previous, bytes_per_pixel)))
+                .min_by_key(|(_, filtered)| {
+                    // Filtered bytes are best read as signed differences.
+                    filtered
+                        .iter()
+                        .map(|byte| u64::from(i8::from_ne_bytes([*byte]).unsigned_abs()))
+                        .sum::<u64>()
+                })
+                .expect("There are several filters");
+            data.push(filter as u8);
+            data.extend_from_slice(&filtered);
+            previous = row;
+        }
+        data
+    }
+}
+
+impl ImageEncoder for PngEncoder {
+    fn encode(&self, canvas: &Canvas, writer: &mut dyn Write) -> IoResult<()> {
+        let dimension = |value: usize| {
+            u32::try_from(value)
+                .ok()
+                .filter(|value| (1..=i32::MAX as u32).contains(value))
+                .ok_or_else(|| {
+                    IoError::new(
+                        ErrorKind::InvalidInput,
+                        format!("PNG images cannot be {value} pixels wide or high"),
+                    )
+                })
+        };
+        let (width, height) = (dimension(canvas.width())?, dimension(canvas.height())?);
+
+        writer.write_all(&PNG_SIGNATURE)?;
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&width.to_be_bytes());
+        header.extend_from_slice(&height.to_be_bytes());
+        // Bit depth, color type, then the compression, filter and interlace methods.
+        header.extend_from_slice(&[self.bit_depth.bits(), self.color_type.code(), 0, 0, 0]);
+        write_chunk(writer, *b"IHDR", &header)?;
+        match self.pipeline.transfer() {
+            // Perceptual rendering intent.
+            TransferFunction::Srgb => write_chunk(writer, *b"sRGB", &[0])?,
+            TransferFunction::Linear => write_chunk(writer, *b"gAMA", &LINEAR_GAMMA.to_be_bytes())?,
+        }
+
+        let compressed = zlib::compress(&self.filtered_data(canvas), self.compression);
+        for data in compressed.chunks(IDAT_CHUNK_SIZE) {
+            write_chunk(writer, *b"IDAT", data)?;
+        }
+        write_chunk(writer, *b"IEND", &[])
+    }
+
+    fn extension(&self) -> &'static str {
+        "png"
+    }
+
+    fn pipeline(&self) -> ExportPipeline {
+        self.pipeline
+    }
+}
+
+/// Writes a PNG chunk: the length of `data`, `kind`, `data`, and the CRC-32 of `kind` and `data`.
+fn write_chunk(writer: &mut dyn Write, kind: [u8; 4], data: &[u8]) -> IoResult<()> {
+    let length = u32::try_from(data.len())
+        .map_err(|_| IoError::new(ErrorKind::InvalidInput, "PNG chunk is too large"))?;
+    writer.write_all(&length.to_be_bytes())?;
+    writer.write_all(&kind)?;
+    writer.write_all(data)?;
+    let crc = !crc32_update(crc32_update(u32::MAX, &kind), data);
+    writer.write_all(&crc.to_be_bytes())
+}
+
+/// CRC-32 checksum (ISO-HDLC, as used by PNG and zip).
+pub fn crc32(data: &[u8]) -> u32 {
+    !crc32_update(u32::MAX, data)
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut n = 0;
+    // `n` as a `u32`, which cannot be converted with `From` in a constant.
+    let mut value: u32 = 0;
+    while n < 256 {
+        let mut c = value;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+        value += 1;
+    }
+    table
+};
+
+/// Updates a running (not yet inverted) CRC-32 with `data`.
+fn crc32_update(crc: u32, data: &[u8]) -> u32 {
+    data.iter().fold(crc, |crc, byte| {
+        CRC32_TABLE[((crc ^ u32::from(*byte)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// PNG filter types, which store each byte as a difference with a prediction made from its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Filter {
+    None = 0,
+    Sub = 1,
+    Up = 2,
+    Average = 3,
+    Paeth = 4,
+}
+
+impl Filter {
+    const ALL: [Self; 5] = [Self::None, Self::Sub, Self::Up, Self::Average, Self::Paeth];
+
+    /// Filters `row`, `previous` being the unfiltered row above it.
+    fn apply(self, row: &[u8], previous: &[u8], bytes_per_pixel: usize) -> Vec<u8> {
+        row.iter()
+            .enumerate()
+            .map(|(index, byte)| {
+                let left = index
+                    .checked_sub(bytes_per_pixel)
+                    .map_or(0, |index| row[index]);
+                let up = previous[index];
+                let up_left = index
+                    .checked_sub(bytes_per_pixel)
+                    .map_or(0, |index| previous[index]);
+                let prediction = match self {
+                    Self::None => 0,
+                    Self::Sub => left,
+                    Self::Up => up,
+                    Self::Average => u8::midpoint(left, up),
+                    Self::Paeth => Self::paeth(left, up, up_left),
+                };
+                byte.wrapping_sub(prediction)
+            })
+            .collect()
+    }
+
+    /// Returns whichever of `left`, `up` and `up_left` is closest to `left` + `up` - `up_left`.
+    fn paeth(left: u8, up: u8, up_left: u8) -> u8 {
+        let estimate = i16::from(left) + i16::from(up) - i16::from(up_left);
+        let distance = |value: u8| (estimate - i16::from(value)).abs();
+        if distance(left) <= distance(up) && distance(left) <= distance(up_left) {
+            left
+        } else if distance(up) <= distance(up_left) {
+            up
+        } else {
+            up_left
+        }
+    }
+}
@@ -1,9 +1,17 @@
-use {super::color::Color, crate::tuple::point::Point};
+use {
+    super::{
+        attenuation::Attenuation,
+        color::Color,
+        light_source::{LightSample, LightSource},
+    },
+    crate::tuple::point::Point,
+};
 
 #[derive(PartialEq, Debug)]
 pub struct PointLight {
     position: Point,
     intensity: Color,
+    attenuation: Attenuation,
 }
 
 impl PointLight {
@@ -11,14 +19,31 @@ impl PointLight {
         Self {
             position,
             intensity,
+            attenuation: Attenuation::None,
         }
     }
 
+    /// Makes the light fade with distance.
+    pub const fn with_attenuation(mut self, attenuation: Attenuation) -> Self {
+        self.attenuation = attenuation;
+        self
+    }
+
     pub const fn position(&self) -> &Point {
         &self.position
     }
+}
 
-    pub const fn intensity(&self) -> &Color {
+impl LightSource for PointLight {
+    fn intensity(&self) -> &Color {
         &self.intensity
     }
+
+    fn samples(&self, point: &Point) -> Vec<LightSample> {
+        vec![LightSample::towards(point, &self.position, self.intensity)]
+    }
+
+    fn attenuation(&self) -> Attenuation {
+        self.attenuation
+    }
 }
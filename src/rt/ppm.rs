@@ -1,7 +1,8 @@
 use std::{
+    error::Error,
     fmt::{Display, Formatter, Result},
     fs::File,
-    io::prelude::Write,
+    io::{prelude::Write, Error as IoError},
 };
 
 pub const PPM_MIN_COLOR_VALUE: f64 = 0.0;
@@ -73,3 +74,49 @@ impl Display for Ppm {
         write!(f, "{}{}", self.header, self.pixel_data)
     }
 }
+
+#[derive(Debug)]
+pub enum PpmError {
+    /// The file could not be read.
+    Io(IoError),
+    /// The data does not start with a supported magic number (P3, P6, PF or Pf).
+    UnsupportedFormat(String),
+    /// The width, height, maximum color value (PPM) or scale (PFM) is missing or invalid.
+    InvalidHeader(String),
+    /// A color value is not an integer between 0 and the maximum color value.
+    InvalidColorValue(String),
+    /// The data ends before all the pixels have been read.
+    MissingPixelData { expected: usize, found: usize },
+}
+
+impl Display for PpmError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read PPM file: {error}"),
+            Self::UnsupportedFormat(magic) => {
+                write!(f, "unsupported image format \"{magic}\"")
+            }
+            Self::InvalidHeader(value) => write!(f, "invalid image header value \"{value}\""),
+            Self::InvalidColorValue(value) => write!(f, "invalid PPM color value \"{value}\""),
+            Self::MissingPixelData { expected, found } => write!(
+                f,
+                "expected {expected} color values in PPM data, found {found}"
+            ),
+        }
+    }
+}
+
+impl Error for PpmError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<IoError> for PpmError {
+    fn from(error: IoError) -> Self {
+        Self::Io(error)
+    }
+}
@@ -0,0 +1,229 @@
+use super::{canvas::Canvas, color::Color, ppm::PpmError};
+
+/// Reads plain (P3) and binary (P6) PPM images, and color (PF) or grayscale (Pf) PFM images, into a
+/// `Canvas`.
+///
+/// PPM headers may contain comments (from a `#` to the end of the line) and any whitespace layout, and
+/// color values are scaled from [0, maximum color value] to [0, 1]. PFM values are read as they are,
+/// the rows of the file going from the bottom to the top of the image.
+#[derive(Debug)]
+pub struct PpmReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> PpmReader<'a> {
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// Reads the whole image, whose format is given by its magic number.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the data is not a valid P3, P6, PF or Pf image.
+    pub fn read(mut self) -> Result<Canvas, PpmError> {
+        match self.next_token() {
+            Some(b"P3") => self.read_plain(),
+            Some(b"P6") => self.read_binary(),
+            Some(b"PF") => self.read_float(3),
+            Some(b"Pf") => self.read_float(1),
+            magic => Err(PpmError::UnsupportedFormat(Self::text(
+                magic.unwrap_or_default(),
+            ))),
+        }
+    }
+
+    fn read_plain(&mut self) -> Result<Canvas, PpmError> {
+        let (width, height, max_color_value) = self.read_ppm_header()?;
+        let expected = Self::value_count(width, height, 3)?;
+        // Each value takes at least two bytes with its separator, which bounds the allocation whatever the
+        // header claims.
+        let mut values = Vec::with_capacity(expected.min(self.data.len() / 2 + 1));
+        while values.len() < expected {
+            let Some(token) = self.next_token() else {
+                break;
+            };
+            values.push(Self::color_value(token, max_color_value)?);
+        }
+        Self::rgb_canvas(width, height, &values, expected)
+    }
+
+    fn read_binary(&mut self) -> Result<Canvas, PpmError> {
+        let (width, height, max_color_value) = self.read_ppm_header()?;
+        self.skip_single_whitespace();
+        let bytes_per_value = if u8::try_from(max_color_value).is_err() {
+            2
+        } else {
+            1
+        };
+        let expected = Self::value_count(width, height, 3)?;
+        let values = self.data[self.position..]
+            .chunks_exact(bytes_per_value)
+            .take(expected)
+            .map(|bytes| {
+                let value = bytes
+                    .iter()
+                    .fold(0, |value, byte| value << 8 | u32::from(*byte));
+                if value > u32::from(max_color_value) {
+                    Err(PpmError::InvalidColorValue(value.to_string()))
+                } else {
+                    Ok(f64::from(value) / f64::from(max_color_value))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::rgb_canvas(width, height, &values, expected)
+    }
+
+    fn read_float(&mut self, channels: usize) -> Result<Canvas, PpmError> {
+        let (width, height) = (self.header_value()?, self.header_value()?);
+        let token = self.next_token().unwrap_or_default();
+        let scale = std::str::from_utf8(token)
+            .ok()
+            .and_then(|scale| scale.parse::<f64>().ok())
+            .filter(|scale| scale.is_finite() && *scale != 0.0)
+            .ok_or_else(|| PpmError::InvalidHeader(Self::text(token)))?;
+        self.skip_single_whitespace();
+
+        // A negative scale means that the values are little-endian.
+        let from_bytes = if scale < 0.0 {
+            f32::from_le_bytes
+        } else {
+            f32::from_be_bytes
+        };
+        let expected = Self::value_count(width, height, channels)?;
+        let values: Vec<f64> = self.data[self.position..]
+            .chunks_exact(4)
+            .take(expected)
+            .map(|bytes| f64::from(from_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])))
+            .collect();
+        if values.len() < expected {
+            return Err(PpmError::MissingPixelData {
+                expected,
+                found: values.len(),
+            });
+        }
+
+        let mut canvas = Canvas::new(width, height);
+        for (row, row_values) in values.chunks_exact(width * channels).enumerate() {
+            for (x, pixel) in row_values.chunks_exact(channels).enumerate() {
+                let color = match *pixel {
+                    [red, green, blue] => Color::new(red, green, blue),
+                    [gray] => Color::new(gray, gray, gray),
+                    _ => unreachable!("PFM images have 1 or 3 channels"),
+                };
+                canvas.write_pixel(x, height - 1 - row, color);
+            }
+        }
+        Ok(canvas)
+    }
+
+    /// Reads the width, height and maximum color value of a PPM image.
+    fn read_ppm_header(&mut self) -> Result<(usize, usize, u16), PpmError> {
+        let (width, height) = (self.header_value()?, self.header_value()?);
+        let token = self.next_token().unwrap_or_default();
+        let max_color_value = Self::parse(token)
+            .and_then(|value| u16::try_from(value).ok())
+            .filter(|value| *value > 0)
+            .ok_or_else(|| PpmError::InvalidHeader(Self::text(token)))?;
+        Ok((width, height, max_color_value))
+    }
+
+    /// Returns the number of values of a `width` x `height` image with `channels` values per pixel.
+    ///
+    /// The dimensions come from the header, which cannot be trusted: a product that overflows is an
+    /// invalid header.
+    fn value_count(width: usize, height: usize, channels: usize) -> Result<usize, PpmError> {
+        width
+            .checked_mul(height)
+            .and_then(|pixels| pixels.checked_mul(channels))
+            .ok_or_else(|| PpmError::InvalidHeader(format!("{width} {height}")))
+    }
+
+    /// Reads a positive integer of the header.
+    fn header_value(&mut self) -> Result<usize, PpmError> {
+        let token = self.next_token().unwrap_or_default();
+        Self::parse(token)
+            .filter(|value| *value > 0)
+            .ok_or_else(|| PpmError::InvalidHeader(Self::text(token)))
+    }
+
+    fn color_value(token: &[u8], max_color_value: u16) -> Result<f64, PpmError> {
+        Self::parse(token)
+            .and_then(|value| u16::try_from(value).ok())
+            .filter(|value| *value <= max_color_value)
+            .map(|value| f64::from(value) / f64::from(max_color_value))
+            .ok_or_else(|| PpmError::InvalidColorValue(Self::text(token)))
+    }
+
+    /// Creates a `width` x `height` canvas from its RGB `values`, once they are all there.
+    fn rgb_canvas(
+        width: usize,
+        height: usize,
+        values: &[f64],
+        expected: usize,
+    ) -> Result<Canvas, PpmError> {
+        if values.len() < expected {
+            return Err(PpmError::MissingPixelData {
+                expected,
+                found: values.len(),
+            });
+        }
+        let mut canvas = Canvas::new(width, height);
+        for (index, rgb) in values.chunks_exact(3).enumerate() {
+            canvas.write_pixel(
+                index % width,
+                index / width,
+                Color::new(rgb[0], rgb[1], rgb[2]),
+            );
+        }
+        Ok(canvas)
+    }
+
+    /// Returns the next whitespace separated token, skipping comments.
+    fn next_token(&mut self) -> Option<&'a [u8]> {
+        loop {
+            match self.data.get(self.position)? {
+                byte if byte.is_ascii_whitespace() => self.position += 1,
+                b'#' => {
+                    while self
+                        .data
+                        .get(self.position)
+                        .is_some_and(|byte| *byte != b'\n')
+                    {
+                        self.position += 1;
+                    }
+                }
+                _ => break,
+            }
+        }
+        let start = self.position;
+        while self
+            .data
+            .get(self.position)
+            .is_some_and(|byte| !byte.is_ascii_whitespace() && *byte != b'#')
+        {
+            self.position += 1;
+        }
+        Some(&self.data[start..self.position])
+    }
+
+    /// Skips the single whitespace character that separates the header of a binary image from its data.
+    fn skip_single_whitespace(&mut self) {
+        if self
+            .data
+            .get(self.position)
+            .is_some_and(u8::is_ascii_whitespace)
+        {
+            self.position += 1;
+        }
+    }
+
+    fn parse(token: &[u8]) -> Option<usize> {
+        std::str::from_utf8(token).ok()?.parse().ok()
+    }
+
+    fn text(token: &[u8]) -> String {
+        String::from_utf8_lossy(token).into_owned()
+    }
+}
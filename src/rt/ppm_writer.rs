@@ -0,0 +1,282 @@
+use {
+    super::{
+        canvas::Canvas,
+        color::Color,
+        export_pipeline::ExportPipeline,
+        image_encoder::{quantize, ImageEncoder},
+        ppm::{Ppm, PPM_MAX_CHARACTERS_PER_LINE},
+    },
+    std::{
+        fmt::Write as _,
+        io::{Error as IoError, ErrorKind, Result as IoResult, Write},
+    },
+};
+
+/// Number of bits used to store each color value of a binary (P6) PPM file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PpmDepth {
+    /// One byte per value, with a maximum color value of 255.
+    #[default]
+    Eight,
+    /// Two big-endian bytes per value, with a maximum color value of 65535.
+    Sixteen,
+}
+
+impl PpmDepth {
+    pub const fn max_color_value(self) -> u16 {
+        match self {
+            Self::Eight => u8::MAX as u16,
+            Self::Sixteen => u16::MAX,
+        }
+    }
+
+    pub const fn bytes_per_value(self) -> usize {
+        match self {
+            Self::Eight => 1,
+            Self::Sixteen => 2,
+        }
+    }
+
+    /// Scales `value` from [0, 1] to [0, maximum color value], rounding it to the nearest integer.
+    pub fn quantize(self, value: f64) -> u16 {
+        quantize(value, self.max_color_value())
+    }
+}
+
+/// Variant of the PPM format written by a `PpmEncoder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PpmFormat {
+    /// ASCII (P3) image, with 8-bit values.
+    #[default]
+    Plain,
+    /// Binary (P6) image, streamed by a `PpmWriter`.
+    Binary(PpmDepth),
+}
+
+/// Encodes canvases as PPM images, the colors going through the export pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PpmEncoder {
+    format: PpmFormat,
+    pipeline: ExportPipeline,
+}
+
+impl PpmEncoder {
+    pub const fn new(format: PpmFormat) -> Self {
+        Self {
+            format,
+            pipeline: ExportPipeline::linear(),
+        }
+    }
+
+    pub const fn plain() -> Self {
+        Self::new(PpmFormat::Plain)
+    }
+
+    pub const fn binary(depth: PpmDepth) -> Self {
+        Self::new(PpmFormat::Binary(depth))
+    }
+
+    pub const fn with_pipeline(mut self, pipeline: ExportPipeline) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+
+    pub const fn format(&self) -> PpmFormat {
+        self.format
+    }
+
+    /// Builds the ASCII (P3) image of `canvas`.
+    pub fn plain_ppm(&self, canvas: &Canvas) -> Ppm {
+        Ppm::new(
+            "P3",
+            &canvas.width().to_string(),
+            &canvas.height().to_string(),
+            &PpmDepth::Eight.max_color_value().to_string(),
+            self.plain_pixel_data(canvas),
+        )
+    }
+
+    /// Builds the ASCII pixel data of `canvas`, one row per line, with lines split to stay under 70
+    /// characters.
+    pub fn plain_pixel_data(&self, canvas: &Canvas) -> String {
+        let max = PpmDepth::Eight.max_color_value();
+        let mut pixel_data = String::new();
+        for y in 0..canvas.height() {
+            for (x, color) in canvas.row(y).iter().enumerate() {
+                let [red, green, blue] = self.pipeline.quantize(color, x, y, max);
+                let separator = if x + 1 < canvas.width() { " " } else { "" };
+                // Writing to a String cannot fail.
+                let _ = write!(pixel_data, "{red} {green} {blue}{separator}");
+            }
+            pixel_data.push('\n');
+        }
+        Self::split_lines_too_long(&pixel_data)
+    }
+
+    /// Replaces the spaces that would make a line longer than 70 characters by newlines. The result has the
+    /// same length as `pixel_data`.
+    fn split_lines_too_long(pixel_data: &str) -> String {
+        let mut cleaned_pixel_data = String::with_capacity(pixel_data.len());
+        let mut it_lines = pixel_data.split('\n').peekable();
+        let mut last_line_start_index: usize = 0;
+
+        while let Some(line) = it_lines.next() {
+            let mut it_colors = line.split(' ').peekable();
+            while let Some(color) = it_colors.next() {
+                let last_line_length = cleaned_pixel_data[last_line_start_index..].len();
+                cleaned_pixel_data.push_str(color);
+                if let Some(next_color) = it_colors.peek() {
+                    // can_insert_next_color_into_line is true if we can insert a space and the next color without exceeding 70 chars
+                    // If true, insert a space, else insert a new line.
+                    let can_insert_next_color_into_line =
+                        (last_line_length + color.len() + 1 + next_color.len())
+                            < PPM_MAX_CHARACTERS_PER_LINE;
+                    if can_insert_next_color_into_line {
+                        cleaned_pixel_data.push(' ');
+                    } else {
+                        cleaned_pixel_data.push('\n');
+                        last_line_start_index = cleaned_pixel_data.len() - 1;
+                    }
+                }
+            }
+            if it_lines.peek().is_some() {
+                cleaned_pixel_data.push('\n');
+                last_line_start_index = cleaned_pixel_data.len() - 1;
+            }
+        }
+
+        cleaned_pixel_data
+    }
+}
+
+impl ImageEncoder for PpmEncoder {
+    fn encode(&self, canvas: &Canvas, writer: &mut dyn Write) -> IoResult<()> {
+        match self.format {
+            PpmFormat::Plain => writer.write_all(self.plain_ppm(canvas).data().as_bytes()),
+            PpmFormat::Binary(depth) => {
+                let mut ppm_writer =
+                    PpmWriter::new(writer, canvas.width(), canvas.height(), depth)?
+                        .with_pipeline(self.pipeline);
+                for y in 0..canvas.height() {
+                    ppm_writer.write_row(canvas.row(y))?;
+                }
+                ppm_writer.finish().map(|_| ())
+            }
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        "ppm"
+    }
+
+    fn pipeline(&self) -> ExportPipeline {
+        self.pipeline
+    }
+}
+
+/// Streams a binary (P6) PPM image to a `Write`, one row at a time, so that the whole file never has to be
+/// held in memory.
+///
+/// The header is written by `new`, each call to `write_row` writes the next row, and `finish` checks that
+/// the image is complete before returning the inner writer.
+#[derive(Debug)]
+pub struct PpmWriter<W: Write> {
+    writer: W,
+    width: usize,
+    height: usize,
+    depth: PpmDepth,
+    pipeline: ExportPipeline,
+    rows_written: usize,
+    /// Bytes of the row being written, reused between rows.
+    row: Vec<u8>,
+}
+
+impl<W: Write> PpmWriter<W> {
+    /// Writes the header of a `width` x `height` image to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the header cannot be written.
+    pub fn new(mut writer: W, width: usize, height: usize, depth: PpmDepth) -> IoResult<Self> {
+        write!(
+            writer,
+            "P6\n{width} {height}\n{}\n",
+            depth.max_color_value()
+        )?;
+        Ok(Self {
+            writer,
+            width,
+            height,
+            depth,
+            pipeline: ExportPipeline::linear(),
+            rows_written: 0,
+            row: Vec::with_capacity(width * 3 * depth.bytes_per_value()),
+        })
+    }
+
+    /// Makes the rows written from now on go through `pipeline`, instead of being written linearly.
+    pub fn with_pipeline(mut self, pipeline: ExportPipeline) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+
+    pub const fn rows_written(&self) -> usize {
+        self.rows_written
+    }
+
+    /// Writes the next row of the image.
+    ///
+    /// # Errors
+    ///
+    /// Will return an `InvalidInput` error if `row` is not `width` pixels long or if all the rows have
+    /// already been written, or the error of the inner writer.
+    pub fn write_row(&mut self, row: &[Color]) -> IoResult<()> {
+        if row.len() != self.width {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!("expected a row of {} pixels, got {}", self.width, row.len()),
+            ));
+        }
+        if self.rows_written == self.height {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!("all the {} rows have already been written", self.height),
+            ));
+        }
+
+        self.row.clear();
+        let max = self.depth.max_color_value();
+        for (x, color) in row.iter().enumerate() {
+            for value in self.pipeline.quantize(color, x, self.rows_written, max) {
+                match self.depth {
+                    // Values are at most 255 at this depth, so their low byte holds all of them.
+                    PpmDepth::Eight => self.row.push(value.to_be_bytes()[1]),
+                    PpmDepth::Sixteen => self.row.extend_from_slice(&value.to_be_bytes()),
+                }
+            }
+        }
+        self.writer.write_all(&self.row)?;
+        self.rows_written += 1;
+        Ok(())
+    }
+
+    /// Flushes the image and returns the inner writer.
+    ///
+    /// # Errors
+    ///
+    /// Will return an `InvalidInput` error if some rows have not been written, or the error of the inner
+    /// writer.
+    pub fn finish(mut self) -> IoResult<W> {
+        if self.rows_written != self.height {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "only {} of the {} rows have been written",
+                    self.rows_written, self.height
+                ),
+            ));
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
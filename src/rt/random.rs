@@ -0,0 +1,30 @@
+/// Small seedable pseudo-random number generator (`SplitMix64`).
+///
+/// It is not cryptographically secure, but it is fast, has no dependencies, and always produces
+/// the same sequence for the same seed, which keeps renders reproducible (and identical whatever
+/// the number of threads used).
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number uniformly distributed in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        // The 52 most significant bits become the mantissa of a `f64` in [1, 2), which is then
+        // shifted down to [0, 1) (the subtraction is exact).
+        f64::from_bits(0x3FF0_0000_0000_0000 | (self.next_u64() >> 12)) - 1.0
+    }
+}
@@ -1,7 +1,8 @@
 use {
     super::{
-        intersections::Intersections, matrix::Matrix, object::Object, plane::Plane, shape::Shape,
-        world::World,
+        bounding_box::BoundingBox, cone::Cone, csg::Csg, cube::Cube, cylinder::Cylinder,
+        group::Group, intersections::Intersections, matrix::Matrix, object::Object, plane::Plane,
+        shape::Shape, smooth_triangle::SmoothTriangle, triangle::Triangle, world::World,
     },
     crate::{
         approx_eq::ApproxEq,
@@ -121,12 +122,291 @@ impl<'object> Intersect<'object, Plane, [f64; 2]> for Ray {
     }
 }
 
+impl Ray {
+    /// Returns the distances at which the ray crosses the two planes bounding
+    /// a box on one axis (at `min` and `max`), as `(tmin, tmax)`. If the ray is parallel
+    /// to those planes, the distances are infinite with the sign given by the origin.
+    fn check_axis(origin: f64, direction: f64, min: f64, max: f64) -> (f64, f64) {
+        let tmin_numerator = min - origin;
+        let tmax_numerator = max - origin;
+        let (tmin, tmax) = if direction.abs() >= EPSILON {
+            (tmin_numerator / direction, tmax_numerator / direction)
+        } else {
+            (
+                tmin_numerator * f64::INFINITY,
+                tmax_numerator * f64::INFINITY,
+            )
+        };
+
+        if tmin > tmax {
+            (tmax, tmin)
+        } else {
+            (tmin, tmax)
+        }
+    }
+
+    /// A box is made of three pairs of parallel planes (slabs). The ray hits the box
+    /// if the intervals in which it lies between each pair of planes overlap:
+    /// the largest minimum and the smallest maximum of those intervals are the intersections.
+    fn intersect_box(&self, min: &Point, max: &Point) -> Option<[f64; 2]> {
+        let (xtmin, xtmax) =
+            Self::check_axis(self.origin.x(), self.direction.x(), min.x(), max.x());
+        let (ytmin, ytmax) =
+            Self::check_axis(self.origin.y(), self.direction.y(), min.y(), max.y());
+        let (ztmin, ztmax) =
+            Self::check_axis(self.origin.z(), self.direction.z(), min.z(), max.z());
+
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+
+        if tmin > tmax {
+            None
+        } else {
+            Some([tmin, tmax])
+        }
+    }
+}
+
+impl Ray {
+    /// Returns true if the intersection at `t` is within `radius` from the y axis.
+    fn check_cap(&self, t: f64, radius: f64) -> bool {
+        let x = self.origin.x() + t * self.direction.x();
+        let z = self.origin.z() + t * self.direction.z();
+
+        x.powi(2) + z.powi(2) <= radius.powi(2)
+    }
+
+    /// Adds to `xs` the intersections of the ray with the caps of a closed shape
+    /// truncated between `minimum` and `maximum` along the y axis.
+    /// `radius` returns the radius of the cap at a given y.
+    fn intersect_caps(
+        &self,
+        minimum: f64,
+        maximum: f64,
+        radius: impl Fn(f64) -> f64,
+        xs: &mut Vec<f64>,
+    ) {
+        // Caps only matter if the ray is not parallel to them.
+        if self.direction.y().abs() < EPSILON {
+            return;
+        }
+        for y in [minimum, maximum] {
+            let t = (y - self.origin.y()) / self.direction.y();
+            if self.check_cap(t, radius(y)) {
+                xs.push(t);
+            }
+        }
+    }
+}
+
+impl<'object> Intersect<'object, Cylinder, Vec<f64>> for Ray {
+    /// The ray intersects the sides of the cylinder where x^2 + z^2 = 1, which we solve
+    /// as a quadratic equation like for the sphere, only keeping the intersections that lie
+    /// between the truncation bounds. If the cylinder is closed, we also intersect its caps.
+    fn intersect(&self, cylinder: &Cylinder) -> Option<Vec<f64>> {
+        let transformed_ray = self.transform(&cylinder.transform().inverse().unwrap());
+        let (origin, direction) = (transformed_ray.origin(), transformed_ray.direction());
+        let mut xs = Vec::with_capacity(4);
+
+        let a = direction.x().powi(2) + direction.z().powi(2);
+        // If a is approximately zero, the ray is parallel to the y axis and can only hit the caps.
+        if !a.approx_eq(0.0) {
+            let b = 2.0 * origin.x() * direction.x() + 2.0 * origin.z() * direction.z();
+            let c = origin.x().powi(2) + origin.z().powi(2) - 1.0;
+            let discriminant = b.powi(2) - 4.0 * a * c;
+            if discriminant < 0.0 {
+                return None;
+            }
+            let t0 = (-b - discriminant.sqrt()) / (2.0 * a);
+            let t1 = (-b + discriminant.sqrt()) / (2.0 * a);
+            for t in [t0.min(t1), t0.max(t1)] {
+                let y = origin.y() + t * direction.y();
+                if cylinder.minimum() < y && y < cylinder.maximum() {
+                    xs.push(t);
+                }
+            }
+        }
+        if cylinder.closed() {
+            transformed_ray.intersect_caps(
+                cylinder.minimum(),
+                cylinder.maximum(),
+                |_| 1.0,
+                &mut xs,
+            );
+        }
+
+        if xs.is_empty() {
+            None
+        } else {
+            Some(xs)
+        }
+    }
+}
+
+impl<'object> Intersect<'object, Cone, Vec<f64>> for Ray {
+    /// The ray intersects the sides of the cone where x^2 - y^2 + z^2 = 0. Unlike the cylinder,
+    /// `a` being zero doesn't mean the ray misses the sides: it means the ray is parallel to one
+    /// of the nappes, and can still intersect the other one at a single point given by the
+    /// remaining linear equation (`t = -c / 2b`). Only if `b` is zero as well is there no hit.
+    fn intersect(&self, cone: &Cone) -> Option<Vec<f64>> {
+        let transformed_ray = self.transform(&cone.transform().inverse().unwrap());
+        let (origin, direction) = (transformed_ray.origin(), transformed_ray.direction());
+        let mut xs = Vec::with_capacity(4);
+
+        let a = direction.x().powi(2) - direction.y().powi(2) + direction.z().powi(2);
+        let b = 2.0 * origin.x() * direction.x() - 2.0 * origin.y() * direction.y()
+            + 2.0 * origin.z() * direction.z();
+        let c = origin.x().powi(2) - origin.y().powi(2) + origin.z().powi(2);
+
+        let sides = if a.approx_eq(0.0) {
+            if b.approx_eq(0.0) {
+                vec![]
+            } else {
+                vec![-c / (2.0 * b)]
+            }
+        } else {
+            let discriminant = b.powi(2) - 4.0 * a * c;
+            if discriminant < 0.0 {
+                return None;
+            }
+            let t0 = (-b - discriminant.sqrt()) / (2.0 * a);
+            let t1 = (-b + discriminant.sqrt()) / (2.0 * a);
+            vec![t0.min(t1), t0.max(t1)]
+        };
+        for t in sides {
+            let y = origin.y() + t * direction.y();
+            if cone.minimum() < y && y < cone.maximum() {
+                xs.push(t);
+            }
+        }
+        if cone.closed() {
+            transformed_ray.intersect_caps(cone.minimum(), cone.maximum(), f64::abs, &mut xs);
+        }
+
+        if xs.is_empty() {
+            None
+        } else {
+            Some(xs)
+        }
+    }
+}
+
+impl Ray {
+    /// Möller–Trumbore intersection of the ray with the triangle starting at `p1`
+    /// and spanned by the edges `e1` and `e2`.
+    /// Returns `[t, u, v]`, `u` and `v` being the barycentric coordinates of the hit
+    /// relatively to `e1` and `e2`.
+    fn intersect_triangle(&self, p1: &Point, e1: &Vector, e2: &Vector) -> Option<[f64; 3]> {
+        let dir_cross_e2 = self.direction.cross(e2);
+        let determinant = e1.dot(&dir_cross_e2);
+        // If the determinant is close to zero, the ray is parallel to the triangle.
+        if determinant.abs() < EPSILON {
+            return None;
+        }
+        let f = 1.0 / determinant;
+        let p1_to_origin = &self.origin - p1;
+        let u = f * p1_to_origin.dot(&dir_cross_e2);
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let origin_cross_e1 = p1_to_origin.cross(e1);
+        let v = f * self.direction.dot(&origin_cross_e1);
+        if v < 0.0 || (u + v) > 1.0 {
+            return None;
+        }
+        let t = f * e2.dot(&origin_cross_e1);
+
+        Some([t, u, v])
+    }
+}
+
+impl<'object> Intersect<'object, Triangle, [f64; 3]> for Ray {
+    /// Returns `[t, u, v]`, see `intersect_triangle`.
+    fn intersect(&self, triangle: &Triangle) -> Option<[f64; 3]> {
+        let transformed_ray = self.transform(&triangle.transform().inverse().unwrap());
+        transformed_ray.intersect_triangle(triangle.p1(), triangle.e1(), triangle.e2())
+    }
+}
+
+impl<'object> Intersect<'object, SmoothTriangle, [f64; 3]> for Ray {
+    /// Returns `[t, u, v]`, see `intersect_triangle`.
+    fn intersect(&self, triangle: &SmoothTriangle) -> Option<[f64; 3]> {
+        let transformed_ray = self.transform(&triangle.transform().inverse().unwrap());
+        transformed_ray.intersect_triangle(triangle.p1(), triangle.e1(), triangle.e2())
+    }
+}
+
+impl<'object> Intersect<'object, Cube, [f64; 2]> for Ray {
+    /// A cube is a box extending from -1.0 to 1.0 on each axis.
+    fn intersect(&self, cube: &Cube) -> Option<[f64; 2]> {
+        let transformed_ray = self.transform(&cube.transform().inverse().unwrap());
+        transformed_ray.intersect_box(&Point::new(-1.0, -1.0, -1.0), &Point::new(1.0, 1.0, 1.0))
+    }
+}
+
+impl<'object> Intersect<'object, BoundingBox, [f64; 2]> for Ray {
+    /// Bounding boxes are already expressed in the space of the ray, so the ray is not transformed.
+    fn intersect(&self, bounds: &BoundingBox) -> Option<[f64; 2]> {
+        self.intersect_box(bounds.min(), bounds.max())
+    }
+}
+
+impl<'object> Intersect<'object, Group, Intersections<'object>> for Ray {
+    /// The ray is converted to the group space, and then intersected with each child
+    /// whose bounding box it hits, which in turn converts it to its own space.
+    fn intersect(&self, group: &'object Group) -> Option<Intersections<'object>> {
+        let transformed_ray = self.transform(&group.transform().inverse().unwrap());
+        let intersections: Vec<_> = group
+            .bvh()
+            .candidates(&transformed_ray)
+            .into_iter()
+            .filter_map(|index| transformed_ray.intersect(&group.children()[index]))
+            .flatten()
+            .collect();
+
+        if intersections.is_empty() {
+            None
+        } else {
+            Some(Intersections::new(intersections))
+        }
+    }
+}
+
+impl<'object> Intersect<'object, Csg, Intersections<'object>> for Ray {
+    /// The ray is converted to the CSG space and intersected with both operands.
+    /// The resulting intersections are then filtered according to the CSG operation.
+    fn intersect(&self, csg: &'object Csg) -> Option<Intersections<'object>> {
+        let transformed_ray = self.transform(&csg.transform().inverse().unwrap());
+        let intersections: Vec<_> = [csg.left(), csg.right()]
+            .into_iter()
+            .filter_map(|operand| transformed_ray.intersect(operand))
+            .flatten()
+            .collect();
+        let intersections = csg.filter_intersections(Intersections::new(intersections));
+
+        if intersections.is_empty() {
+            None
+        } else {
+            Some(intersections)
+        }
+    }
+}
+
 impl<'object> Intersect<'object, Object, Intersections<'object>> for Ray {
     /// Returns a list of intersections with the object.
     fn intersect(&self, object: &'object Object) -> Option<Intersections<'object>> {
         match object {
             Object::Sphere(sphere) => self.intersect(sphere).map(|xs| (xs, object).into()),
             Object::Plane(plane) => self.intersect(plane).map(|xs| (xs, object).into()),
+            Object::Cube(cube) => self.intersect(cube).map(|xs| (xs, object).into()),
+            Object::Cylinder(cylinder) => self.intersect(cylinder).map(|xs| (xs, object).into()),
+            Object::Cone(cone) => self.intersect(cone).map(|xs| (xs, object).into()),
+            Object::Triangle(triangle) => self.intersect(triangle).map(|xs| (xs, object).into()),
+            Object::SmoothTriangle(triangle) => {
+                self.intersect(triangle).map(|xs| (xs, object).into())
+            }
+            Object::Group(group) => self.intersect(group).map(|xs| xs.with_parent(object)),
+            Object::Csg(csg) => self.intersect(csg).map(|xs| xs.with_parent(object)),
         }
     }
 }
@@ -137,12 +417,13 @@ impl<'object> Intersect<'object, Object, Intersections<'object>> for Ray {
 /// of the shape's transformation matrix.
 impl<'objects> Intersect<'objects, World, Intersections<'objects>> for Ray {
     /// Returns a list of intersections with the objects composing the world.
+    /// Only the objects whose bounding box is hit by the ray are tested.
     fn intersect(&self, world: &'objects World) -> Option<Intersections<'objects>> {
-        world.objects().map(|objects| {
+        world.objects().zip(world.bvh()).map(|(objects, bvh)| {
             Intersections::new(
-                objects
-                    .iter()
-                    .filter_map(|object| self.intersect(object))
+                bvh.candidates(self)
+                    .into_iter()
+                    .filter_map(|index| self.intersect(&objects[index]))
                     .flatten()
                     .collect(),
             )
@@ -1,5 +1,7 @@
 use {
-    super::{material::Material, matrix::Matrix},
+    super::{
+        bounding_box::BoundingBox, intersection::Intersection, material::Material, matrix::Matrix,
+    },
     crate::tuple::{point::Point, vector::Vector},
 };
 
@@ -7,6 +9,10 @@ use {
 pub trait Shape {
     fn transform(&self) -> &Matrix<4>;
     fn set_transform(&mut self, transform: Matrix<4>);
+    /// Whether the object blocks the light of the world's lights. Objects cast shadows by default.
+    /// Groups and CSGs apply it to all their children: a child only casts shadows if all its parents do.
+    fn casts_shadow(&self) -> bool;
+    fn set_casts_shadow(&mut self, casts_shadow: bool);
     fn material(&self) -> &Material;
     fn material_mut(&mut self) -> &mut Material;
     fn set_material(&mut self, material: Material);
@@ -18,5 +24,16 @@ pub trait Shape {
     /// to world space by multiplying it by the inverse
     /// of the transpose of the shape transformation matrix,
     /// and finally be normalized.
-    fn normal_at(&self, point: &Point) -> Vector;
+    /// `hit` is the intersection the normal is computed for. Most shapes
+    /// ignore it, but smooth triangles need its `u` and `v` to interpolate
+    /// the normal.
+    fn normal_at(&self, point: &Point, hit: &Intersection) -> Vector;
+    /// Returns the box bounding the shape in object space.
+    fn bounds(&self) -> BoundingBox;
+    /// Returns the box bounding the shape in the space of its parent
+    /// (or in world space if the shape doesn't belong to a group),
+    /// that is the object space bounds transformed by the shape transformation matrix.
+    fn parent_space_bounds(&self) -> BoundingBox {
+        self.bounds().transform(self.transform())
+    }
 }
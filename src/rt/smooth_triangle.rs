@@ -0,0 +1,124 @@
+use {
+    super::{
+        bounding_box::BoundingBox, intersection::Intersection, material::Material, matrix::Matrix,
+        shape::Shape,
+    },
+    crate::tuple::{point::Point, vector::Vector},
+};
+
+/// A triangle with a normal at each vertex. The normal at any point of the triangle
+/// is interpolated from the vertex normals, using the `u` and `v` of the intersection,
+/// which gives the illusion of a curved surface when used in a mesh.
+#[derive(PartialEq, Debug)]
+pub struct SmoothTriangle {
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    n1: Vector,
+    n2: Vector,
+    n3: Vector,
+    e1: Vector,
+    e2: Vector,
+    transform: Matrix<4>,
+    material: Material,
+    casts_shadow: bool,
+}
+
+impl SmoothTriangle {
+    pub fn new(p1: Point, p2: Point, p3: Point, n1: Vector, n2: Vector, n3: Vector) -> Self {
+        let e1 = &p2 - &p1;
+        let e2 = &p3 - &p1;
+
+        Self {
+            p1,
+            p2,
+            p3,
+            n1,
+            n2,
+            n3,
+            e1,
+            e2,
+            transform: Matrix::<4>::identity(),
+            material: Material::default(),
+            casts_shadow: true,
+        }
+    }
+
+    pub const fn p1(&self) -> &Point {
+        &self.p1
+    }
+
+    pub const fn p2(&self) -> &Point {
+        &self.p2
+    }
+
+    pub const fn p3(&self) -> &Point {
+        &self.p3
+    }
+
+    pub const fn n1(&self) -> &Vector {
+        &self.n1
+    }
+
+    pub const fn n2(&self) -> &Vector {
+        &self.n2
+    }
+
+    pub const fn n3(&self) -> &Vector {
+        &self.n3
+    }
+
+    pub const fn e1(&self) -> &Vector {
+        &self.e1
+    }
+
+    pub const fn e2(&self) -> &Vector {
+        &self.e2
+    }
+}
+
+impl Shape for SmoothTriangle {
+    fn transform(&self) -> &Matrix<4> {
+        &self.transform
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn material_mut(&mut self) -> &mut Material {
+        &mut self.material
+    }
+
+    fn set_transform(&mut self, transform: Matrix<4>) {
+        self.transform = transform;
+    }
+
+    fn casts_shadow(&self) -> bool {
+        self.casts_shadow
+    }
+
+    fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        self.casts_shadow = casts_shadow;
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn normal_at(&self, _: &Point, hit: &Intersection) -> Vector {
+        let object_normal =
+            &self.n2 * hit.u() + &self.n3 * hit.v() + &self.n1 * (1.0 - hit.u() - hit.v());
+        let world_normal = self.transform.inverse().unwrap().transpose() * object_normal;
+
+        world_normal.normalized()
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        for point in [&self.p1, &self.p2, &self.p3] {
+            bounds.add_point(point);
+        }
+        bounds
+    }
+}
@@ -1,5 +1,8 @@
 use {
-    super::{color::WHITE, material::Material, matrix::Matrix, shape::Shape},
+    super::{
+        bounding_box::BoundingBox, color::WHITE, intersection::Intersection, material::Material,
+        matrix::Matrix, shape::Shape,
+    },
     crate::tuple::{point::Point, vector::Vector},
 };
 
@@ -8,6 +11,7 @@ pub struct Sphere {
     origin: Point,
     transform: Matrix<4>,
     material: Material,
+    casts_shadow: bool,
 }
 
 impl Sphere {
@@ -16,6 +20,7 @@ impl Sphere {
             origin,
             transform,
             material,
+            casts_shadow: true,
         }
     }
 
@@ -65,12 +70,20 @@ impl Shape for Sphere {
         self.transform = transform;
     }
 
+    fn casts_shadow(&self) -> bool {
+        self.casts_shadow
+    }
+
+    fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        self.casts_shadow = casts_shadow;
+    }
+
     fn set_material(&mut self, material: Material) {
         self.material = material;
     }
 
     /// Returns the normal vector at a specified point.
-    fn normal_at(&self, point: &Point) -> Vector {
+    fn normal_at(&self, point: &Point, _: &Intersection) -> Vector {
         // Convert the point from world space to object space
         let object_point = &self.transform.inverse().unwrap() * point;
         let object_normal = &object_point - &self.origin;
@@ -79,6 +92,21 @@ impl Shape for Sphere {
 
         world_normal.normalized()
     }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(
+                self.origin.x() - 1.0,
+                self.origin.y() - 1.0,
+                self.origin.z() - 1.0,
+            ),
+            Point::new(
+                self.origin.x() + 1.0,
+                self.origin.y() + 1.0,
+                self.origin.z() + 1.0,
+            ),
+        )
+    }
 }
 
 impl Default for Sphere {
@@ -88,6 +116,7 @@ impl Default for Sphere {
             origin: Point::new(0.0, 0.0, 0.0),
             transform: Matrix::<4>::identity(),
             material: Material::default(),
+            casts_shadow: true,
         }
     }
 }
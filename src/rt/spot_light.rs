@@ -0,0 +1,105 @@
+use {
+    super::{
+        attenuation::Attenuation,
+        color::Color,
+        light_source::{LightSample, LightSource},
+    },
+    crate::tuple::{point::Point, vector::Vector},
+};
+
+/// A light located at `position` and shining along `direction`, inside a cone.
+/// Points within `inner_angle` of the direction get the full intensity, points beyond `outer_angle`
+/// get no light at all, and the intensity falls off smoothly in between.
+#[derive(PartialEq, Debug)]
+pub struct SpotLight {
+    position: Point,
+    direction: Vector,
+    inner_angle: f64,
+    outer_angle: f64,
+    intensity: Color,
+    attenuation: Attenuation,
+}
+
+impl SpotLight {
+    /// Angles are half-angles of the cones, in radians.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `inner_angle` is greater than `outer_angle`.
+    pub fn new(
+        position: Point,
+        direction: &Vector,
+        inner_angle: f64,
+        outer_angle: f64,
+        intensity: Color,
+    ) -> Self {
+        assert!(
+            inner_angle <= outer_angle,
+            "The inner cone of a spot light cannot be wider than its outer cone"
+        );
+        Self {
+            position,
+            direction: direction.normalized(),
+            inner_angle,
+            outer_angle,
+            intensity,
+            attenuation: Attenuation::None,
+        }
+    }
+
+    /// Makes the light fade with distance.
+    pub const fn with_attenuation(mut self, attenuation: Attenuation) -> Self {
+        self.attenuation = attenuation;
+        self
+    }
+
+    pub const fn position(&self) -> &Point {
+        &self.position
+    }
+
+    pub const fn direction(&self) -> &Vector {
+        &self.direction
+    }
+
+    pub const fn inner_angle(&self) -> f64 {
+        self.inner_angle
+    }
+
+    pub const fn outer_angle(&self) -> f64 {
+        self.outer_angle
+    }
+
+    /// Returns the fraction of the intensity of the light that reaches `point`, depending on its angle
+    /// to the axis of the spot.
+    pub fn falloff(&self, point: &Point) -> f64 {
+        let cos_angle = (point - &self.position).normalized().dot(&self.direction);
+        let (cos_inner, cos_outer) = (self.inner_angle.cos(), self.outer_angle.cos());
+        if cos_angle >= cos_inner {
+            1.0
+        } else if cos_angle <= cos_outer {
+            0.0
+        } else {
+            // Smoothstep between the outer and inner cones.
+            let t = (cos_angle - cos_outer) / (cos_inner - cos_outer);
+            t * t * 2.0f64.mul_add(-t, 3.0)
+        }
+    }
+}
+
+impl LightSource for SpotLight {
+    fn intensity(&self) -> &Color {
+        &self.intensity
+    }
+
+    fn samples(&self, point: &Point) -> Vec<LightSample> {
+        vec![LightSample::towards(
+            point,
+            &self.position,
+            self.intensity * self.falloff(point),
+        )]
+    }
+
+    fn attenuation(&self) -> Attenuation {
+        self.attenuation
+    }
+}
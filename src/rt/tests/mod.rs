@@ -1,12 +1,32 @@
+pub mod tests_anti_aliasing;
+pub mod tests_attenuation;
+pub mod tests_bounding_box;
+pub mod tests_bvh;
 pub mod tests_camera;
 pub mod tests_canvas;
 pub mod tests_color;
+pub mod tests_cone;
+pub mod tests_csg;
+pub mod tests_cube;
+pub mod tests_cylinder;
+pub mod tests_export_pipeline;
+pub mod tests_group;
+pub mod tests_hdr;
 pub mod tests_intersection;
 pub mod tests_light;
 pub mod tests_material;
 pub mod tests_matrix;
+pub mod tests_noise;
+pub mod tests_obj_parser;
 pub mod tests_patterns;
 pub mod tests_plane;
+pub mod tests_png;
+pub mod tests_ppm_reader;
+pub mod tests_ppm_writer;
 pub mod tests_ray;
+pub mod tests_smooth_triangle;
 pub mod tests_sphere;
+pub mod tests_triangle;
+pub mod tests_uv_mapping;
+pub mod tests_uv_pattern;
 pub mod tests_world;
@@ -0,0 +1,182 @@
+#[cfg(test)]
+use {
+    crate::{
+        rt::{
+            anti_aliasing::AntiAliasing,
+            camera::Camera,
+            color::{Color, BLACK, WHITE},
+            matrix::Matrix,
+            world::World,
+        },
+        tuple::{point::Point, vector::Vector},
+    },
+    std::{cell::RefCell, f64::consts::PI},
+};
+
+/// Records every sample offset requested by an `AntiAliasing`, and returns white on the left half of the pixel,
+/// black on the right half.
+/// The vertical line through the center of the pixel is white.
+#[cfg(test)]
+fn sample_half_white(samples: &RefCell<Vec<(f64, f64)>>) -> impl Fn(f64, f64) -> Color + '_ {
+    move |x, y| {
+        samples.borrow_mut().push((x, y));
+        if x <= 0.5 {
+            WHITE
+        } else {
+            BLACK
+        }
+    }
+}
+
+#[test]
+fn no_anti_aliasing_samples_the_center_of_the_pixel() {
+    let samples = RefCell::new(Vec::new());
+    let color = AntiAliasing::None.sample(3, 4, sample_half_white(&samples));
+    assert_eq!(samples.into_inner(), vec![(0.5, 0.5)]);
+    assert_eq!(color, WHITE);
+}
+
+#[test]
+fn grid_anti_aliasing_samples_the_centers_of_the_sub_pixels() {
+    let samples = RefCell::new(Vec::new());
+    let color = AntiAliasing::Grid(2).sample(3, 4, sample_half_white(&samples));
+    assert_eq!(
+        samples.into_inner(),
+        vec![(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)]
+    );
+    assert_eq!(color, Color::new(0.5, 0.5, 0.5));
+}
+
+#[test]
+fn jittered_anti_aliasing_samples_once_inside_each_sub_pixel() {
+    let samples = RefCell::new(Vec::new());
+    let aa = AntiAliasing::Jittered { grid: 3, seed: 42 };
+    aa.sample(3, 4, sample_half_white(&samples));
+    let samples = samples.into_inner();
+    assert_eq!(samples.len(), 9);
+    for ((x, y), n) in samples.iter().zip(0_u32..) {
+        let (i, j) = (f64::from(n % 3), f64::from(n / 3));
+        assert!(*x >= i / 3.0 && *x < (i + 1.0) / 3.0);
+        assert!(*y >= j / 3.0 && *y < (j + 1.0) / 3.0);
+    }
+}
+
+#[test]
+fn jittered_anti_aliasing_is_reproducible() {
+    let aa = AntiAliasing::Jittered { grid: 2, seed: 7 };
+    let first = RefCell::new(Vec::new());
+    let second = RefCell::new(Vec::new());
+    let other_pixel = RefCell::new(Vec::new());
+    aa.sample(3, 4, sample_half_white(&first));
+    aa.sample(3, 4, sample_half_white(&second));
+    aa.sample(4, 3, sample_half_white(&other_pixel));
+    assert_eq!(first, second);
+    assert_ne!(first, other_pixel);
+}
+
+#[test]
+fn adaptive_anti_aliasing_does_not_refine_uniform_pixels() {
+    let samples = RefCell::new(Vec::new());
+    let aa = AntiAliasing::Adaptive {
+        threshold: 0.1,
+        max_depth: 3,
+    };
+    let color = aa.sample(0, 0, |x, y| {
+        samples.borrow_mut().push((x, y));
+        WHITE
+    });
+    assert_eq!(samples.into_inner().len(), 4);
+    assert_eq!(color, WHITE);
+}
+
+#[test]
+fn adaptive_anti_aliasing_refines_pixels_on_edges() {
+    let aa = AntiAliasing::Adaptive {
+        threshold: 0.1,
+        max_depth: 1,
+    };
+    let samples = RefCell::new(Vec::new());
+    let color = aa.sample(0, 0, sample_half_white(&samples));
+    // 4 corners, then the 5 points splitting the pixel into quadrants.
+    assert_eq!(samples.into_inner().len(), 9);
+    // The quadrants on the left are white, the ones on the right straddle the edge.
+    assert_eq!(color, Color::new(0.75, 0.75, 0.75));
+
+    let deeper = AntiAliasing::Adaptive {
+        threshold: 0.1,
+        max_depth: 4,
+    };
+    let samples = RefCell::new(Vec::new());
+    let color = deeper.sample(0, 0, sample_half_white(&samples));
+    assert!(samples.into_inner().len() > 9);
+    assert!(color.red() < 0.75 && color.red() > 0.5);
+}
+
+#[test]
+fn a_ray_through_the_center_of_a_sub_pixel_is_the_ray_for_the_pixel() {
+    let c = Camera::new(201.0, 101.0, PI / 2.0, None);
+    let (sub_pixel, pixel) = (
+        c.ray_for_subpixel(100.0, 50.0, 0.5, 0.5),
+        c.ray_for_pixel(100.0, 50.0),
+    );
+    assert_eq!(sub_pixel.origin(), pixel.origin());
+    assert_eq!(sub_pixel.direction(), pixel.direction());
+}
+
+#[test]
+fn constructing_a_ray_through_the_corner_of_a_pixel() {
+    let c = Camera::new(201.0, 101.0, PI / 2.0, None);
+    let r = c.ray_for_subpixel(0.0, 0.0, 0.0, 0.0);
+    assert_eq!(r.origin(), &Point::new(0.0, 0.0, 0.0));
+    let x = c.half_width();
+    let y = c.half_height();
+    let length = (x * x + y * y + 1.0).sqrt();
+    assert_eq!(
+        r.direction(),
+        &Vector::new(x / length, y / length, -1.0 / length)
+    );
+}
+
+#[test]
+fn rendering_with_a_one_sample_grid_matches_no_anti_aliasing() {
+    let w = World::default();
+    let transform = Matrix::<4>::view_transform(
+        &Point::new(0.0, 0.0, -5.0),
+        &Point::new(0.0, 0.0, 0.0),
+        &Vector::new(0.0, 1.0, 0.0),
+    );
+    let c = Camera::new(11.0, 11.0, PI / 2.0, Some(transform.clone()));
+    let grid = Camera::new(11.0, 11.0, PI / 2.0, Some(transform))
+        .with_anti_aliasing(AntiAliasing::Grid(1));
+    assert_eq!(c.render(&w).pixels(), grid.render(&w).pixels());
+}
+
+#[test]
+fn anti_aliasing_blends_the_edges_of_a_sphere() {
+    let w = World::default();
+    let transform = Matrix::<4>::view_transform(
+        &Point::new(0.0, 0.0, -5.0),
+        &Point::new(0.0, 0.0, 0.0),
+        &Vector::new(0.0, 1.0, 0.0),
+    );
+    let aliased = Camera::new(11.0, 11.0, PI / 2.0, Some(transform.clone())).render(&w);
+    let smooth = Camera::new(11.0, 11.0, PI / 2.0, Some(transform))
+        .with_anti_aliasing(AntiAliasing::Grid(4))
+        .render(&w);
+    // Far from the sphere, every sample misses.
+    assert_eq!(smooth.pixel_at(0, 0), &BLACK);
+    // On the silhouette, the first lit pixel of the row is darker once part of it sees the background.
+    let first_lit = (0..11).find(|&x| aliased.pixel_at(x, 5) != &BLACK).unwrap();
+    assert!(
+        smooth.pixel_at(first_lit - 1, 5) != &BLACK || {
+            smooth.pixel_at(first_lit, 5).green() < aliased.pixel_at(first_lit, 5).green()
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Anti-aliasing grid size must be at least 1")]
+fn anti_aliasing_with_an_empty_grid_panics() {
+    let mut c = Camera::new(11.0, 11.0, PI / 2.0, None);
+    c.set_anti_aliasing(AntiAliasing::Jittered { grid: 0, seed: 1 });
+}
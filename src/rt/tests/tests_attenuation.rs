@@ -0,0 +1,120 @@
+#[cfg(test)]
+use crate::{
+    approx_eq::ApproxEq,
+    rt::{
+        area_light::AreaLight,
+        attenuation::Attenuation,
+        color::{Color, WHITE},
+        directional_light::DirectionalLight,
+        light::Light,
+        light_source::LightSource,
+        material::Material,
+        object::Object,
+        point_light::PointLight,
+        sphere::Sphere,
+        spot_light::SpotLight,
+    },
+    tuple::{point::Point, vector::Vector},
+};
+
+#[test]
+fn no_attenuation_keeps_the_intensity_at_any_distance() {
+    for distance in [0.0, 1.0, 10.0, 1000.0, f64::INFINITY] {
+        assert!(Attenuation::None.factor(distance).approx_eq(1.0));
+    }
+}
+
+#[test]
+fn inverse_square_attenuation() {
+    assert!(Attenuation::InverseSquare.factor(1.0).approx_eq(1.0));
+    assert!(Attenuation::InverseSquare.factor(2.0).approx_eq(0.25));
+    assert!(Attenuation::InverseSquare.factor(10.0).approx_eq(0.01));
+    assert!(Attenuation::InverseSquare
+        .factor(f64::INFINITY)
+        .approx_eq(0.0));
+}
+
+#[test]
+fn attenuation_stays_finite_on_the_light() {
+    let attenuations = [
+        Attenuation::InverseSquare,
+        Attenuation::coefficients(0.0, 1.0, 0.0),
+        Attenuation::coefficients(0.0, 0.0, 1.0),
+    ];
+    for attenuation in attenuations {
+        let factor = attenuation.factor(0.0);
+        assert!(factor.is_finite() && factor > 0.0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Attenuation coefficients cannot all be 0")]
+fn attenuation_coefficients_cannot_all_be_zero() {
+    Attenuation::coefficients(0.0, 0.0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Attenuation coefficients must be finite and non-negative")]
+fn attenuation_coefficients_cannot_be_negative() {
+    Attenuation::coefficients(1.0, -0.5, 0.0);
+}
+
+#[test]
+fn attenuation_with_constant_linear_and_quadratic_coefficients() {
+    let attenuation = Attenuation::coefficients(1.0, 0.5, 0.25);
+    assert!(attenuation.factor(0.0).approx_eq(1.0));
+    // 1 / (1 + 0.5 * 2 + 0.25 * 4)
+    assert!(attenuation.factor(2.0).approx_eq(1.0 / 3.0));
+    // 1 / (1 + 0.5 * 4 + 0.25 * 16)
+    assert!(attenuation.factor(4.0).approx_eq(1.0 / 7.0));
+}
+
+#[test]
+fn lights_are_not_attenuated_by_default() {
+    let point = PointLight::new(Point::new(0.0, 0.0, 0.0), WHITE);
+    let spot = SpotLight::new(
+        Point::new(0.0, 0.0, 0.0),
+        &Vector::new(0.0, -1.0, 0.0),
+        0.5,
+        1.0,
+        WHITE,
+    );
+    let area = AreaLight::new(
+        Point::new(0.0, 0.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0),
+        1,
+        Vector::new(0.0, 1.0, 0.0),
+        1,
+        WHITE,
+    );
+    let directional = DirectionalLight::new(&Vector::new(0.0, -1.0, 0.0), WHITE);
+    assert_eq!(point.attenuation(), Attenuation::None);
+    assert_eq!(spot.attenuation(), Attenuation::None);
+    assert_eq!(area.attenuation(), Attenuation::None);
+    assert_eq!(directional.attenuation(), Attenuation::None);
+}
+
+#[test]
+fn a_light_with_an_attenuation() {
+    let light = Light::from(
+        PointLight::new(Point::new(0.0, 0.0, 0.0), WHITE)
+            .with_attenuation(Attenuation::InverseSquare),
+    );
+    assert_eq!(light.attenuation(), Attenuation::InverseSquare);
+}
+
+#[test]
+fn lighting_attenuates_the_diffuse_and_specular_terms() {
+    let object = Object::Sphere(Sphere::default());
+    let m = Material::default();
+    let position = Point::new(0.0, 0.0, 0.0);
+    let eye = Vector::new(0.0, 0.0, -1.0);
+    let normal = Vector::new(0.0, 0.0, -1.0);
+    let light = Light::from(
+        PointLight::new(Point::new(0.0, 0.0, -10.0), WHITE)
+            .with_attenuation(Attenuation::InverseSquare),
+    );
+    let result = m.lighting(&object, &light, &position, &eye, &normal, WHITE);
+    // Ambient (0.1) is not attenuated, diffuse (0.9) and specular (0.9) are divided by 10².
+    assert_eq!(result, Color::new(0.118, 0.118, 0.118));
+}
@@ -0,0 +1,318 @@
+#[cfg(test)]
+use {
+    crate::{
+        rt::{
+            bounding_box::BoundingBox,
+            cone::Cone,
+            csg::{Csg, CsgOperation},
+            cube::Cube,
+            cylinder::Cylinder,
+            group::Group,
+            matrix::Matrix,
+            object::Object,
+            plane::Plane,
+            ray::{Intersect, Ray},
+            shape::Shape,
+            sphere::Sphere,
+            triangle::Triangle,
+        },
+        tuple::{point::Point, vector::Vector},
+    },
+    std::f64::consts::{PI, SQRT_2},
+};
+
+#[test]
+fn creating_an_empty_bounding_box() {
+    let bounds = BoundingBox::empty();
+    assert!(bounds.min().x().is_infinite() && bounds.min().x() > 0.0);
+    assert!(bounds.max().x().is_infinite() && bounds.max().x() < 0.0);
+    assert!(!bounds.contains_point(&Point::new(0.0, 0.0, 0.0)));
+}
+
+#[test]
+fn adding_points_to_an_empty_bounding_box() {
+    let mut bounds = BoundingBox::empty();
+    bounds.add_point(&Point::new(-5.0, 2.0, 0.0));
+    bounds.add_point(&Point::new(7.0, 0.0, -3.0));
+    assert_eq!(bounds.min(), &Point::new(-5.0, 0.0, -3.0));
+    assert_eq!(bounds.max(), &Point::new(7.0, 2.0, 0.0));
+}
+
+#[test]
+fn adding_one_bounding_box_to_another() {
+    let box1 = BoundingBox::new(Point::new(-5.0, -2.0, 0.0), Point::new(7.0, 4.0, 4.0));
+    let box2 = BoundingBox::new(Point::new(8.0, -7.0, -2.0), Point::new(14.0, 2.0, 8.0));
+    assert_eq!(
+        box1 + &box2,
+        BoundingBox::new(Point::new(-5.0, -7.0, -2.0), Point::new(14.0, 4.0, 8.0))
+    );
+}
+
+#[test]
+fn checking_to_see_if_a_box_contains_a_given_point() {
+    let bounds = BoundingBox::new(Point::new(5.0, -2.0, 0.0), Point::new(11.0, 4.0, 7.0));
+    let examples = [
+        (Point::new(5.0, -2.0, 0.0), true),
+        (Point::new(11.0, 4.0, 7.0), true),
+        (Point::new(8.0, 1.0, 3.0), true),
+        (Point::new(3.0, 0.0, 3.0), false),
+        (Point::new(8.0, -4.0, 3.0), false),
+        (Point::new(8.0, 1.0, -1.0), false),
+        (Point::new(13.0, 1.0, 3.0), false),
+        (Point::new(8.0, 5.0, 3.0), false),
+        (Point::new(8.0, 1.0, 8.0), false),
+    ];
+    for (point, result) in examples {
+        assert_eq!(bounds.contains_point(&point), result);
+    }
+}
+
+#[test]
+fn checking_to_see_if_a_box_contains_a_given_box() {
+    let bounds = BoundingBox::new(Point::new(5.0, -2.0, 0.0), Point::new(11.0, 4.0, 7.0));
+    let examples = [
+        (Point::new(5.0, -2.0, 0.0), Point::new(11.0, 4.0, 7.0), true),
+        (Point::new(6.0, -1.0, 1.0), Point::new(10.0, 3.0, 6.0), true),
+        (
+            Point::new(4.0, -3.0, -1.0),
+            Point::new(10.0, 3.0, 6.0),
+            false,
+        ),
+        (
+            Point::new(6.0, -1.0, 1.0),
+            Point::new(12.0, 5.0, 8.0),
+            false,
+        ),
+    ];
+    for (min, max, result) in examples {
+        assert_eq!(bounds.contains_box(&BoundingBox::new(min, max)), result);
+    }
+}
+
+#[test]
+fn transforming_a_bounding_box() {
+    let bounds = BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+    let matrix = Matrix::<4>::rotation_x(PI / 4.0) * Matrix::<4>::rotation_y(PI / 4.0);
+    let transformed = bounds.transform(&matrix);
+    assert_eq!(transformed.min(), &Point::new(-SQRT_2, -1.70711, -1.70711));
+    assert_eq!(transformed.max(), &Point::new(SQRT_2, 1.70711, 1.70711));
+}
+
+#[test]
+fn transforming_an_infinite_bounding_box() {
+    let bounds = Plane::default().bounds();
+    let translated = bounds.transform(&Matrix::<4>::translation(0.0, 2.0, 0.0));
+    assert_eq!(
+        translated,
+        BoundingBox::new(
+            Point::new(f64::NEG_INFINITY, 2.0, f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, 2.0, f64::INFINITY)
+        )
+    );
+    let rotated = bounds.transform(&Matrix::<4>::rotation_z(PI / 4.0));
+    assert_eq!(
+        rotated,
+        BoundingBox::new(
+            Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY)
+        )
+    );
+    assert!(!rotated.is_finite());
+}
+
+#[test]
+fn querying_a_shape_bounding_box_in_its_parent_space() {
+    let shape = Sphere::with_transform(
+        Matrix::<4>::translation(1.0, -3.0, 5.0) * Matrix::<4>::scaling(0.5, 2.0, 4.0),
+    );
+    assert_eq!(
+        shape.parent_space_bounds(),
+        BoundingBox::new(Point::new(0.5, -5.0, 1.0), Point::new(1.5, -1.0, 9.0))
+    );
+}
+
+#[test]
+fn the_bounds_of_primitive_shapes() {
+    let examples: [(Object, BoundingBox); 7] = [
+        (
+            Sphere::default().into(),
+            BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0)),
+        ),
+        (
+            Plane::default().into(),
+            BoundingBox::new(
+                Point::new(f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY),
+                Point::new(f64::INFINITY, 0.0, f64::INFINITY),
+            ),
+        ),
+        (
+            Cube::default().into(),
+            BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0)),
+        ),
+        (
+            Cylinder::default().into(),
+            BoundingBox::new(
+                Point::new(-1.0, f64::NEG_INFINITY, -1.0),
+                Point::new(1.0, f64::INFINITY, 1.0),
+            ),
+        ),
+        (
+            Cylinder::truncated(-5.0, 3.0, false).into(),
+            BoundingBox::new(Point::new(-1.0, -5.0, -1.0), Point::new(1.0, 3.0, 1.0)),
+        ),
+        (
+            Cone::truncated(-5.0, 3.0, false).into(),
+            BoundingBox::new(Point::new(-5.0, -5.0, -5.0), Point::new(5.0, 3.0, 5.0)),
+        ),
+        (
+            Triangle::new(
+                Point::new(-3.0, 7.0, 2.0),
+                Point::new(6.0, 2.0, -4.0),
+                Point::new(2.0, -1.0, -1.0),
+            )
+            .into(),
+            BoundingBox::new(Point::new(-3.0, -1.0, -4.0), Point::new(6.0, 7.0, 2.0)),
+        ),
+    ];
+    for (shape, bounds) in examples {
+        assert_eq!(shape.bounds(), bounds);
+    }
+}
+
+#[test]
+fn a_group_has_a_bounding_box_that_contains_its_children() {
+    let s = Sphere::with_transform(
+        Matrix::<4>::translation(2.0, 5.0, -3.0) * Matrix::<4>::scaling(2.0, 2.0, 2.0),
+    );
+    let c = Cylinder::new(
+        Matrix::<4>::translation(-4.0, -1.0, 4.0) * Matrix::<4>::scaling(0.5, 1.0, 0.5),
+        Cylinder::default().material().clone(),
+        -2.0,
+        2.0,
+        false,
+    );
+    let g = Group::new(Matrix::<4>::identity(), vec![s.into(), c.into()]);
+    assert_eq!(
+        g.bounds(),
+        BoundingBox::new(Point::new(-4.5, -3.0, -5.0), Point::new(4.0, 7.0, 4.5))
+    );
+}
+
+#[test]
+fn a_csg_shape_has_a_bounding_box_that_contains_its_children() {
+    let left = Sphere::default();
+    let right = Sphere::with_transform(Matrix::<4>::translation(2.0, 3.0, 4.0));
+    let csg = Csg::new(CsgOperation::Difference, left.into(), right.into());
+    assert_eq!(
+        csg.bounds(),
+        BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(3.0, 4.0, 5.0))
+    );
+}
+
+#[test]
+fn intersecting_a_ray_with_a_bounding_box_at_the_origin() {
+    let bounds = BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+    let examples = [
+        (Point::new(5.0, 0.5, 0.0), Vector::new(-1.0, 0.0, 0.0), true),
+        (Point::new(-5.0, 0.5, 0.0), Vector::new(1.0, 0.0, 0.0), true),
+        (Point::new(0.5, 5.0, 0.0), Vector::new(0.0, -1.0, 0.0), true),
+        (Point::new(0.5, -5.0, 0.0), Vector::new(0.0, 1.0, 0.0), true),
+        (Point::new(0.5, 0.0, 5.0), Vector::new(0.0, 0.0, -1.0), true),
+        (Point::new(0.5, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0), true),
+        (Point::new(0.0, 0.5, 0.0), Vector::new(0.0, 0.0, 1.0), true),
+        (
+            Point::new(-2.0, 0.0, 0.0),
+            Vector::new(2.0, 4.0, 6.0),
+            false,
+        ),
+        (
+            Point::new(0.0, -2.0, 0.0),
+            Vector::new(6.0, 2.0, 4.0),
+            false,
+        ),
+        (
+            Point::new(0.0, 0.0, -2.0),
+            Vector::new(4.0, 6.0, 2.0),
+            false,
+        ),
+        (
+            Point::new(2.0, 0.0, 2.0),
+            Vector::new(0.0, 0.0, -1.0),
+            false,
+        ),
+        (
+            Point::new(0.0, 2.0, 2.0),
+            Vector::new(0.0, -1.0, 0.0),
+            false,
+        ),
+        (
+            Point::new(2.0, 2.0, 0.0),
+            Vector::new(-1.0, 0.0, 0.0),
+            false,
+        ),
+    ];
+    for (origin, direction, result) in examples {
+        let r = Ray::new(origin, direction.normalized());
+        assert_eq!(r.intersect(&bounds).is_some(), result);
+    }
+}
+
+#[test]
+fn intersecting_a_ray_with_a_non_cubic_bounding_box() {
+    let bounds = BoundingBox::new(Point::new(5.0, -2.0, 0.0), Point::new(11.0, 4.0, 7.0));
+    let examples = [
+        (
+            Point::new(15.0, 1.0, 2.0),
+            Vector::new(-1.0, 0.0, 0.0),
+            true,
+        ),
+        (
+            Point::new(-5.0, -1.0, 4.0),
+            Vector::new(1.0, 0.0, 0.0),
+            true,
+        ),
+        (Point::new(7.0, 6.0, 5.0), Vector::new(0.0, -1.0, 0.0), true),
+        (Point::new(9.0, -5.0, 6.0), Vector::new(0.0, 1.0, 0.0), true),
+        (
+            Point::new(8.0, 2.0, 12.0),
+            Vector::new(0.0, 0.0, -1.0),
+            true,
+        ),
+        (Point::new(6.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0), true),
+        (Point::new(8.0, 1.0, 3.5), Vector::new(0.0, 0.0, 1.0), true),
+        (
+            Point::new(9.0, -1.0, -8.0),
+            Vector::new(2.0, 4.0, 6.0),
+            false,
+        ),
+        (
+            Point::new(8.0, 3.0, -4.0),
+            Vector::new(6.0, 2.0, 4.0),
+            false,
+        ),
+        (
+            Point::new(9.0, -1.0, -2.0),
+            Vector::new(4.0, 6.0, 2.0),
+            false,
+        ),
+        (
+            Point::new(4.0, 0.0, 9.0),
+            Vector::new(0.0, 0.0, -1.0),
+            false,
+        ),
+        (
+            Point::new(8.0, 6.0, -1.0),
+            Vector::new(0.0, -1.0, 0.0),
+            false,
+        ),
+        (
+            Point::new(12.0, 5.0, 4.0),
+            Vector::new(-1.0, 0.0, 0.0),
+            false,
+        ),
+    ];
+    for (origin, direction, result) in examples {
+        let r = Ray::new(origin, direction.normalized());
+        assert_eq!(r.intersect(&bounds).is_some(), result);
+    }
+}
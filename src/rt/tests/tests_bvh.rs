@@ -0,0 +1,134 @@
+#[cfg(test)]
+use crate::{
+    rt::{
+        bvh::Bvh,
+        group::Group,
+        intersections::Intersections,
+        matrix::Matrix,
+        object::Object,
+        plane::Plane,
+        ray::{Intersect, Ray},
+        sphere::Sphere,
+        world::World,
+    },
+    tuple::{point::Point, vector::Vector},
+};
+
+#[cfg(test)]
+fn grid_of_spheres(size: u32) -> Vec<Object> {
+    let mut spheres = Vec::new();
+    for x in 0..size {
+        for y in 0..size {
+            for z in 0..size {
+                spheres.push(
+                    Sphere::with_transform(
+                        Matrix::<4>::translation(
+                            f64::from(x) * 3.0,
+                            f64::from(y) * 3.0,
+                            f64::from(z) * 3.0,
+                        ) * Matrix::<4>::scaling(0.5, 0.5, 0.5),
+                    )
+                    .into(),
+                );
+            }
+        }
+    }
+    spheres
+}
+
+/// Intersects every object of the world, without using its bounding volume hierarchy.
+#[cfg(test)]
+fn intersect_linearly<'objects>(ray: &Ray, world: &'objects World) -> Intersections<'objects> {
+    Intersections::new(
+        world
+            .objects()
+            .unwrap()
+            .iter()
+            .filter_map(|object| ray.intersect(object))
+            .flatten()
+            .collect(),
+    )
+}
+
+#[test]
+fn a_ray_only_gets_the_objects_whose_bounds_it_hits_as_candidates() {
+    let objects = grid_of_spheres(4);
+    let bvh = Bvh::new(&objects);
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    // The spheres in the first column are aligned with the ray, while most
+    // of the others are skipped.
+    let candidates = bvh.candidates(&r);
+    assert!([0, 1, 2, 3].iter().all(|index| candidates.contains(index)));
+    assert!(candidates.len() < objects.len() / 2);
+    let r = Ray::new(Point::new(20.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    assert!(bvh.candidates(&r).is_empty());
+}
+
+#[test]
+fn unbounded_objects_are_always_candidates() {
+    let mut objects = grid_of_spheres(2);
+    objects.push(Plane::default().into());
+    let bvh = Bvh::new(&objects);
+    let r = Ray::new(Point::new(-5.0, 5.0, -5.0), Vector::new(0.0, 1.0, 0.0));
+    assert_eq!(bvh.candidates(&r), vec![8]);
+}
+
+#[test]
+fn the_hierarchy_of_a_group_is_rebuilt_when_a_child_is_added() {
+    let mut g = Group::default();
+    g.add_child(Sphere::default().into());
+    let r = Ray::new(Point::new(5.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    assert!(g.bvh().candidates(&r).is_empty());
+    g.add_child(Sphere::with_transform(Matrix::<4>::translation(5.0, 0.0, 0.0)).into());
+    assert!(g.bvh().candidates(&r).contains(&1));
+}
+
+#[test]
+fn intersecting_a_world_through_its_hierarchy_gives_the_same_intersections() {
+    let mut objects = grid_of_spheres(5);
+    objects.push(Plane::default().into());
+    objects.push(Group::new(Matrix::<4>::translation(1.5, 1.5, 1.5), grid_of_spheres(2)).into());
+    let world = World::with_objects(Some(objects));
+    let rays = [
+        Ray::new(
+            Point::new(-5.0, 0.0, -5.0),
+            Vector::new(1.0, 0.0, 1.0).normalized(),
+        ),
+        Ray::new(
+            Point::new(-5.0, -5.0, -5.0),
+            Vector::new(1.0, 1.0, 1.0).normalized(),
+        ),
+        Ray::new(Point::new(6.0, 20.0, 6.0), Vector::new(0.0, -1.0, 0.0)),
+        Ray::new(Point::new(1.5, 1.5, -5.0), Vector::new(0.0, 0.0, 1.0)),
+    ];
+    for r in rays {
+        let xs = r.intersect(&world).expect("No intersection found!");
+        let expected = intersect_linearly(&r, &world);
+        assert_eq!(xs.len(), expected.len());
+        for (x, e) in xs.iter().zip(expected.iter()) {
+            assert_eq!(x.t(), e.t());
+            assert!(std::ptr::eq(x.object(), e.object()));
+        }
+    }
+}
+
+/// The hierarchy only hands a small fraction of a scene made of several thousand spheres to the
+/// exact intersection tests, and finds the same intersections as a linear scan.
+#[test]
+fn the_hierarchy_tests_far_fewer_objects_than_a_linear_scan() {
+    let world = World::with_objects(Some(grid_of_spheres(16)));
+    let object_count = world.objects().unwrap().len();
+    let bvh = world.bvh().unwrap();
+    for i in 0..8 {
+        let (x, y) = (f64::from(i % 4) * 10.0, f64::from(i / 4) * 20.0);
+        let r = Ray::new(
+            Point::new(x, y, -10.0),
+            Vector::new(0.1, 0.05, 1.0).normalized(),
+        );
+        assert!(bvh.candidates(&r).len() * 20 < object_count);
+        assert_eq!(
+            r.intersect(&world).map_or(0, |xs| xs.len()),
+            intersect_linearly(&r, &world).len()
+        );
+    }
+}
@@ -2,7 +2,14 @@
 use {
     crate::{
         approx_eq::ApproxEq,
-        rt::{camera::Camera, color::Color, matrix::Matrix, world::World},
+        rt::{
+            camera::{Camera, RENDER_TILE_SIZE},
+            color::{Color, BLACK},
+            matrix::Matrix,
+            object::Object,
+            pattern::Pattern,
+            world::World,
+        },
         tuple::{point::Point, vector::Vector},
     },
     std::f64::consts::PI,
@@ -80,3 +87,100 @@ fn rendering_a_world_with_a_camera() {
         &Color::new(0.3806609553101071, 0.47582619413763383, 0.2854957164825803)
     );
 }
+
+#[test]
+fn rendering_a_world_covers_the_last_row_and_column() {
+    let w = World::default();
+    let from = Point::new(0.0, 0.0, -5.0);
+    let up = Vector::new(0.0, 1.0, 0.0);
+    let to = Point::new(0.0, 0.0, 0.0);
+    // A narrow field of view, so that every pixel sees the outer sphere.
+    let c = Camera::new(
+        11.0,
+        11.0,
+        PI / 16.0,
+        Some(Matrix::<4>::view_transform(&from, &to, &up)),
+    );
+    for image in [c.render(&w), c.render_parallel(&w, 3)] {
+        for i in 0..11 {
+            assert_ne!(image.pixel_at(10, i), &BLACK);
+            assert_ne!(image.pixel_at(i, 10), &BLACK);
+        }
+    }
+}
+
+#[test]
+fn rendering_a_region_matches_the_full_render() {
+    let w = World::default();
+    let from = Point::new(0.0, 0.0, -5.0);
+    let up = Vector::new(0.0, 1.0, 0.0);
+    let to = Point::new(0.0, 0.0, 0.0);
+    let c = Camera::new(
+        11.0,
+        11.0,
+        PI / 2.0,
+        Some(Matrix::<4>::view_transform(&from, &to, &up)),
+    );
+    let full = c.render(&w);
+    let crop = c.render_region(&w, 3, 4, 11, 9);
+    assert_eq!(crop.width(), 8);
+    assert_eq!(crop.height(), 5);
+    for y in 0..5 {
+        for x in 0..8 {
+            assert_eq!(crop.pixel_at(x, y), full.pixel_at(x + 3, y + 4));
+        }
+    }
+    assert_eq!(crop.pixel_at(2, 1), full.pixel_at(5, 5));
+}
+
+#[test]
+fn rendering_an_empty_region() {
+    let c = Camera::new(11.0, 11.0, PI / 2.0, None);
+    let crop = c.render_region(&World::default(), 5, 5, 5, 5);
+    assert!(crop.pixels().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "is not within the 11x11 canvas")]
+fn rendering_a_region_outside_the_canvas_panics() {
+    let c = Camera::new(11.0, 11.0, PI / 2.0, None);
+    c.render_region(&World::default(), 5, 5, 12, 8);
+}
+
+#[test]
+fn world_and_objects_can_be_shared_between_threads() {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<World>();
+    assert_send_sync::<Object>();
+    assert_send_sync::<Pattern>();
+}
+
+#[test]
+fn rendering_a_world_in_parallel_matches_the_serial_render() {
+    let w = World::default();
+    let from = Point::new(0.0, 0.0, -5.0);
+    let up = Vector::new(0.0, 1.0, 0.0);
+    let to = Point::new(0.0, 0.0, 0.0);
+    // Sizes that are not multiples of the tile size, so that edge tiles are partial.
+    let (hsize, vsize) = (RENDER_TILE_SIZE * 2 + 5, RENDER_TILE_SIZE + 3);
+    let c = Camera::new(
+        f64::from(u32::try_from(hsize).unwrap()),
+        f64::from(u32::try_from(vsize).unwrap()),
+        PI / 2.0,
+        Some(Matrix::<4>::view_transform(&from, &to, &up)),
+    );
+    let serial = c.render(&w);
+    for threads in [1, 3, 8] {
+        let parallel = c.render_parallel(&w, threads);
+        assert_eq!(parallel.width(), hsize);
+        assert_eq!(parallel.height(), vsize);
+        assert_eq!(parallel.pixels(), serial.pixels());
+    }
+}
+
+#[test]
+#[should_panic(expected = "Cannot render with 0 threads")]
+fn rendering_with_no_threads_panics() {
+    let c = Camera::new(11.0, 11.0, PI / 2.0, None);
+    c.render_parallel(&World::default(), 0);
+}
@@ -2,6 +2,8 @@
 use crate::rt::{
     canvas::Canvas,
     color::{Color, BLACK},
+    image_encoder::ImageEncoder,
+    ppm_writer::PpmEncoder,
     to_ppm::ToPPM,
 };
 
@@ -79,6 +81,19 @@ fn construct_ppm_pixel_data_lines_too_long_are_split() {
     assert_eq!(pixel_data_lines, expected_pixel_data_lines);
 }
 
+#[test]
+fn constructed_ppms_match_the_plain_ppm_encoder() {
+    let mut canvas = Canvas::new(3, 2);
+    canvas.write_pixel(0, 0, Color::new(0.401, 0.5, 1.5));
+    canvas.write_pixel(2, 1, Color::new(0.2, 0.0, -0.5));
+    let ppm = canvas.to_ppm();
+    // Values are rounded to the nearest integer: 0.401 is 102.255.
+    assert!(ppm.pixel_data().starts_with("102 128 255 "));
+    let mut data = Vec::new();
+    PpmEncoder::plain().encode(&canvas, &mut data).unwrap();
+    assert_eq!(ppm.data().as_bytes(), data);
+}
+
 #[test]
 fn ppm_files_are_terminated_by_a_newline_character() {
     let canvas = Canvas::new(5, 3);
@@ -88,3 +103,12 @@ fn ppm_files_are_terminated_by_a_newline_character() {
     let expected_last_line = "";
     assert_eq!(last_line, expected_last_line);
 }
+
+#[test]
+fn reading_a_row_of_pixels() {
+    let mut canvas = Canvas::new(3, 2);
+    let red = Color::new(1.0, 0.0, 0.0);
+    canvas.write_pixel(1, 1, red);
+    assert_eq!(canvas.row(0), &[BLACK, BLACK, BLACK]);
+    assert_eq!(canvas.row(1), &[BLACK, red, BLACK]);
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+use crate::{
+    approx_eq::ApproxEq,
+    rt::{
+        cone::Cone,
+        intersection::Intersection,
+        object::Object,
+        ray::{Intersect, Ray},
+        shape::Shape,
+    },
+    tuple::{point::Point, vector::Vector},
+};
+
+#[test]
+fn intersecting_a_cone_with_a_ray() {
+    let shape = Object::Cone(Cone::default());
+    let examples = [
+        (
+            Point::new(0.0, 0.0, -5.0),
+            Vector::new(0.0, 0.0, 1.0),
+            5.0,
+            5.0,
+        ),
+        (
+            Point::new(0.0, 0.0, -5.0),
+            Vector::new(1.0, 1.0, 1.0),
+            8.66025,
+            8.66025,
+        ),
+        (
+            Point::new(1.0, 1.0, -5.0),
+            Vector::new(-0.5, -1.0, 1.0),
+            4.55006,
+            49.44994,
+        ),
+    ];
+    for (origin, direction, t0, t1) in examples {
+        let r = Ray::new(origin, direction.normalized());
+        let xs = r.intersect(&shape).expect("No intersection found!");
+        assert_eq!(xs.len(), 2);
+        assert!((xs[0].t() - t0).abs() < 0.0001);
+        assert!((xs[1].t() - t1).abs() < 0.0001);
+    }
+}
+
+#[test]
+fn intersecting_a_cone_with_a_ray_parallel_to_one_of_its_halves() {
+    let shape = Object::Cone(Cone::default());
+    let r = Ray::new(
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 1.0).normalized(),
+    );
+    let xs = r.intersect(&shape).expect("No intersection found!");
+    assert_eq!(xs.len(), 1);
+    assert!(xs[0].t().approx_eq(0.35355));
+}
+
+#[test]
+fn intersecting_a_cone_end_caps() {
+    let shape = Object::Cone(Cone::truncated(-0.5, 0.5, true));
+    let examples = [
+        (Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 1.0, 0.0), 0),
+        (Point::new(0.0, 0.0, -0.25), Vector::new(0.0, 1.0, 1.0), 2),
+        (Point::new(0.0, 0.0, -0.25), Vector::new(0.0, 1.0, 0.0), 4),
+    ];
+    for (origin, direction, count) in examples {
+        let r = Ray::new(origin, direction.normalized());
+        assert_eq!(r.intersect(&shape).map_or(0, |xs| xs.len()), count);
+    }
+}
+
+#[test]
+fn computing_the_normal_vector_on_a_cone() {
+    let shape = Object::Cone(Cone::default());
+    let i = Intersection::new(0.0, &shape);
+    let examples = [
+        (
+            Point::new(1.0, 1.0, 1.0),
+            Vector::new(1.0, -(2.0_f64.sqrt()), 1.0),
+        ),
+        (Point::new(-1.0, -1.0, 0.0), Vector::new(-1.0, 1.0, 0.0)),
+    ];
+    for (point, normal) in examples {
+        assert_eq!(shape.normal_at(&point, &i), normal.normalized());
+    }
+}
+
+#[test]
+fn the_normal_vector_on_a_cone_end_caps() {
+    let shape = Object::Cone(Cone::truncated(-1.0, 2.0, true));
+    let i = Intersection::new(0.0, &shape);
+    let examples = [
+        (Point::new(0.5, -1.0, 0.0), Vector::new(0.0, -1.0, 0.0)),
+        (Point::new(0.0, 2.0, 1.5), Vector::new(0.0, 1.0, 0.0)),
+    ];
+    for (point, normal) in examples {
+        assert_eq!(shape.normal_at(&point, &i), normal);
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(test)]
+use crate::{
+    rt::{
+        csg::{Csg, CsgOperation},
+        cube::Cube,
+        group::Group,
+        intersection::Intersection,
+        intersections::Intersections,
+        matrix::Matrix,
+        object::Object,
+        ray::{Intersect, Ray},
+        shape::Shape,
+        sphere::Sphere,
+    },
+    tuple::{point::Point, vector::Vector},
+};
+
+#[test]
+fn csg_is_created_with_an_operation_and_two_shapes() {
+    let c = Csg::new(
+        CsgOperation::Union,
+        Sphere::default().into(),
+        Cube::default().into(),
+    );
+    assert_eq!(c.operation(), CsgOperation::Union);
+    assert_eq!(c.left(), &Object::Sphere(Sphere::default()));
+    assert_eq!(c.right(), &Object::Cube(Cube::default()));
+}
+
+#[test]
+fn evaluating_the_rule_for_a_csg_operation() {
+    let examples = [
+        (CsgOperation::Union, true, true, true, false),
+        (CsgOperation::Union, true, true, false, true),
+        (CsgOperation::Union, true, false, true, false),
+        (CsgOperation::Union, true, false, false, true),
+        (CsgOperation::Union, false, true, true, false),
+        (CsgOperation::Union, false, true, false, false),
+        (CsgOperation::Union, false, false, true, true),
+        (CsgOperation::Union, false, false, false, true),
+        (CsgOperation::Intersection, true, true, true, true),
+        (CsgOperation::Intersection, true, true, false, false),
+        (CsgOperation::Intersection, true, false, true, true),
+        (CsgOperation::Intersection, true, false, false, false),
+        (CsgOperation::Intersection, false, true, true, true),
+        (CsgOperation::Intersection, false, true, false, true),
+        (CsgOperation::Intersection, false, false, true, false),
+        (CsgOperation::Intersection, false, false, false, false),
+        (CsgOperation::Difference, true, true, true, false),
+        (CsgOperation::Difference, true, true, false, true),
+        (CsgOperation::Difference, true, false, true, false),
+        (CsgOperation::Difference, true, false, false, true),
+        (CsgOperation::Difference, false, true, true, true),
+        (CsgOperation::Difference, false, true, false, true),
+        (CsgOperation::Difference, false, false, true, false),
+        (CsgOperation::Difference, false, false, false, false),
+    ];
+    for (operation, left_hit, inside_left, inside_right, result) in examples {
+        assert_eq!(
+            operation.intersection_allowed(left_hit, inside_left, inside_right),
+            result
+        );
+    }
+}
+
+#[test]
+fn filtering_a_list_of_intersections() {
+    let examples = [
+        (CsgOperation::Union, 0, 3),
+        (CsgOperation::Intersection, 1, 2),
+        (CsgOperation::Difference, 0, 1),
+    ];
+    for (operation, x0, x1) in examples {
+        let c = Csg::new(operation, Sphere::default().into(), Cube::default().into());
+        let (s1, s2) = (c.left(), c.right());
+        let xs = Intersections::new(vec![
+            Intersection::new(1.0, s1),
+            Intersection::new(2.0, s2),
+            Intersection::new(3.0, s1),
+            Intersection::new(4.0, s2),
+        ]);
+        let result = c.filter_intersections(Intersections::new(xs.to_vec()));
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0], xs[x0]);
+        assert_eq!(result[1], xs[x1]);
+    }
+}
+
+#[test]
+fn filtering_intersections_on_an_operand_made_of_a_group() {
+    let c = Csg::new(
+        CsgOperation::Difference,
+        Group::new(Matrix::<4>::identity(), vec![Sphere::default().into()]).into(),
+        Cube::with_transform(Matrix::<4>::translation(0.0, 0.0, 1.5)).into(),
+    );
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let c = Object::Csg(c);
+    let xs = r.intersect(&c).expect("No intersection found!");
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t(), 4.0);
+    assert_eq!(xs[1].t(), 5.5);
+}
+
+#[test]
+fn a_ray_misses_a_csg_object() {
+    let c = Object::Csg(Csg::new(
+        CsgOperation::Union,
+        Sphere::default().into(),
+        Cube::default().into(),
+    ));
+    let r = Ray::new(Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    assert!(r.intersect(&c).is_none());
+}
+
+#[test]
+fn a_ray_hits_a_csg_object() {
+    let c = Object::Csg(Csg::new(
+        CsgOperation::Union,
+        Sphere::default().into(),
+        Sphere::with_transform(Matrix::<4>::translation(0.0, 0.0, 0.5)).into(),
+    ));
+    let Object::Csg(csg) = &c else { unreachable!() };
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let xs = r.intersect(&c).expect("No intersection found!");
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t(), 4.0);
+    assert!(std::ptr::eq(xs[0].object(), csg.left()));
+    assert_eq!(xs[1].t(), 6.5);
+    assert!(std::ptr::eq(xs[1].object(), csg.right()));
+}
+
+#[test]
+#[should_panic(expected = "A CSG has no normal, only its operands do.")]
+fn a_csg_has_no_normal() {
+    let c = Object::Csg(Csg::new(
+        CsgOperation::Union,
+        Sphere::default().into(),
+        Cube::default().into(),
+    ));
+    let i = Intersection::new(0.0, &c);
+    let _ = c.normal_at(&Point::new(0.0, 0.0, 0.0), &i);
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+use crate::{
+    rt::{
+        cube::Cube,
+        intersection::Intersection,
+        object::Object,
+        ray::{Intersect, Ray},
+        shape::Shape,
+    },
+    tuple::{point::Point, vector::Vector},
+};
+
+#[test]
+fn a_ray_intersects_a_cube() {
+    let c = Object::Cube(Cube::default());
+    let examples = [
+        // +x
+        (
+            Point::new(5.0, 0.5, 0.0),
+            Vector::new(-1.0, 0.0, 0.0),
+            4.0,
+            6.0,
+        ),
+        // -x
+        (
+            Point::new(-5.0, 0.5, 0.0),
+            Vector::new(1.0, 0.0, 0.0),
+            4.0,
+            6.0,
+        ),
+        // +y
+        (
+            Point::new(0.5, 5.0, 0.0),
+            Vector::new(0.0, -1.0, 0.0),
+            4.0,
+            6.0,
+        ),
+        // -y
+        (
+            Point::new(0.5, -5.0, 0.0),
+            Vector::new(0.0, 1.0, 0.0),
+            4.0,
+            6.0,
+        ),
+        // +z
+        (
+            Point::new(0.5, 0.0, 5.0),
+            Vector::new(0.0, 0.0, -1.0),
+            4.0,
+            6.0,
+        ),
+        // -z
+        (
+            Point::new(0.5, 0.0, -5.0),
+            Vector::new(0.0, 0.0, 1.0),
+            4.0,
+            6.0,
+        ),
+        // inside
+        (
+            Point::new(0.0, 0.5, 0.0),
+            Vector::new(0.0, 0.0, 1.0),
+            -1.0,
+            1.0,
+        ),
+    ];
+    for (origin, direction, t1, t2) in examples {
+        let r = Ray::new(origin, direction);
+        let xs = r.intersect(&c).expect("No intersection found!");
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t(), t1);
+        assert_eq!(xs[1].t(), t2);
+    }
+}
+
+#[test]
+fn a_ray_misses_a_cube() {
+    let c = Object::Cube(Cube::default());
+    let examples = [
+        (
+            Point::new(-2.0, 0.0, 0.0),
+            Vector::new(0.2673, 0.5345, 0.8018),
+        ),
+        (
+            Point::new(0.0, -2.0, 0.0),
+            Vector::new(0.8018, 0.2673, 0.5345),
+        ),
+        (
+            Point::new(0.0, 0.0, -2.0),
+            Vector::new(0.5345, 0.8018, 0.2673),
+        ),
+        (Point::new(2.0, 0.0, 2.0), Vector::new(0.0, 0.0, -1.0)),
+        (Point::new(0.0, 2.0, 2.0), Vector::new(0.0, -1.0, 0.0)),
+        (Point::new(2.0, 2.0, 0.0), Vector::new(-1.0, 0.0, 0.0)),
+    ];
+    for (origin, direction) in examples {
+        let r = Ray::new(origin, direction);
+        assert!(r.intersect(&c).is_none());
+    }
+}
+
+#[test]
+fn the_normal_on_the_surface_of_a_cube() {
+    let c = Object::Cube(Cube::default());
+    let i = Intersection::new(0.0, &c);
+    let examples = [
+        (Point::new(1.0, 0.5, -0.8), Vector::new(1.0, 0.0, 0.0)),
+        (Point::new(-1.0, -0.2, 0.9), Vector::new(-1.0, 0.0, 0.0)),
+        (Point::new(-0.4, 1.0, -0.1), Vector::new(0.0, 1.0, 0.0)),
+        (Point::new(0.3, -1.0, -0.7), Vector::new(0.0, -1.0, 0.0)),
+        (Point::new(-0.6, 0.3, 1.0), Vector::new(0.0, 0.0, 1.0)),
+        (Point::new(0.4, 0.4, -1.0), Vector::new(0.0, 0.0, -1.0)),
+        (Point::new(1.0, 1.0, 1.0), Vector::new(1.0, 0.0, 0.0)),
+        (Point::new(-1.0, -1.0, -1.0), Vector::new(-1.0, 0.0, 0.0)),
+    ];
+    for (point, expected) in examples {
+        assert_eq!(c.normal_at(&point, &i), expected);
+    }
+}
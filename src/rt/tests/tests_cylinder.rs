@@ -0,0 +1,138 @@
+#[cfg(test)]
+use crate::{
+    approx_eq::ApproxEq,
+    rt::{
+        cylinder::Cylinder,
+        intersection::Intersection,
+        object::Object,
+        ray::{Intersect, Ray},
+        shape::Shape,
+    },
+    tuple::{point::Point, vector::Vector},
+};
+
+#[test]
+fn a_ray_misses_a_cylinder() {
+    let cyl = Object::Cylinder(Cylinder::default());
+    let examples = [
+        (Point::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0)),
+        (Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0)),
+        (Point::new(0.0, 0.0, -5.0), Vector::new(1.0, 1.0, 1.0)),
+    ];
+    for (origin, direction) in examples {
+        let r = Ray::new(origin, direction.normalized());
+        assert!(r.intersect(&cyl).is_none());
+    }
+}
+
+#[test]
+fn a_ray_strikes_a_cylinder() {
+    let cyl = Object::Cylinder(Cylinder::default());
+    let examples = [
+        (
+            Point::new(1.0, 0.0, -5.0),
+            Vector::new(0.0, 0.0, 1.0),
+            5.0,
+            5.0,
+        ),
+        (
+            Point::new(0.0, 0.0, -5.0),
+            Vector::new(0.0, 0.0, 1.0),
+            4.0,
+            6.0,
+        ),
+        (
+            Point::new(0.5, 0.0, -5.0),
+            Vector::new(0.1, 1.0, 1.0),
+            6.80798,
+            7.08872,
+        ),
+    ];
+    for (origin, direction, t0, t1) in examples {
+        let r = Ray::new(origin, direction.normalized());
+        let xs = r.intersect(&cyl).expect("No intersection found!");
+        assert_eq!(xs.len(), 2);
+        assert!(xs[0].t().approx_eq(t0));
+        assert!(xs[1].t().approx_eq(t1));
+    }
+}
+
+#[test]
+fn normal_vector_on_a_cylinder() {
+    let cyl = Object::Cylinder(Cylinder::default());
+    let i = Intersection::new(0.0, &cyl);
+    let examples = [
+        (Point::new(1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0)),
+        (Point::new(0.0, 5.0, -1.0), Vector::new(0.0, 0.0, -1.0)),
+        (Point::new(0.0, -2.0, 1.0), Vector::new(0.0, 0.0, 1.0)),
+        (Point::new(-1.0, 1.0, 0.0), Vector::new(-1.0, 0.0, 0.0)),
+    ];
+    for (point, normal) in examples {
+        assert_eq!(cyl.normal_at(&point, &i), normal);
+    }
+}
+
+#[test]
+fn the_default_minimum_and_maximum_for_a_cylinder() {
+    let cyl = Cylinder::default();
+    assert_eq!(cyl.minimum(), f64::NEG_INFINITY);
+    assert_eq!(cyl.maximum(), f64::INFINITY);
+}
+
+#[test]
+fn intersecting_a_constrained_cylinder() {
+    let cyl = Object::Cylinder(Cylinder::truncated(1.0, 2.0, false));
+    let examples = [
+        (Point::new(0.0, 1.5, 0.0), Vector::new(0.1, 1.0, 0.0), 0),
+        (Point::new(0.0, 3.0, -5.0), Vector::new(0.0, 0.0, 1.0), 0),
+        (Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0), 0),
+        (Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0), 0),
+        (Point::new(0.0, 1.0, -5.0), Vector::new(0.0, 0.0, 1.0), 0),
+        (Point::new(0.0, 1.5, -2.0), Vector::new(0.0, 0.0, 1.0), 2),
+    ];
+    for (origin, direction, count) in examples {
+        let r = Ray::new(origin, direction.normalized());
+        assert_eq!(r.intersect(&cyl).map_or(0, |xs| xs.len()), count);
+    }
+}
+
+#[test]
+fn the_default_closed_value_for_a_cylinder() {
+    let cyl = Cylinder::default();
+    assert!(!cyl.closed());
+}
+
+#[test]
+fn intersecting_the_caps_of_a_closed_cylinder() {
+    let cyl = Object::Cylinder(Cylinder::truncated(1.0, 2.0, true));
+    let examples = [
+        (Point::new(0.0, 3.0, 0.0), Vector::new(0.0, -1.0, 0.0), 2),
+        (Point::new(0.0, 3.0, -2.0), Vector::new(0.0, -1.0, 2.0), 2),
+        // corner case
+        (Point::new(0.0, 4.0, -2.0), Vector::new(0.0, -1.0, 1.0), 2),
+        (Point::new(0.0, 0.0, -2.0), Vector::new(0.0, 1.0, 2.0), 2),
+        // corner case
+        (Point::new(0.0, -1.0, -2.0), Vector::new(0.0, 1.0, 1.0), 2),
+    ];
+    for (origin, direction, count) in examples {
+        let r = Ray::new(origin, direction.normalized());
+        assert_eq!(r.intersect(&cyl).map_or(0, |xs| xs.len()), count);
+    }
+}
+
+#[test]
+fn the_normal_vector_on_a_cylinder_end_caps() {
+    let cyl = Object::Cylinder(Cylinder::truncated(1.0, 2.0, true));
+    let i = Intersection::new(0.0, &cyl);
+    let examples = [
+        (Point::new(0.0, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0)),
+        (Point::new(0.5, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0)),
+        (Point::new(0.0, 1.0, 0.5), Vector::new(0.0, -1.0, 0.0)),
+        (Point::new(0.0, 2.0, 0.0), Vector::new(0.0, 1.0, 0.0)),
+        (Point::new(0.5, 2.0, 0.0), Vector::new(0.0, 1.0, 0.0)),
+        (Point::new(0.0, 2.0, 0.5), Vector::new(0.0, 1.0, 0.0)),
+    ];
+    for (point, normal) in examples {
+        assert_eq!(cyl.normal_at(&point, &i), normal);
+    }
+}
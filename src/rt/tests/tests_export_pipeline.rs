@@ -0,0 +1,264 @@
+#[cfg(test)]
+use crate::rt::{
+    canvas::Canvas,
+    color::{Color, BLACK, WHITE},
+    dithering::Dithering,
+    export_pipeline::{ExportPipeline, TransferFunction},
+    hdr::HdrEncoder,
+    image_encoder::ImageEncoder,
+    pfm::PfmEncoder,
+    png::PngEncoder,
+    ppm_writer::{PpmDepth, PpmEncoder, PpmFormat, PpmWriter},
+    tone_mapping::{luminance, ToneMapping},
+};
+
+#[cfg(test)]
+fn gray(value: f64) -> Color {
+    Color::new(value, value, value)
+}
+
+#[cfg(test)]
+fn encode(encoder: &dyn ImageEncoder, canvas: &Canvas) -> Vec<u8> {
+    let mut data = Vec::new();
+    encoder.encode(canvas, &mut data).unwrap();
+    data
+}
+
+#[test]
+fn computing_the_luminance_of_a_color() {
+    assert!((luminance(&WHITE) - 1.0).abs() < 1e-12);
+    assert!((luminance(&Color::new(0.0, 1.0, 0.0)) - 0.7152).abs() < 1e-12);
+    assert!(luminance(&BLACK).abs() < 1e-12);
+}
+
+#[test]
+fn clamping_and_not_tone_mapping() {
+    let color = Color::new(2.0, 0.5, -1.0);
+    assert_eq!(ToneMapping::default(), ToneMapping::None);
+    assert_eq!(ToneMapping::None.apply(color), color);
+    assert_eq!(ToneMapping::Clamp.apply(color), Color::new(1.0, 0.5, 0.0));
+}
+
+#[test]
+fn the_reinhard_operator_compresses_the_luminance() {
+    assert_eq!(ToneMapping::Reinhard.apply(gray(1.0)), gray(0.5));
+    assert_eq!(ToneMapping::Reinhard.apply(gray(3.0)), gray(0.75));
+    assert_eq!(ToneMapping::Reinhard.apply(BLACK), BLACK);
+    // The hue is kept.
+    let mapped = ToneMapping::Reinhard.apply(Color::new(4.0, 2.0, 0.0));
+    assert!((mapped.red() / mapped.green() - 2.0).abs() < 1e-12);
+    assert!(mapped.blue().abs() < 1e-12);
+    assert!(luminance(&mapped) < 1.0);
+}
+
+#[test]
+fn the_extended_reinhard_operator_reaches_white() {
+    let tone_mapping = ToneMapping::extended_reinhard(4.0);
+    assert_eq!(tone_mapping.apply(gray(4.0)), WHITE);
+    assert_eq!(tone_mapping.apply(gray(8.0)), WHITE);
+    assert_eq!(tone_mapping.apply(gray(1.0)), gray(0.531_25));
+    assert_eq!(tone_mapping.apply(BLACK), BLACK);
+}
+
+#[test]
+#[should_panic(expected = "The white luminance must be finite and positive")]
+fn the_extended_reinhard_operator_needs_a_positive_white() {
+    let _ = ToneMapping::extended_reinhard(0.0);
+}
+
+#[test]
+fn the_aces_filmic_operator() {
+    let tone_mapping = ToneMapping::AcesFilmic;
+    assert_eq!(tone_mapping.apply(BLACK), BLACK);
+    assert_eq!(tone_mapping.apply(gray(1.0)), gray(2.54 / 3.16));
+    assert_eq!(tone_mapping.apply(gray(100.0)), WHITE);
+    assert_eq!(tone_mapping.apply(gray(-1.0)), BLACK);
+    let mapped = tone_mapping.apply(Color::new(0.1, 0.5, 2.0));
+    assert!(mapped.red() < mapped.green() && mapped.green() < mapped.blue());
+}
+
+#[test]
+fn encoding_values_with_the_srgb_curve() {
+    let srgb = TransferFunction::Srgb;
+    assert!(srgb.encode(0.0).abs() < 1e-12);
+    assert!((srgb.encode(1.0) - 1.0).abs() < 1e-12);
+    assert!((srgb.encode(0.002) - 0.025_84).abs() < 1e-12);
+    assert!((srgb.encode(0.5) - 0.735_357).abs() < 1e-6);
+    assert!(srgb.encode(-1.0).abs() < 1e-12);
+    assert!((TransferFunction::Linear.encode(0.5) - 0.5).abs() < 1e-12);
+    assert!((TransferFunction::Linear.encode(-1.0) + 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn the_default_export_pipeline_writes_colors_as_they_are() {
+    let pipeline = ExportPipeline::default();
+    assert_eq!(pipeline, ExportPipeline::linear());
+    assert!(pipeline.exposure().abs() < 1e-12);
+    assert_eq!(pipeline.tone_mapping(), ToneMapping::None);
+    assert_eq!(pipeline.transfer(), TransferFunction::Linear);
+    assert_eq!(pipeline.dithering(), Dithering::None);
+    let color = Color::new(2.0, 0.5, -1.0);
+    assert_eq!(pipeline.map(&color), color);
+    assert_eq!(pipeline.quantize(&color, 0, 0, 255), [255, 128, 0]);
+}
+
+#[test]
+fn the_display_export_pipeline() {
+    let pipeline = ExportPipeline::display();
+    assert_eq!(pipeline.tone_mapping(), ToneMapping::AcesFilmic);
+    assert_eq!(pipeline.transfer(), TransferFunction::Srgb);
+    assert_eq!(pipeline.dithering(), Dithering::BlueNoise);
+    assert_eq!(pipeline.quantize(&gray(100.0), 3, 5, 255), [255; 3]);
+    assert_eq!(pipeline.quantize(&BLACK, 3, 5, 255), [0; 3]);
+}
+
+#[test]
+fn exposure_is_in_stops() {
+    let color = Color::new(0.25, 0.5, 1.0);
+    assert_eq!(
+        ExportPipeline::linear().with_exposure(1.0).map(&color),
+        Color::new(0.5, 1.0, 2.0)
+    );
+    assert_eq!(
+        ExportPipeline::linear().with_exposure(-2.0).map(&color),
+        Color::new(0.0625, 0.125, 0.25)
+    );
+}
+
+#[test]
+fn the_pipeline_applies_exposure_then_tone_mapping_then_transfer() {
+    let pipeline = ExportPipeline::linear()
+        .with_exposure(1.0)
+        .with_tone_mapping(ToneMapping::Reinhard)
+        .with_transfer(TransferFunction::Srgb);
+    // 0.5 is exposed to 1.0, tone mapped to 0.5, then encoded as 0.735.
+    assert_eq!(pipeline.quantize(&gray(0.5), 0, 0, 255), [188; 3]);
+}
+
+#[test]
+fn ordered_dithering_follows_a_bayer_matrix() {
+    let ranks: Vec<f64> = (0..8)
+        .map(|x| (Dithering::Ordered.offset(x, 0) + 0.5) * 64.0 - 0.5)
+        .collect();
+    assert_eq!(ranks, [0.0, 32.0, 8.0, 40.0, 2.0, 34.0, 10.0, 42.0]);
+    let rank = (Dithering::Ordered.offset(0, 1) + 0.5) * 64.0 - 0.5;
+    assert!((rank - 48.0).abs() < 1e-9);
+    assert!((Dithering::Ordered.offset(11, 13) - Dithering::Ordered.offset(3, 5)).abs() < 1e-12);
+    assert!(Dithering::None.offset(3, 5).abs() < 1e-12);
+}
+
+#[test]
+fn blue_noise_dithering_uses_every_threshold_once_per_tile() {
+    let mut offsets: Vec<f64> = (0..32)
+        .flat_map(|y| (0..32).map(move |x| Dithering::BlueNoise.offset(x, y)))
+        .collect();
+    // Neighbouring thresholds are far apart: about 1/3 on average for white noise.
+    let neighbour_difference = (0..32 * 31)
+        .map(|index| (offsets[index] - offsets[index + 32]).abs())
+        .sum::<f64>()
+        / (32.0 * 31.0);
+    assert!(neighbour_difference > 0.4);
+
+    offsets.sort_by(f64::total_cmp);
+    for (offset, rank) in offsets.iter().zip(0_u32..) {
+        assert!((offset - ((f64::from(rank) + 0.5) / 1024.0 - 0.5)).abs() < 1e-12);
+    }
+    assert_eq!(
+        Dithering::BlueNoise.offset(40, 70).to_bits(),
+        Dithering::BlueNoise.offset(8, 6).to_bits()
+    );
+}
+
+#[test]
+fn dithering_keeps_the_average_of_flat_areas() {
+    // 0.3 is 76.5 in 8 bits: rounding alone makes it 77 everywhere.
+    let undithered = ExportPipeline::linear().quantize(&gray(0.3), 0, 0, 255);
+    assert_eq!(undithered, [77; 3]);
+    for dithering in [Dithering::Ordered, Dithering::BlueNoise] {
+        let pipeline = ExportPipeline::linear().with_dithering(dithering);
+        let values: Vec<u16> = (0..32)
+            .flat_map(|y| (0..32).map(move |x| pipeline.quantize(&gray(0.3), x, y, 255)[0]))
+            .collect();
+        assert!(values.iter().all(|value| *value == 76 || *value == 77));
+        let average = values.iter().map(|value| f64::from(*value)).sum::<f64>() / 1024.0;
+        assert!((average - 76.5).abs() < 0.01);
+    }
+}
+
+#[test]
+fn sixteen_bit_values_are_not_dithered() {
+    let pipeline = ExportPipeline::linear().with_dithering(Dithering::Ordered);
+    for x in 0..8 {
+        assert_eq!(pipeline.quantize(&gray(0.5), x, 0, u16::MAX), [32768; 3]);
+    }
+}
+
+#[test]
+fn every_encoder_has_a_linear_pipeline_by_default() {
+    let encoders: [&dyn ImageEncoder; 5] = [
+        &PngEncoder::default(),
+        &PpmEncoder::plain(),
+        &PpmEncoder::binary(PpmDepth::Sixteen),
+        &HdrEncoder::default(),
+        &PfmEncoder::default(),
+    ];
+    for encoder in encoders {
+        assert_eq!(encoder.pipeline(), ExportPipeline::linear());
+    }
+}
+
+#[test]
+fn plain_ppm_images_go_through_the_pipeline() {
+    let mut canvas = Canvas::new(2, 1);
+    canvas.write_pixel(0, 0, Color::new(0.401, 0.5, 1.5));
+    canvas.write_pixel(1, 0, Color::new(0.2, 0.0, -0.5));
+    let linear = encode(&PpmEncoder::plain(), &canvas);
+    assert_eq!(linear, b"P3\n2 1\n255\n102 128 255 51 0 0\n");
+
+    let srgb = PpmEncoder::plain()
+        .with_pipeline(ExportPipeline::linear().with_transfer(TransferFunction::Srgb));
+    assert_eq!(srgb.format(), PpmFormat::Plain);
+    assert_eq!(
+        encode(&srgb, &canvas),
+        b"P3\n2 1\n255\n170 188 255 124 0 0\n"
+    );
+}
+
+#[test]
+fn streamed_ppm_rows_go_through_the_pipeline() {
+    let mut writer = PpmWriter::new(Vec::new(), 2, 1, PpmDepth::Eight)
+        .unwrap()
+        .with_pipeline(ExportPipeline::linear().with_exposure(-1.0));
+    writer.write_row(&[WHITE, gray(0.5)]).unwrap();
+    let data = writer.finish().unwrap();
+    assert_eq!(data[data.len() - 6..], [128, 128, 128, 64, 64, 64]);
+
+    let encoder = PpmEncoder::binary(PpmDepth::Eight)
+        .with_pipeline(ExportPipeline::linear().with_exposure(-1.0));
+    let mut canvas = Canvas::new(2, 1);
+    canvas.write_pixel(0, 0, WHITE);
+    canvas.write_pixel(1, 0, gray(0.5));
+    assert_eq!(encode(&encoder, &canvas), data);
+}
+
+#[test]
+fn float_images_go_through_the_pipeline_without_clamping() {
+    let mut canvas = Canvas::new(3, 2);
+    let mut brighter = Canvas::new(3, 2);
+    for (y, green) in [0.0, 0.5].into_iter().enumerate() {
+        for (x, red) in [0.0, 1.0, 2.0].into_iter().enumerate() {
+            let color = Color::new(red, green, 4.0);
+            canvas.write_pixel(x, y, color);
+            brighter.write_pixel(x, y, color * 4.0);
+        }
+    }
+    let exposed = ExportPipeline::linear().with_exposure(2.0);
+    assert_eq!(
+        encode(&HdrEncoder::default().with_pipeline(exposed), &canvas),
+        encode(&HdrEncoder::default(), &brighter)
+    );
+    assert_eq!(
+        encode(&PfmEncoder::default().with_pipeline(exposed), &canvas),
+        encode(&PfmEncoder::default(), &brighter)
+    );
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+use {
+    crate::{
+        rt::{
+            group::Group,
+            intersection::Intersection,
+            matrix::Matrix,
+            object::Object,
+            ray::{Intersect, Ray},
+            shape::Shape,
+            sphere::Sphere,
+        },
+        tuple::{point::Point, vector::Vector},
+    },
+    std::f64::consts::PI,
+};
+
+#[test]
+fn creating_a_new_group() {
+    let g = Group::default();
+    assert_eq!(g.transform(), &Matrix::<4>::identity());
+    assert!(g.is_empty());
+}
+
+#[test]
+fn adding_a_child_to_a_group() {
+    let mut g = Group::default();
+    g.add_child(Sphere::default().into());
+    assert!(!g.is_empty());
+    assert_eq!(g.children(), &[Object::Sphere(Sphere::default())]);
+}
+
+#[test]
+fn intersecting_a_ray_with_an_empty_group() {
+    let g = Object::Group(Group::default());
+    let r = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    assert!(r.intersect(&g).is_none());
+}
+
+#[test]
+fn intersecting_a_ray_with_a_nonempty_group() {
+    let g = Object::Group(Group::new(
+        Matrix::<4>::identity(),
+        vec![
+            Sphere::default().into(),
+            Sphere::with_transform(Matrix::<4>::translation(0.0, 0.0, -3.0)).into(),
+            Sphere::with_transform(Matrix::<4>::translation(5.0, 0.0, 0.0)).into(),
+        ],
+    ));
+    let Object::Group(group) = &g else {
+        unreachable!()
+    };
+    let (s1, s2) = (&group.children()[0], &group.children()[1]);
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let xs = r.intersect(&g).expect("No intersection found!");
+    assert_eq!(xs.len(), 4);
+    assert!(std::ptr::eq(xs[0].object(), s2));
+    assert!(std::ptr::eq(xs[1].object(), s2));
+    assert!(std::ptr::eq(xs[2].object(), s1));
+    assert!(std::ptr::eq(xs[3].object(), s1));
+    assert!(std::ptr::eq(xs[0].parents()[0], &g));
+}
+
+#[test]
+fn intersecting_a_transformed_group() {
+    let g = Object::Group(Group::new(
+        Matrix::<4>::scaling(2.0, 2.0, 2.0),
+        vec![Sphere::with_transform(Matrix::<4>::translation(5.0, 0.0, 0.0)).into()],
+    ));
+    let r = Ray::new(Point::new(10.0, 0.0, -10.0), Vector::new(0.0, 0.0, 1.0));
+    let xs = r.intersect(&g).expect("No intersection found!");
+    assert_eq!(xs.len(), 2);
+}
+
+#[test]
+fn intersections_with_nested_groups_record_the_parent_chain() {
+    let g1 = Object::Group(Group::new(
+        Matrix::<4>::identity(),
+        vec![Group::new(Matrix::<4>::identity(), vec![Sphere::default().into()]).into()],
+    ));
+    let Object::Group(group) = &g1 else {
+        unreachable!()
+    };
+    let g2 = &group.children()[0];
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let xs = r.intersect(&g1).expect("No intersection found!");
+    assert_eq!(xs[0].parents().len(), 2);
+    assert!(std::ptr::eq(xs[0].parents()[0], g2));
+    assert!(std::ptr::eq(xs[0].parents()[1], &g1));
+}
+
+#[test]
+fn finding_the_normal_on_a_child_object() {
+    let g1 = Object::Group(Group::new(
+        Matrix::<4>::rotation_y(PI / 2.0),
+        vec![Group::new(
+            Matrix::<4>::scaling(1.0, 2.0, 3.0),
+            vec![Sphere::with_transform(Matrix::<4>::translation(5.0, 0.0, 0.0)).into()],
+        )
+        .into()],
+    ));
+    let Object::Group(group) = &g1 else {
+        unreachable!()
+    };
+    let g2 = &group.children()[0];
+    let Object::Group(inner_group) = g2 else {
+        unreachable!()
+    };
+    let s = &inner_group.children()[0];
+    let mut i = Intersection::new(0.0, s);
+    i.add_parent(g2);
+    i.add_parent(&g1);
+    let n = i.normal_at(&Point::new(1.7321, 1.1547, -5.5774));
+    assert_eq!(n, Vector::new(0.2857, 0.42854, -0.85716));
+}
+
+#[test]
+fn precomputing_the_normal_of_a_hit_inside_a_transformed_group() {
+    let g = Object::Group(Group::new(
+        Matrix::<4>::translation(0.0, 0.0, 5.0),
+        vec![Sphere::default().into()],
+    ));
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let xs = r.intersect(&g).expect("No intersection found!");
+    let hit = xs.hit().expect("No hit found!");
+    let comps = hit.prepare_computations(&r, Some(&xs));
+    assert_eq!(hit.t(), 9.0);
+    assert_eq!(comps.point(), &Point::new(0.0, 0.0, 4.0));
+    assert_eq!(comps.normal_vector(), &Vector::new(0.0, 0.0, -1.0));
+}
+
+#[test]
+#[should_panic(expected = "A group has no normal, only its children do.")]
+fn a_group_has_no_normal() {
+    let g = Object::Group(Group::new(
+        Matrix::<4>::identity(),
+        vec![Sphere::default().into()],
+    ));
+    let i = Intersection::new(0.0, &g);
+    let _ = g.normal_at(&Point::new(0.0, 0.0, 0.0), &i);
+}
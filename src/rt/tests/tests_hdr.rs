@@ -0,0 +1,211 @@
+#[cfg(test)]
+use crate::rt::{
+    canvas::Canvas,
+    color::{Color, BLACK},
+    hdr::{rgbe, HdrEncoder},
+    image_encoder::ImageEncoder,
+    pfm::PfmEncoder,
+};
+
+/// Decodes the RGBE pixels of a Radiance image, whose scanlines may be run-length encoded.
+#[cfg(test)]
+fn decode_hdr(data: &[u8]) -> (usize, usize, Vec<[u8; 4]>) {
+    let header_end = data.windows(2).position(|bytes| bytes == b"\n\n").unwrap() + 2;
+    let resolution_end = header_end
+        + data[header_end..]
+            .iter()
+            .position(|byte| *byte == b'\n')
+            .unwrap();
+    let resolution = std::str::from_utf8(&data[header_end..resolution_end]).unwrap();
+    let parts: Vec<&str> = resolution.split(' ').collect();
+    assert_eq!((parts[0], parts[2]), ("-Y", "+X"));
+    let (height, width): (usize, usize) = (parts[1].parse().unwrap(), parts[3].parse().unwrap());
+
+    let mut position = resolution_end + 1;
+    let mut pixels = Vec::new();
+    for _ in 0..height {
+        if data[position..position + 2] == [2, 2] {
+            let length = usize::from(u16::from_be_bytes([data[position + 2], data[position + 3]]));
+            assert_eq!(length, width);
+            position += 4;
+            let mut channels = [vec![], vec![], vec![], vec![]];
+            for channel in &mut channels {
+                while channel.len() < width {
+                    let count = usize::from(data[position]);
+                    if count > 128 {
+                        channel.extend(std::iter::repeat_n(data[position + 1], count - 128));
+                        position += 2;
+                    } else {
+                        assert!(count > 0);
+                        channel.extend_from_slice(&data[position + 1..=position + count]);
+                        position += count + 1;
+                    }
+                }
+                assert_eq!(channel.len(), width);
+            }
+            pixels.extend((0..width).map(|x| {
+                [
+                    channels[0][x],
+                    channels[1][x],
+                    channels[2][x],
+                    channels[3][x],
+                ]
+            }));
+        } else {
+            for pixel in data[position..position + width * 4].chunks_exact(4) {
+                pixels.push(pixel.try_into().unwrap());
+            }
+            position += width * 4;
+        }
+    }
+    assert_eq!(position, data.len());
+    (width, height, pixels)
+}
+
+#[cfg(test)]
+fn from_rgbe(pixel: [u8; 4]) -> Color {
+    if pixel[3] == 0 {
+        return BLACK;
+    }
+    let scale = 2_f64.powi(i32::from(pixel[3]) - 136);
+    Color::new(
+        (f64::from(pixel[0]) + 0.5) * scale,
+        (f64::from(pixel[1]) + 0.5) * scale,
+        (f64::from(pixel[2]) + 0.5) * scale,
+    )
+}
+
+#[test]
+fn converting_colors_to_rgbe() {
+    assert_eq!(rgbe(&Color::new(1.0, 1.0, 1.0)), [128, 128, 128, 129]);
+    assert_eq!(rgbe(&Color::new(0.5, 0.25, 0.0)), [128, 64, 0, 128]);
+    assert_eq!(rgbe(&Color::new(10.0, 1.0, 0.1)), [160, 16, 1, 132]);
+    assert_eq!(rgbe(&BLACK), [0; 4]);
+    assert_eq!(rgbe(&Color::new(-1.0, 0.0, 0.0)), [0; 4]);
+    assert_eq!(rgbe(&Color::new(-1.0, 0.5, 0.0)), [0, 128, 0, 128]);
+    assert_eq!(rgbe(&Color::new(f64::INFINITY, 0.0, 0.0)), [255, 0, 0, 255]);
+}
+
+#[test]
+fn rgbe_keeps_about_1_percent_of_precision() {
+    for value in [0.003, 0.7, 1.0, 3.5, 250.0, 1e6] {
+        let color = from_rgbe(rgbe(&Color::new(value, value, value)));
+        assert!((color.red() - value).abs() / value < 0.01);
+    }
+}
+
+#[test]
+fn the_header_of_a_radiance_image() {
+    let mut data = Vec::new();
+    HdrEncoder::default()
+        .encode(&Canvas::new(3, 2), &mut data)
+        .unwrap();
+    assert!(data.starts_with(b"#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y 2 +X 3\n"));
+    assert_eq!(HdrEncoder::default().extension(), "hdr");
+    assert!(HdrEncoder::default().run_length_encoding());
+}
+
+#[test]
+fn narrow_scanlines_are_not_run_length_encoded() {
+    let mut canvas = Canvas::new(3, 1);
+    canvas.write_pixel(1, 0, Color::new(1.0, 1.0, 1.0));
+    let mut data = Vec::new();
+    HdrEncoder::default().encode(&canvas, &mut data).unwrap();
+    let header = b"#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y 1 +X 3\n".len();
+    assert_eq!(data[header..], [0, 0, 0, 0, 128, 128, 128, 129, 0, 0, 0, 0]);
+}
+
+#[test]
+fn scanlines_wider_than_0x7fff_pixels_are_not_run_length_encoded() {
+    let canvas = Canvas::new(0x8000, 1);
+    let mut data = Vec::new();
+    HdrEncoder::default().encode(&canvas, &mut data).unwrap();
+    let header = b"#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y 1 +X 32768\n".len();
+    assert_eq!(data.len(), header + 0x8000 * 4);
+    assert!(data[header..].iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn scanlines_are_run_length_encoded_by_channel() {
+    let mut canvas = Canvas::new(10, 1);
+    for (x, n) in (0..10).zip(0_u32..) {
+        canvas.write_pixel(x, 0, Color::new(1.0, 0.25 * f64::from(n % 2), 0.5));
+    }
+    let mut data = Vec::new();
+    HdrEncoder::default().encode(&canvas, &mut data).unwrap();
+    let header = b"#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y 1 +X 10\n".len();
+    let mut expected = vec![2, 2, 0, 10];
+    // Red and blue are runs, green alternates, and the exponents are a run.
+    expected.extend_from_slice(&[138, 128]);
+    expected.extend_from_slice(&[10, 0, 32, 0, 32, 0, 32, 0, 32, 0, 32]);
+    expected.extend_from_slice(&[138, 64]);
+    expected.extend_from_slice(&[138, 129]);
+    assert_eq!(data[header..], expected);
+}
+
+#[test]
+fn run_length_encoded_images_decode_to_the_canvas() {
+    let mut canvas = Canvas::new(300, 4);
+    for (y, row) in (0..4).zip(0_u32..) {
+        for (x, column) in (0..300).zip(0_u32..) {
+            let value = if x < 150 {
+                2.5
+            } else {
+                f64::from(column * row) / 100.0
+            };
+            canvas.write_pixel(x, y, Color::new(value, 0.3, f64::from(column % 7)));
+        }
+    }
+    for encoder in [HdrEncoder::new(true), HdrEncoder::new(false)] {
+        let mut data = Vec::new();
+        encoder.encode(&canvas, &mut data).unwrap();
+        let (width, height, pixels) = decode_hdr(&data);
+        assert_eq!((width, height), (300, 4));
+        for (index, pixel) in pixels.into_iter().enumerate() {
+            assert_eq!(pixel, rgbe(canvas.pixel_at(index % 300, index / 300)));
+        }
+    }
+}
+
+#[test]
+fn run_length_encoding_makes_flat_images_smaller() {
+    let mut canvas = Canvas::new(64, 64);
+    canvas.set_all_pixels_to_color(&Color::new(4.0, 2.0, 1.0));
+    let size = |encoder: HdrEncoder| {
+        let mut data = Vec::new();
+        encoder.encode(&canvas, &mut data).unwrap();
+        data.len()
+    };
+    assert!(size(HdrEncoder::new(true)) * 10 < size(HdrEncoder::new(false)));
+}
+
+#[test]
+fn writing_a_pfm_image() {
+    let mut canvas = Canvas::new(2, 2);
+    canvas.write_pixel(0, 0, Color::new(1.0, 2.0, 3.0));
+    canvas.write_pixel(1, 1, Color::new(-0.5, 0.25, 100.0));
+    let mut data = Vec::new();
+    PfmEncoder::default().encode(&canvas, &mut data).unwrap();
+    let header = b"PF\n2 2\n-1.0\n";
+    assert_eq!(&data[..header.len()], header);
+    let values: Vec<f32> = data[header.len()..]
+        .chunks_exact(4)
+        .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+        .collect();
+    // The bottom row comes first.
+    assert_eq!(
+        values,
+        [0.0, 0.0, 0.0, -0.5, 0.25, 100.0, 1.0, 2.0, 3.0, 0.0, 0.0, 0.0]
+    );
+    assert_eq!(PfmEncoder::default().extension(), "pfm");
+}
+
+#[test]
+fn pfm_images_keep_colors_above_1() {
+    let mut canvas = Canvas::new(3, 2);
+    canvas.write_pixel(0, 0, Color::new(12.5, 0.001, 3.0));
+    canvas.write_pixel(2, 1, Color::new(0.2, 0.4, 1.6));
+    let mut data = Vec::new();
+    PfmEncoder::default().encode(&canvas, &mut data).unwrap();
+    assert_eq!(Canvas::from_ppm_bytes(&data).unwrap(), canvas);
+}
@@ -1,7 +1,18 @@
 #[cfg(test)]
-use crate::{
-    rt::{color::WHITE, point_light::PointLight},
-    tuple::point::Point,
+use {
+    crate::{
+        rt::{
+            area_light::AreaLight,
+            color::{Color, BLACK, WHITE},
+            directional_light::DirectionalLight,
+            light::Light,
+            light_source::{LightSample, LightSource},
+            point_light::PointLight,
+            spot_light::SpotLight,
+        },
+        tuple::{point::Point, vector::Vector},
+    },
+    std::f64::consts::PI,
 };
 
 #[test]
@@ -12,3 +23,185 @@ fn a_point_light_has_a_position_and_intensity() {
     assert_eq!(light.position(), &position);
     assert_eq!(light.intensity(), &intensity);
 }
+
+#[test]
+fn a_point_light_is_sampled_at_its_position() {
+    let position = Point::new(0.0, 3.0, 4.0);
+    let light = Light::from(PointLight::new(position, WHITE));
+    assert_eq!(
+        light.samples(&Point::new(0.0, 0.0, 0.0)),
+        vec![LightSample::new(Vector::new(0.0, 0.6, 0.8), 5.0, WHITE)]
+    );
+}
+
+#[test]
+fn creating_an_area_light() {
+    let corner = Point::new(0.0, 0.0, 0.0);
+    let v1 = Vector::new(2.0, 0.0, 0.0);
+    let v2 = Vector::new(0.0, 0.0, 1.0);
+    let light = AreaLight::new(corner.clone(), v1, 4, v2, 2, WHITE);
+    assert_eq!(light.corner(), &corner);
+    assert_eq!(light.uvec(), &Vector::new(0.5, 0.0, 0.0));
+    assert_eq!(light.usteps(), 4);
+    assert_eq!(light.vvec(), &Vector::new(0.0, 0.0, 0.5));
+    assert_eq!(light.vsteps(), 2);
+    assert_eq!(light.sample_count(), 8);
+    assert_eq!(light.position(), &Point::new(1.0, 0.0, 0.5));
+    assert_eq!(light.intensity(), &WHITE);
+    assert_eq!(light.jitter(), None);
+}
+
+#[test]
+fn finding_a_single_point_on_an_area_light() {
+    let corner = Point::new(0.0, 0.0, 0.0);
+    let v1 = Vector::new(2.0, 0.0, 0.0);
+    let v2 = Vector::new(0.0, 0.0, 1.0);
+    let light = AreaLight::new(corner, v1, 4, v2, 2, WHITE);
+    let cases = [
+        (0, 0, Point::new(0.25, 0.0, 0.25)),
+        (1, 0, Point::new(0.75, 0.0, 0.25)),
+        (0, 1, Point::new(0.25, 0.0, 0.75)),
+        (2, 0, Point::new(1.25, 0.0, 0.25)),
+        (3, 1, Point::new(1.75, 0.0, 0.75)),
+    ];
+    for (u, v, expected) in cases {
+        assert_eq!(light.point_on_light(u, v, 0.5, 0.5), expected);
+    }
+}
+
+#[test]
+fn an_area_light_is_sampled_at_the_center_of_its_cells() {
+    let light = AreaLight::new(
+        Point::new(0.0, 0.0, 0.0),
+        Vector::new(2.0, 0.0, 0.0),
+        2,
+        Vector::new(0.0, 0.0, 1.0),
+        1,
+        WHITE,
+    );
+    assert_eq!(
+        light.sample_points(&Point::new(0.0, -1.0, 0.0)),
+        vec![Point::new(0.5, 0.0, 0.5), Point::new(1.5, 0.0, 0.5)]
+    );
+}
+
+#[test]
+fn a_jittered_area_light_is_sampled_inside_its_cells() {
+    let light = AreaLight::new(
+        Point::new(0.0, 0.0, 0.0),
+        Vector::new(2.0, 0.0, 0.0),
+        4,
+        Vector::new(0.0, 0.0, 1.0),
+        2,
+        WHITE,
+    )
+    .with_jitter(42);
+    let point = Point::new(0.0, -1.0, 0.0);
+    let samples = light.sample_points(&point);
+    assert_eq!(samples.len(), 8);
+    for (sample, n) in samples.iter().zip(0_u32..) {
+        let (u, v) = (f64::from(n % 4), f64::from(n / 4));
+        assert!(sample.x() >= u * 0.5 && sample.x() <= (u + 1.0) * 0.5);
+        assert!(sample.z() >= v * 0.5 && sample.z() <= (v + 1.0) * 0.5);
+    }
+    // Jitter is reproducible for a given point, but differs between points.
+    assert_eq!(light.sample_points(&point), samples);
+    assert_ne!(light.sample_points(&Point::new(0.1, -1.0, 0.0)), samples);
+}
+
+#[test]
+#[should_panic(expected = "An area light must have at least one cell")]
+fn an_area_light_without_cells_panics() {
+    AreaLight::new(
+        Point::new(0.0, 0.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0),
+        0,
+        Vector::new(0.0, 1.0, 0.0),
+        1,
+        WHITE,
+    );
+}
+
+#[test]
+fn a_spot_light_fully_lights_points_inside_its_inner_cone() {
+    let light = SpotLight::new(
+        Point::new(0.0, 10.0, 0.0),
+        &Vector::new(0.0, -2.0, 0.0),
+        PI / 8.0,
+        PI / 4.0,
+        WHITE,
+    );
+    assert_eq!(light.direction(), &Vector::new(0.0, -1.0, 0.0));
+    let point = Point::new(0.0, 0.0, 0.0);
+    assert_eq!(light.falloff(&point), 1.0);
+    assert_eq!(
+        light.samples(&point),
+        vec![LightSample::new(Vector::new(0.0, 1.0, 0.0), 10.0, WHITE)]
+    );
+}
+
+#[test]
+fn a_spot_light_does_not_light_points_outside_its_outer_cone() {
+    let light = SpotLight::new(
+        Point::new(0.0, 10.0, 0.0),
+        &Vector::new(0.0, -1.0, 0.0),
+        PI / 8.0,
+        PI / 4.0,
+        WHITE,
+    );
+    // 45° away from the axis is on the outer cone.
+    assert_eq!(light.falloff(&Point::new(10.0, 0.0, 0.0)), 0.0);
+    assert_eq!(light.falloff(&Point::new(20.0, 0.0, 0.0)), 0.0);
+    assert_eq!(
+        light.samples(&Point::new(20.0, 0.0, 0.0))[0].intensity(),
+        &BLACK
+    );
+    // Points behind the light are not lit either.
+    assert_eq!(light.falloff(&Point::new(0.0, 20.0, 0.0)), 0.0);
+}
+
+#[test]
+fn a_spot_light_falls_off_smoothly_between_its_cones() {
+    let light = SpotLight::new(
+        Point::new(0.0, 0.0, 0.0),
+        &Vector::new(0.0, 0.0, 1.0),
+        PI / 8.0,
+        PI / 4.0,
+        WHITE,
+    );
+    let falloffs = [0.45, 0.55, 0.65, 0.75]
+        .map(|angle: f64| light.falloff(&Point::new(angle.sin(), 0.0, angle.cos())));
+    assert!(falloffs.iter().all(|f| *f > 0.0 && *f < 1.0));
+    assert!(falloffs.windows(2).all(|f| f[0] > f[1]));
+    let point = Point::new(0.6_f64.sin(), 0.0, 0.6_f64.cos());
+    let falloff = light.falloff(&point);
+    assert_eq!(
+        light.samples(&point)[0].intensity(),
+        &Color::new(falloff, falloff, falloff)
+    );
+}
+
+#[test]
+#[should_panic(expected = "The inner cone of a spot light cannot be wider than its outer cone")]
+fn a_spot_light_with_an_inner_cone_wider_than_its_outer_cone_panics() {
+    SpotLight::new(
+        Point::new(0.0, 0.0, 0.0),
+        &Vector::new(0.0, 0.0, 1.0),
+        PI / 4.0,
+        PI / 8.0,
+        WHITE,
+    );
+}
+
+#[test]
+fn a_directional_light_lights_every_point_from_the_same_direction() {
+    let light = DirectionalLight::new(&Vector::new(0.0, -2.0, 0.0), WHITE);
+    assert_eq!(light.direction(), &Vector::new(0.0, -1.0, 0.0));
+    for point in [Point::new(0.0, 0.0, 0.0), Point::new(100.0, -50.0, 3.0)] {
+        let samples = light.samples(&point);
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].direction(), &Vector::new(0.0, 1.0, 0.0));
+        assert_eq!(samples[0].distance(), f64::INFINITY);
+        assert_eq!(samples[0].intensity(), &WHITE);
+    }
+}
@@ -1,15 +1,20 @@
 #[cfg(test)]
-use crate::{
-    approx_eq::ApproxEq,
-    rt::{
-        color::{Color, BLACK, WHITE},
-        material::Material,
-        object::Object,
-        pattern::Pattern,
-        point_light::PointLight,
-        sphere::Sphere,
+use {
+    crate::{
+        approx_eq::ApproxEq,
+        rt::{
+            area_light::AreaLight,
+            color::{Color, BLACK, WHITE},
+            light::Light,
+            material::Material,
+            object::Object,
+            pattern::Pattern,
+            point_light::PointLight,
+            sphere::Sphere,
+        },
+        tuple::{point::Point, vector::Vector},
     },
-    tuple::{point::Point, vector::Vector},
+    std::f64::consts::FRAC_1_SQRT_2,
 };
 
 #[test]
@@ -28,8 +33,8 @@ fn lighting_with_the_eye_between_the_light_and_the_surface() {
     let position = Point::new(0.0, 0.0, 0.0);
     let eye = Vector::new(0.0, 0.0, -1.0);
     let normal = Vector::new(0.0, 0.0, -1.0);
-    let light = PointLight::new(Point::new(0.0, 0.0, -10.0), WHITE);
-    let result = m.lighting(&object, &light, &position, &eye, &normal, false);
+    let light = Light::Point(PointLight::new(Point::new(0.0, 0.0, -10.0), WHITE));
+    let result = m.lighting(&object, &light, &position, &eye, &normal, WHITE);
     assert_eq!(result, Color::new(1.9, 1.9, 1.9));
 }
 
@@ -40,8 +45,8 @@ fn lighting_with_the_eye_between_the_light_and_surface_eye_offset_45deg() {
     let position = Point::new(0.0, 0.0, 0.0);
     let eye = Vector::new(0.0, 2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0);
     let normal = Vector::new(0.0, 0.0, -1.0);
-    let light = PointLight::new(Point::new(0.0, 0.0, -10.0), WHITE);
-    let result = m.lighting(&object, &light, &position, &eye, &normal, false);
+    let light = Light::Point(PointLight::new(Point::new(0.0, 0.0, -10.0), WHITE));
+    let result = m.lighting(&object, &light, &position, &eye, &normal, WHITE);
     assert_eq!(result, WHITE);
 }
 
@@ -52,8 +57,8 @@ fn lighting_with_eye_opposite_surface_light_offset_45deg() {
     let position = Point::new(0.0, 0.0, 0.0);
     let eye = Vector::new(0.0, 0.0, -1.0);
     let normal = Vector::new(0.0, 0.0, -1.0);
-    let light = PointLight::new(Point::new(0.0, 10.0, -10.0), WHITE);
-    let result = m.lighting(&object, &light, &position, &eye, &normal, false);
+    let light = Light::Point(PointLight::new(Point::new(0.0, 10.0, -10.0), WHITE));
+    let result = m.lighting(&object, &light, &position, &eye, &normal, WHITE);
     assert_eq!(result, Color::new(0.7364, 0.7364, 0.7364));
 }
 
@@ -64,8 +69,8 @@ fn lighting_with_eye_in_the_path_of_the_reflection_vector() {
     let position = Point::new(0.0, 0.0, 0.0);
     let eye = Vector::new(0.0, -2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0);
     let normal = Vector::new(0.0, 0.0, -1.0);
-    let light = PointLight::new(Point::new(0.0, 10.0, -10.0), WHITE);
-    let result = m.lighting(&object, &light, &position, &eye, &normal, false);
+    let light = Light::Point(PointLight::new(Point::new(0.0, 10.0, -10.0), WHITE));
+    let result = m.lighting(&object, &light, &position, &eye, &normal, WHITE);
     assert_eq!(
         result,
         Color::new(1.6363961030678928, 1.6363961030678928, 1.6363961030678928)
@@ -79,8 +84,8 @@ fn lighting_with_the_eye_behind_the_suface() {
     let position = Point::new(0.0, 0.0, 0.0);
     let eye = Vector::new(0.0, 0.0, -1.0);
     let normal = Vector::new(0.0, 0.0, -1.0);
-    let light = PointLight::new(Point::new(0.0, 0.0, 10.0), WHITE);
-    let result = m.lighting(&object, &light, &position, &eye, &normal, false);
+    let light = Light::Point(PointLight::new(Point::new(0.0, 0.0, 10.0), WHITE));
+    let result = m.lighting(&object, &light, &position, &eye, &normal, WHITE);
     assert_eq!(result, Color::new(0.1, 0.1, 0.1));
 }
 
@@ -91,12 +96,41 @@ fn lighting_with_the_surface_in_shadow() {
     let position = Point::new(0.0, 0.0, 0.0);
     let eye = Vector::new(0.0, 0.0, -1.0);
     let normal = Vector::new(0.0, 0.0, -1.0);
-    let light = PointLight::new(Point::new(0.0, 0.0, -10.0), WHITE);
-    let in_shadow = true;
-    let result = m.lighting(&object, &light, &position, &eye, &normal, in_shadow);
+    let light = Light::Point(PointLight::new(Point::new(0.0, 0.0, -10.0), WHITE));
+    let light_intensity = BLACK;
+    let result = m.lighting(&object, &light, &position, &eye, &normal, light_intensity);
     assert_eq!(result, Color::new(0.1, 0.1, 0.1));
 }
 
+#[test]
+fn lighting_with_the_surface_partially_in_shadow() {
+    let object = Object::Sphere(Sphere::default());
+    let m = Material::default();
+    let position = Point::new(0.0, 0.0, 0.0);
+    let eye = Vector::new(0.0, 0.0, -1.0);
+    let normal = Vector::new(0.0, 0.0, -1.0);
+    let light = Light::Point(PointLight::new(Point::new(0.0, 0.0, -10.0), WHITE));
+    let light_intensity = Color::new(1.0, 0.5, 0.0);
+    let result = m.lighting(&object, &light, &position, &eye, &normal, light_intensity);
+    // Ambient (0.1) is always there, diffuse (0.9) and specular (0.9) are filtered.
+    assert_eq!(result, Color::new(1.9, 1.0, 0.1));
+}
+
+#[test]
+fn materials_with_patterns_can_be_cloned_and_compared() {
+    let mut m = Material::default();
+    m.set_pattern(Pattern::stripe(WHITE, BLACK, None));
+    let copy = m.clone();
+    assert_eq!(copy, m);
+    assert_eq!(copy.pattern(), m.pattern());
+    assert_ne!(m, Material::default());
+
+    let mut other = Material::default();
+    other.set_pattern(Pattern::stripe(BLACK, WHITE, None));
+    assert_ne!(m, other);
+    assert!(format!("{m:?}").contains("Stripe"));
+}
+
 #[test]
 fn lighting_with_a_pattern_applied() {
     let object = Object::Sphere(Sphere::default());
@@ -114,14 +148,17 @@ fn lighting_with_a_pattern_applied() {
     );
     let eye = Vector::new(0.0, 0.0, -1.0);
     let normal = Vector::new(0.0, 0.0, -1.0);
-    let light = PointLight::new(Point::new(0.0, 0.0, -10.0), Color::new(1.0, 1.0, 1.0));
+    let light = Light::Point(PointLight::new(
+        Point::new(0.0, 0.0, -10.0),
+        Color::new(1.0, 1.0, 1.0),
+    ));
     let c1 = m.lighting(
         &object,
         &light,
         &Point::new(0.9, 0.0, 0.0),
         &eye,
         &normal,
-        false,
+        WHITE,
     );
     let c2 = m.lighting(
         &object,
@@ -129,8 +166,42 @@ fn lighting_with_a_pattern_applied() {
         &Point::new(1.1, 0.0, 0.0),
         &eye,
         &normal,
-        false,
+        WHITE,
     );
     assert_eq!(c1, WHITE);
     assert_eq!(c2, BLACK);
 }
+
+#[test]
+fn lighting_samples_the_area_light() {
+    let light = AreaLight::new(
+        Point::new(-0.5, -0.5, -5.0),
+        Vector::new(1.0, 0.0, 0.0),
+        2,
+        Vector::new(0.0, 1.0, 0.0),
+        2,
+        WHITE,
+    )
+    .into();
+    let object = Object::Sphere(Sphere::default());
+    let m = Material::new(WHITE, None, 0.1, 0.9, 0.0, 200.0, 0.0, 0.0, 1.0);
+    let eye = Point::new(0.0, 0.0, -5.0);
+    let cases = [
+        (
+            Point::new(0.0, 0.0, -1.0),
+            Color::new(0.9965, 0.9965, 0.9965),
+        ),
+        (
+            Point::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+            Color::new(0.6232, 0.6232, 0.6232),
+        ),
+    ];
+    for (point, expected) in cases {
+        let eye_vector = (&eye - &point).normalized();
+        let normal = Vector::new(point.x(), point.y(), point.z());
+        let result = m.lighting(&object, &light, &point, &eye_vector, &normal, WHITE);
+        assert!((result.red() - expected.red()).abs() < 0.0001);
+        assert!((result.green() - expected.green()).abs() < 0.0001);
+        assert!((result.blue() - expected.blue()).abs() < 0.0001);
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+use crate::{approx_eq::ApproxEq, rt::noise::Noise, tuple::point::Point};
+
+#[cfg(test)]
+fn sample_points() -> impl Iterator<Item = Point> {
+    (0..1000).map(|i| {
+        let i = f64::from(i);
+        Point::new(i * 0.173 - 40.0, i * 0.091 - 20.0, i * 0.057)
+    })
+}
+
+#[test]
+fn noise_is_deterministic_for_a_given_seed() {
+    let a = Noise::new(42);
+    let b = Noise::new(42);
+    for point in sample_points() {
+        assert_eq!(a.at(&point).to_bits(), b.at(&point).to_bits());
+    }
+}
+
+#[test]
+fn different_seeds_give_different_noise() {
+    let a = Noise::new(1);
+    let b = Noise::new(2);
+    assert!(sample_points().any(|point| !a.at(&point).approx_eq(b.at(&point))));
+}
+
+#[test]
+fn noise_is_zero_on_lattice_points() {
+    let noise = Noise::default();
+    for (x, y, z) in [(0.0, 0.0, 0.0), (1.0, 2.0, 3.0), (-4.0, 7.0, -300.0)] {
+        assert!(noise.at(&Point::new(x, y, z)).approx_eq(0.0));
+    }
+}
+
+#[test]
+fn noise_stays_within_bounds_and_varies() {
+    let noise = Noise::new(7);
+    let values: Vec<f64> = sample_points().map(|point| noise.at(&point)).collect();
+    assert!(values.iter().all(|value| (-1.1..=1.1).contains(value)));
+    assert!(values.iter().any(|&value| value > 0.1));
+    assert!(values.iter().any(|&value| value < -0.1));
+}
+
+#[test]
+fn noise_is_continuous() {
+    let noise = Noise::new(7);
+    for point in sample_points() {
+        let nearby = Point::new(point.x() + 0.001, point.y() - 0.001, point.z() + 0.001);
+        assert!((noise.at(&point) - noise.at(&nearby)).abs() < 0.01);
+    }
+}
+
+#[test]
+fn a_single_octave_fractal_sum_is_the_noise() {
+    let noise = Noise::new(3);
+    for point in sample_points() {
+        assert!(noise.fractal_sum(&point, 1).approx_eq(noise.at(&point)));
+    }
+}
+
+#[test]
+fn fractal_sum_adds_smaller_and_finer_layers() {
+    let noise = Noise::new(3);
+    for point in sample_points() {
+        let double = Point::new(point.x() * 2.0, point.y() * 2.0, point.z() * 2.0);
+        let expected = noise.at(&point) + noise.at(&double) / 2.0;
+        assert!((noise.fractal_sum(&point, 2) - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn turbulence_is_never_negative() {
+    let noise = Noise::new(5);
+    let values: Vec<f64> = sample_points()
+        .map(|point| noise.turbulence(&point, 4))
+        .collect();
+    assert!(values.iter().all(|&value| (0.0..=2.2).contains(&value)));
+    assert!(values.iter().any(|&value| value > 0.1));
+}
@@ -0,0 +1,244 @@
+#[cfg(test)]
+use crate::{
+    rt::{
+        group::Group,
+        matrix::Matrix,
+        obj_parser::{ObjError, ObjParser},
+        object::Object,
+        smooth_triangle::SmoothTriangle,
+        triangle::Triangle,
+    },
+    tuple::{point::Point, vector::Vector},
+};
+
+#[test]
+fn ignoring_unrecognized_lines() {
+    let gibberish = "There was a young lady named Bright
+who traveled much faster than light.
+She set out one day
+in a relative way,
+and came back the previous night.";
+    let parser = ObjParser::parse(gibberish).expect("Parsing failed!");
+    assert_eq!(parser.ignored_lines().len(), 5);
+    assert!(matches!(
+        parser.ignored_lines()[0],
+        ObjError::IgnoredLine { line: 1, .. }
+    ));
+}
+
+#[test]
+fn vertex_records() {
+    let file = "v -1 1 0
+v -1.0000 0.5000 0.0000
+v 1 0 0
+v 1 1 0";
+    let parser = ObjParser::parse(file).expect("Parsing failed!");
+    assert_eq!(parser.vertices()[0], Point::new(-1.0, 1.0, 0.0));
+    assert_eq!(parser.vertices()[1], Point::new(-1.0, 0.5, 0.0));
+    assert_eq!(parser.vertices()[2], Point::new(1.0, 0.0, 0.0));
+    assert_eq!(parser.vertices()[3], Point::new(1.0, 1.0, 0.0));
+}
+
+#[test]
+fn parsing_triangle_faces() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+f 1 3 4";
+    let parser = ObjParser::parse(file).expect("Parsing failed!");
+    let g = parser.default_group();
+    let (v1, v2, v3, v4) = (
+        parser.vertices()[0].clone(),
+        parser.vertices()[1].clone(),
+        parser.vertices()[2].clone(),
+        parser.vertices()[3].clone(),
+    );
+    assert!(parser.ignored_lines().is_empty());
+    assert_eq!(
+        g.children(),
+        &[
+            Object::Triangle(Triangle::new(v1.clone(), v2, v3.clone())),
+            Object::Triangle(Triangle::new(v1, v3, v4)),
+        ]
+    );
+}
+
+#[test]
+fn triangulating_polygons() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+v 0 2 0
+
+f 1 2 3 4 5";
+    let parser = ObjParser::parse(file).expect("Parsing failed!");
+    let v = parser.vertices();
+    assert_eq!(
+        parser.default_group().children(),
+        &[
+            Object::Triangle(Triangle::new(v[0].clone(), v[1].clone(), v[2].clone())),
+            Object::Triangle(Triangle::new(v[0].clone(), v[2].clone(), v[3].clone())),
+            Object::Triangle(Triangle::new(v[0].clone(), v[3].clone(), v[4].clone())),
+        ]
+    );
+}
+
+#[test]
+fn triangles_in_groups() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4";
+    let parser = ObjParser::parse(file).expect("Parsing failed!");
+    let v = parser.vertices();
+    let g1 = parser
+        .group("FirstGroup")
+        .expect("No group named FirstGroup!");
+    let g2 = parser
+        .group("SecondGroup")
+        .expect("No group named SecondGroup!");
+    assert!(parser.default_group().is_empty());
+    assert_eq!(
+        g1.children(),
+        &[Object::Triangle(Triangle::new(
+            v[0].clone(),
+            v[1].clone(),
+            v[2].clone()
+        ))]
+    );
+    assert_eq!(
+        g2.children(),
+        &[Object::Triangle(Triangle::new(
+            v[0].clone(),
+            v[2].clone(),
+            v[3].clone()
+        ))]
+    );
+}
+
+#[test]
+fn converting_an_obj_file_to_a_group() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4";
+    let parser = ObjParser::parse(file).expect("Parsing failed!");
+    let v = parser.vertices().to_vec();
+    let g = parser.into_group();
+    assert_eq!(
+        g.children(),
+        &[
+            Object::Group(Group::new(
+                Matrix::<4>::identity(),
+                vec![Triangle::new(v[0].clone(), v[1].clone(), v[2].clone()).into()]
+            )),
+            Object::Group(Group::new(
+                Matrix::<4>::identity(),
+                vec![Triangle::new(v[0].clone(), v[2].clone(), v[3].clone()).into()]
+            )),
+        ]
+    );
+}
+
+#[test]
+fn vertex_normal_records() {
+    let file = "vn 0 0 1
+vn 0.707 0 -0.707
+vn 1 2 3";
+    let parser = ObjParser::parse(file).expect("Parsing failed!");
+    assert_eq!(parser.normals()[0], Vector::new(0.0, 0.0, 1.0));
+    assert_eq!(parser.normals()[1], Vector::new(0.707, 0.0, -0.707));
+    assert_eq!(parser.normals()[2], Vector::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn faces_with_normals() {
+    let file = "v 0 1 0
+v -1 0 0
+v 1 0 0
+
+vn -1 0 0
+vn 1 0 0
+vn 0 1 0
+
+f 1//3 2//1 3//2
+f 1/0/3 2/102/1 3/14/2";
+    let parser = ObjParser::parse(file).expect("Parsing failed!");
+    let (v, n) = (parser.vertices(), parser.normals());
+    let expected = Object::SmoothTriangle(SmoothTriangle::new(
+        v[0].clone(),
+        v[1].clone(),
+        v[2].clone(),
+        n[2].clone(),
+        n[0].clone(),
+        n[1].clone(),
+    ));
+    let children = parser.default_group().children();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0], expected);
+    assert_eq!(children[1], expected);
+}
+
+#[test]
+fn negative_indices_are_relative_to_the_last_vertex() {
+    let file = "v -1 1 0
+v -1 0 0
+v 1 0 0
+f -3 -2 -1";
+    let parser = ObjParser::parse(file).expect("Parsing failed!");
+    let v = parser.vertices();
+    assert_eq!(
+        parser.default_group().children(),
+        &[Object::Triangle(Triangle::new(
+            v[0].clone(),
+            v[1].clone(),
+            v[2].clone()
+        ))]
+    );
+}
+
+#[test]
+fn malformed_data_is_reported() {
+    let vertices = "v -1 1 0\nv -1 0 0\nv 1 0 0\n";
+    let examples = [
+        "f 1 2 4",
+        "f 1 2 0",
+        "f 1 2 x",
+        "f 1//2 2//1 3//1",
+        "f 1 2",
+        "v 1 two 3",
+        "vn 1 2",
+    ];
+    for statement in examples {
+        let error = ObjParser::parse(&format!("{vertices}{statement}"))
+            .expect_err("Parsing should have failed!");
+        let (ObjError::InvalidIndex { line, .. }
+        | ObjError::InvalidFace { line, .. }
+        | ObjError::InvalidCoordinates { line, .. }) = error
+        else {
+            panic!("Unexpected error: {error}");
+        };
+        assert_eq!(line, 4);
+    }
+}
+
+#[test]
+fn reading_a_missing_file_is_an_io_error() {
+    let error = ObjParser::parse_file("this/file/does/not/exist.obj")
+        .expect_err("Parsing should have failed!");
+    assert!(matches!(error, ObjError::Io(_)));
+}
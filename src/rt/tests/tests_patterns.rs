@@ -2,9 +2,12 @@
 use crate::{
     rt::{
         color::{Color, BLACK, WHITE},
+        group::Group,
+        intersection::Intersection,
         matrix::Matrix,
+        noise::Noise,
         object::Object,
-        pattern::{test_pattern, Pattern},
+        pattern::{test_pattern, Paint, Pattern, PatternKind},
         shape::Shape,
         sphere::Sphere,
     },
@@ -51,6 +54,23 @@ fn stripes_with_an_object_transformation() {
     assert_eq!(c, WHITE);
 }
 
+#[test]
+fn stripes_on_an_object_inside_a_scaled_group() {
+    let group = Object::Group(Group::new(
+        Matrix::<4>::scaling(2.0, 2.0, 2.0),
+        vec![Sphere::default().into()],
+    ));
+    let Object::Group(inner) = &group else {
+        unreachable!()
+    };
+    let mut i = Intersection::new(0.0, &inner.children()[0]);
+    i.add_parent(&group);
+    let pattern = Pattern::stripe(WHITE, BLACK, None);
+    let point = Point::new(1.5, 0.0, 0.0);
+    assert_eq!(pattern.at_intersection(&i, &point), WHITE);
+    assert_eq!(pattern.at_object(i.object(), &point), BLACK);
+}
+
 #[test]
 fn stripes_with_a_pattern_transformation() {
     let object = Object::Sphere(Sphere::default());
@@ -165,3 +185,235 @@ fn checkers_should_repeat_in_z() {
     assert_eq!(pattern.at(&Point::new(0.0, 0.0, 0.99)), WHITE);
     assert_eq!(pattern.at(&Point::new(0.0, 0.0, 1.01)), BLACK);
 }
+
+#[test]
+fn a_noise_pattern_blends_between_its_colors() {
+    let pattern = Pattern::noise(BLACK, WHITE, Noise::new(1), 3, None);
+    assert_eq!(pattern.a(), &BLACK);
+    assert_eq!(pattern.b(), &WHITE);
+    // The noise is 0 on lattice points, which falls halfway between the colors.
+    assert_eq!(
+        pattern.at(&Point::new(0.0, 0.0, 0.0)),
+        Color::new(0.5, 0.5, 0.5)
+    );
+    let color = pattern.at(&Point::new(0.3, 1.7, -2.4));
+    assert!((0.0..=1.0).contains(&color.red()));
+    assert_ne!(color, Color::new(0.5, 0.5, 0.5));
+}
+
+#[test]
+fn a_perturbed_pattern_with_no_scale_is_the_original_pattern() {
+    let pattern = Pattern::perturbed(test_pattern(), Noise::new(1), 0.0, None);
+    let point = Point::new(0.3, 1.7, -2.4);
+    assert_eq!(pattern.at(&point), Color::new(0.3, 1.7, -2.4));
+}
+
+#[test]
+fn a_perturbed_pattern_jitters_points() {
+    let pattern = Pattern::perturbed(test_pattern(), Noise::new(1), 0.5, None);
+    let point = Point::new(0.3, 1.7, -2.4);
+    let color = pattern.at(&point);
+    assert_ne!(color, Color::new(0.3, 1.7, -2.4));
+    assert!((color.red() - 0.3).abs() <= 0.55);
+    assert!((color.green() - 1.7).abs() <= 0.55);
+    assert!((color.blue() + 2.4).abs() <= 0.55);
+}
+
+#[test]
+fn a_perturbed_pattern_keeps_the_transform_of_the_original_pattern() {
+    let mut inner = test_pattern();
+    inner.set_transform(Matrix::<4>::scaling(2.0, 2.0, 2.0));
+    let pattern = Pattern::perturbed(inner, Noise::new(1), 0.0, None);
+    assert_eq!(
+        pattern.at(&Point::new(2.0, 3.0, 4.0)),
+        Color::new(1.0, 1.5, 2.0)
+    );
+}
+
+#[test]
+fn turbulent_and_fractal_patterns_jitter_points() {
+    let point = Point::new(0.3, 1.7, -2.4);
+    let original = Color::new(0.3, 1.7, -2.4);
+    let turbulent = Pattern::turbulent(test_pattern(), Noise::new(1), 4, 0.5, None);
+    let fractal = Pattern::fractal(test_pattern(), Noise::new(1), 4, 0.5, None);
+    assert_ne!(turbulent.at(&point), original);
+    assert_ne!(fractal.at(&point), original);
+    assert_ne!(turbulent.at(&point), fractal.at(&point));
+}
+
+#[test]
+fn a_perturbed_stripe_pattern_is_deterministic() {
+    let marble = |seed| {
+        Pattern::turbulent(
+            Pattern::stripe(WHITE, BLACK, Some(Matrix::<4>::scaling(0.2, 0.2, 0.2))),
+            Noise::new(seed),
+            4,
+            1.0,
+            None,
+        )
+    };
+    let (a, b) = (marble(9), marble(9));
+    let object = Object::Sphere(Sphere::default());
+    for i in 0..100 {
+        let point = Point::new(f64::from(i) * 0.05, 0.5, -0.25);
+        assert_eq!(a.at_object(&object, &point), b.at_object(&object, &point));
+    }
+}
+
+#[test]
+fn a_ring_pattern_repeats() {
+    let pattern = Pattern::ring(WHITE, BLACK, None);
+    assert_eq!(pattern.at(&Point::new(2.0, 0.0, 0.0)), WHITE);
+    assert_eq!(pattern.at(&Point::new(0.0, 0.0, -2.5)), WHITE);
+    assert_eq!(pattern.at(&Point::new(3.0, 0.0, 0.0)), BLACK);
+}
+
+#[test]
+fn a_radial_gradient_interpolates_along_the_distance_from_the_y_axis() {
+    let pattern = Pattern::radial_gradient(WHITE, BLACK, None);
+    assert_eq!(pattern.at(&Point::new(0.0, 0.0, 0.0)), WHITE);
+    assert_eq!(
+        pattern.at(&Point::new(0.25, 0.0, 0.0)),
+        Color::new(0.75, 0.75, 0.75)
+    );
+    assert_eq!(
+        pattern.at(&Point::new(0.3, 5.0, 0.4)),
+        Color::new(0.5, 0.5, 0.5)
+    );
+    assert_eq!(
+        pattern.at(&Point::new(0.0, 0.0, -1.75)),
+        Color::new(0.25, 0.25, 0.25)
+    );
+}
+
+#[test]
+fn a_blend_pattern_is_a_weighted_average() {
+    let pattern = Pattern::blend(WHITE, BLACK, 0.25, None);
+    assert_eq!(
+        pattern.at(&Point::new(0.0, 0.0, 0.0)),
+        Color::new(0.75, 0.75, 0.75)
+    );
+    assert_eq!(pattern.a(), &WHITE);
+    assert_eq!(pattern.b(), &BLACK);
+}
+
+#[test]
+fn blending_two_patterns() {
+    let stripes = Pattern::stripe(WHITE, BLACK, None);
+    let rotated = Pattern::stripe(
+        WHITE,
+        BLACK,
+        Some(Matrix::<4>::rotation_y(std::f64::consts::FRAC_PI_2)),
+    );
+    let pattern = Pattern::blend(stripes, rotated, 0.5, None);
+    assert_eq!(pattern.at(&Point::new(0.5, 0.0, -0.5)), WHITE);
+    assert_eq!(
+        pattern.at(&Point::new(1.5, 0.0, -0.5)),
+        Color::new(0.5, 0.5, 0.5)
+    );
+    assert_eq!(pattern.at(&Point::new(1.5, 0.0, 0.5)), BLACK);
+}
+
+#[test]
+fn a_pattern_arm_can_be_a_sub_pattern() {
+    let stripes = Pattern::stripe(WHITE, BLACK, None);
+    let pattern = Pattern::checkers(stripes, Color::new(1.0, 0.0, 0.0), None);
+    assert_eq!(pattern.at(&Point::new(0.5, 0.0, 0.0)), WHITE);
+    assert_eq!(pattern.at(&Point::new(1.5, 1.0, 0.0)), BLACK);
+    assert_eq!(
+        pattern.at(&Point::new(1.5, 0.0, 0.0)),
+        Color::new(1.0, 0.0, 0.0)
+    );
+    assert!(pattern.a().pattern().is_some());
+    assert!(pattern.a().color().is_none());
+    assert_eq!(pattern.b(), &Color::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn a_sub_pattern_has_its_own_transform() {
+    let stripes = Pattern::stripe(WHITE, BLACK, Some(Matrix::<4>::scaling(0.5, 1.0, 1.0)));
+    let pattern = Pattern::gradient(stripes, BLACK, Some(Matrix::<4>::scaling(4.0, 1.0, 1.0)));
+    let object = Object::Sphere(Sphere::default());
+    // In pattern space, x = 0.5: halfway from the stripes to black. In the space of the stripes,
+    // x = 1.0: the second (black) stripe.
+    assert_eq!(
+        pattern.at_object(&object, &Point::new(2.0, 0.0, 0.0)),
+        BLACK
+    );
+    // Pattern space x = 0.25, stripes space x = 0.5: the first (white) stripe.
+    assert_eq!(
+        pattern.at_object(&object, &Point::new(1.0, 0.0, 0.0)),
+        Color::new(0.75, 0.75, 0.75)
+    );
+}
+
+#[test]
+fn patterns_are_compared_by_value() {
+    let stripes = Paint::from(Pattern::stripe(WHITE, BLACK, None));
+    assert_eq!(stripes, Paint::from(Pattern::stripe(WHITE, BLACK, None)));
+    assert_ne!(stripes, Paint::from(Pattern::stripe(BLACK, WHITE, None)));
+    assert_ne!(
+        stripes,
+        Paint::from(Pattern::stripe(
+            WHITE,
+            BLACK,
+            Some(Matrix::<4>::scaling(2.0, 2.0, 2.0))
+        ))
+    );
+    assert_ne!(stripes, Paint::from(WHITE));
+    assert_eq!(Paint::from(WHITE), Paint::Solid(WHITE));
+}
+
+#[test]
+fn custom_patterns_are_only_equal_to_their_clones() {
+    let pattern = test_pattern();
+    assert_eq!(pattern, pattern.clone());
+    assert_ne!(pattern, test_pattern());
+}
+
+#[test]
+fn a_pattern_is_described_by_its_kind() {
+    let pattern = Pattern::checkers(WHITE, Pattern::stripe(WHITE, BLACK, None), None);
+    assert_eq!(
+        pattern.kind(),
+        &PatternKind::Checkers {
+            a: Paint::Solid(WHITE),
+            b: Paint::from(Pattern::stripe(WHITE, BLACK, None)),
+        }
+    );
+    assert_eq!(
+        Pattern::from_kind(pattern.kind().clone(), None).at(&Point::new(1.5, 0.0, 0.0)),
+        BLACK
+    );
+    let debug = format!("{pattern:?}");
+    assert!(debug.contains("Checkers"));
+    assert!(debug.contains("Stripe"));
+}
+
+#[test]
+fn a_cloned_pattern_is_shared_across_threads() {
+    let pattern = Pattern::turbulent(
+        Pattern::ring(WHITE, BLACK, None),
+        Noise::new(4),
+        3,
+        0.5,
+        None,
+    );
+    let point = Point::new(0.7, 0.2, 1.3);
+    let expected = pattern.at(&point);
+    let copy = pattern.clone();
+    let color = std::thread::spawn(move || copy.at(&point)).join().unwrap();
+    assert_eq!(color, expected);
+}
+
+#[test]
+fn a_pattern_without_arms() {
+    let pattern = test_pattern();
+    assert!(pattern.arms().is_none());
+}
+
+#[test]
+#[should_panic(expected = "Arms are not set on the Pattern")]
+fn accessing_the_arms_of_a_pattern_without_arms_panics() {
+    test_pattern().a();
+}
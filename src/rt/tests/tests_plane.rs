@@ -1,6 +1,7 @@
 #[cfg(test)]
 use crate::{
     rt::{
+        intersection::Intersection,
         object::Object,
         plane::Plane,
         ray::{Intersect, Ray},
@@ -12,10 +13,11 @@ use crate::{
 #[test]
 fn the_normal_of_a_plane_is_constant_everywhere() {
     let p = Object::Plane(Plane::default());
+    let i = Intersection::new(0.0, &p);
     let (n1, n2, n3) = (
-        p.normal_at(&Point::new(0.0, 0.0, 0.0)),
-        p.normal_at(&Point::new(10.0, 0.0, -10.0)),
-        p.normal_at(&Point::new(-5.0, 0.0, 150.0)),
+        p.normal_at(&Point::new(0.0, 0.0, 0.0), &i),
+        p.normal_at(&Point::new(10.0, 0.0, -10.0), &i),
+        p.normal_at(&Point::new(-5.0, 0.0, 150.0), &i),
     );
     let expected = Vector::new(0.0, 1.0, 0.0);
     assert_eq!(n1, expected);
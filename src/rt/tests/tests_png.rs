@@ -0,0 +1,373 @@
+#[cfg(test)]
+use crate::rt::{
+    canvas::Canvas,
+    color::{Color, BLACK, WHITE},
+    export_pipeline::{ExportPipeline, TransferFunction},
+    image_encoder::ImageEncoder,
+    png::{crc32, PngBitDepth, PngColorType, PngEncoder, PNG_SIGNATURE},
+    ppm_writer::{PpmDepth, PpmEncoder},
+    zlib::{adler32, compress, Compression},
+};
+
+/// Minimal inflate, for the stored and fixed Huffman blocks written by `zlib::compress`.
+#[cfg(test)]
+fn inflate(zlib: &[u8]) -> Vec<u8> {
+    struct Bits<'a> {
+        data: &'a [u8],
+        position: usize,
+    }
+    impl Bits<'_> {
+        fn bit(&mut self) -> u32 {
+            let bit = (self.data[self.position / 8] >> (self.position % 8)) & 1;
+            self.position += 1;
+            u32::from(bit)
+        }
+        fn bits(&mut self, count: u32) -> u32 {
+            (0..count).fold(0, |value, i| value | self.bit() << i)
+        }
+        fn huffman(&mut self, count: u32) -> u32 {
+            (0..count).fold(0, |value, _| value << 1 | self.bit())
+        }
+        fn literal(&mut self) -> u32 {
+            let code = self.huffman(7);
+            if code <= 0b001_0111 {
+                return code + 256;
+            }
+            let code = code << 1 | self.bit();
+            match code {
+                0x30..=0xBF => code - 0x30,
+                0xC0..=0xC7 => code - 0xC0 + 280,
+                _ => (code << 1 | self.bit()) - 0x190 + 144,
+            }
+        }
+    }
+    const LENGTHS: [u32; 29] = [
+        3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115,
+        131, 163, 195, 227, 258,
+    ];
+    const DISTANCES: [u32; 30] = [
+        1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+        2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+    ];
+
+    assert_eq!(u16::from_be_bytes([zlib[0], zlib[1]]) % 31, 0);
+    let mut bits = Bits {
+        data: &zlib[2..],
+        position: 0,
+    };
+    let mut output: Vec<u8> = Vec::new();
+    loop {
+        let is_final = bits.bit() == 1;
+        match bits.bits(2) {
+            0 => {
+                bits.position = bits.position.div_ceil(8) * 8;
+                let start = bits.position / 8;
+                let length =
+                    usize::from(u16::from_le_bytes([bits.data[start], bits.data[start + 1]]));
+                let check = u16::from_le_bytes([bits.data[start + 2], bits.data[start + 3]]);
+                assert_eq!(usize::from(!check), length);
+                output.extend_from_slice(&bits.data[start + 4..start + 4 + length]);
+                bits.position += (4 + length) * 8;
+            }
+            1 => loop {
+                let symbol = bits.literal();
+                match symbol {
+                    0..=255 => output.push(u8::try_from(symbol).unwrap()),
+                    256 => break,
+                    _ => {
+                        let code = symbol - 257;
+                        let extra = if (8..28).contains(&code) {
+                            code / 4 - 1
+                        } else {
+                            0
+                        };
+                        let length = LENGTHS[usize::try_from(code).unwrap()] + bits.bits(extra);
+                        let code = bits.huffman(5);
+                        let extra = if code < 4 { 0 } else { code / 2 - 1 };
+                        let distance = usize::try_from(
+                            DISTANCES[usize::try_from(code).unwrap()] + bits.bits(extra),
+                        )
+                        .unwrap();
+                        for _ in 0..length {
+                            output.push(output[output.len() - distance]);
+                        }
+                    }
+                }
+            },
+            block_type => panic!("Unexpected block type {block_type}"),
+        }
+        if is_final {
+            break;
+        }
+    }
+    let end = 2 + bits.position.div_ceil(8);
+    assert_eq!(zlib[end..], adler32(&output).to_be_bytes());
+    output
+}
+
+/// Splits a PNG file into its chunks, checking their CRCs.
+#[cfg(test)]
+fn chunks(png: &[u8]) -> Vec<([u8; 4], Vec<u8>)> {
+    assert_eq!(png[..8], PNG_SIGNATURE);
+    let mut chunks = Vec::new();
+    let mut position = 8;
+    while position < png.len() {
+        let length = u32::from_be_bytes(png[position..position + 4].try_into().unwrap()) as usize;
+        let kind: [u8; 4] = png[position + 4..position + 8].try_into().unwrap();
+        let data = png[position + 8..position + 8 + length].to_vec();
+        let crc = u32::from_be_bytes(
+            png[position + 8 + length..position + 12 + length]
+                .try_into()
+                .unwrap(),
+        );
+        assert_eq!(crc, crc32(&png[position + 4..position + 8 + length]));
+        chunks.push((kind, data));
+        position += 12 + length;
+    }
+    chunks
+}
+
+/// Returns the unfiltered image data of a PNG file.
+#[cfg(test)]
+fn decode(png: &[u8], bytes_per_pixel: usize, width: usize) -> Vec<Vec<u8>> {
+    let chunks = chunks(png);
+    let zlib: Vec<u8> = chunks
+        .iter()
+        .filter(|(kind, _)| kind == b"IDAT")
+        .flat_map(|(_, data)| data.clone())
+        .collect();
+    let data = inflate(&zlib);
+    let row_length = width * bytes_per_pixel;
+    let mut previous = vec![0_u8; row_length];
+    data.chunks(row_length + 1)
+        .map(|filtered| {
+            let mut row = vec![0_u8; row_length];
+            for i in 0..row_length {
+                let left = if i >= bytes_per_pixel {
+                    row[i - bytes_per_pixel]
+                } else {
+                    0
+                };
+                let up = previous[i];
+                let up_left = if i >= bytes_per_pixel {
+                    previous[i - bytes_per_pixel]
+                } else {
+                    0
+                };
+                let prediction = match filtered[0] {
+                    0 => 0,
+                    1 => left,
+                    2 => up,
+                    3 => u8::midpoint(left, up),
+                    4 => {
+                        let p = i16::from(left) + i16::from(up) - i16::from(up_left);
+                        let (pa, pb, pc) = (
+                            (p - i16::from(left)).abs(),
+                            (p - i16::from(up)).abs(),
+                            (p - i16::from(up_left)).abs(),
+                        );
+                        if pa <= pb && pa <= pc {
+                            left
+                        } else if pb <= pc {
+                            up
+                        } else {
+                            up_left
+                        }
+                    }
+                    filter => panic!("Unexpected filter {filter}"),
+                };
+                row[i] = filtered[i + 1].wrapping_add(prediction);
+            }
+            previous.clone_from(&row);
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn test_canvas() -> Canvas {
+    let mut canvas = Canvas::new(5, 3);
+    for (y, green) in [0.0, 0.5, 1.0].into_iter().enumerate() {
+        for (x, red) in [0.0, 0.25, 0.5, 0.75, 1.0].into_iter().enumerate() {
+            canvas.write_pixel(x, y, Color::new(red, green, 0.2));
+        }
+    }
+    canvas.write_pixel(4, 2, WHITE);
+    canvas
+}
+
+#[test]
+fn computing_checksums() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+    assert_eq!(adler32(b""), 1);
+    assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    assert_eq!(adler32(&vec![0xFF; 100_000]), 0x149A_302C);
+}
+
+#[test]
+fn compressing_with_stored_blocks() {
+    let data = b"hello, hello, hello";
+    let zlib = compress(data, Compression::Stored);
+    assert_eq!(zlib[..2], [0x78, 0x01]);
+    // The final flag and block type, the length and its complement, then the data.
+    assert_eq!(zlib[2..7], [0x01, 19, 0, !19, 0xFF]);
+    assert_eq!(inflate(&zlib), data);
+    assert_eq!(inflate(&compress(b"", Compression::Stored)), b"");
+}
+
+#[test]
+fn data_larger_than_a_stored_block_is_split() {
+    let data: Vec<u8> = (0..150_000_u32).map(|i| (i % 251) as u8).collect();
+    let zlib = compress(&data, Compression::Stored);
+    assert_eq!(zlib.len(), 2 + 3 * 5 + data.len() + 4);
+    assert_eq!(inflate(&zlib), data);
+}
+
+#[test]
+fn compressing_with_deflate() {
+    let data = b"hello, hello, hello".repeat(100);
+    let zlib = compress(&data, Compression::Deflate);
+    assert!(zlib.len() < data.len() / 10);
+    assert_eq!(inflate(&zlib), data);
+    assert_eq!(inflate(&compress(b"", Compression::Deflate)), b"");
+    assert_eq!(inflate(&compress(b"ab", Compression::Deflate)), b"ab");
+}
+
+#[test]
+fn deflate_handles_long_and_distant_matches() {
+    let noise: Vec<u8> = (0..40_000_u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+        .collect();
+    let mut data = noise.clone();
+    data.extend_from_slice(&[7; 1000]);
+    data.extend_from_slice(&noise[..20_000]);
+    assert_eq!(inflate(&compress(&data, Compression::Deflate)), data);
+}
+
+#[test]
+fn the_default_png_encoder() {
+    let encoder = PngEncoder::default();
+    assert_eq!(encoder.color_type(), PngColorType::Rgb);
+    assert_eq!(encoder.bit_depth(), PngBitDepth::Eight);
+    assert_eq!(encoder.compression(), Compression::Deflate);
+    assert_eq!(encoder.extension(), "png");
+}
+
+#[test]
+fn a_png_is_made_of_a_header_a_gamma_data_and_an_end_chunk() {
+    let mut png = Vec::new();
+    PngEncoder::default()
+        .encode(&test_canvas(), &mut png)
+        .unwrap();
+    let chunks = chunks(&png);
+    assert_eq!(&chunks[0].0, b"IHDR");
+    assert_eq!(chunks[0].1, [0, 0, 0, 5, 0, 0, 0, 3, 8, 2, 0, 0, 0]);
+    assert_eq!(chunks[1], (*b"gAMA", 100_000_u32.to_be_bytes().to_vec()));
+    assert!(chunks[2..chunks.len() - 1]
+        .iter()
+        .all(|(kind, _)| kind == b"IDAT"));
+    assert_eq!(chunks.last().unwrap(), &(*b"IEND", Vec::new()));
+}
+
+#[test]
+fn srgb_pngs_are_marked_as_such() {
+    let mut png = Vec::new();
+    PngEncoder::default()
+        .with_pipeline(ExportPipeline::linear().with_transfer(TransferFunction::Srgb))
+        .encode(&test_canvas(), &mut png)
+        .unwrap();
+    assert_eq!(chunks(&png)[1], (*b"sRGB", vec![0]));
+    let rows = decode(&png, 3, 5);
+    // 0.2 and 0.5 are encoded as 0.485 and 0.735.
+    assert_eq!(rows[0][..6], [0, 0, 124, 137, 0, 124]);
+    assert_eq!(rows[1][..3], [0, 188, 124]);
+}
+
+#[test]
+fn encoding_an_rgb8_png() {
+    let canvas = test_canvas();
+    for compression in [Compression::Stored, Compression::Deflate] {
+        let mut png = Vec::new();
+        PngEncoder::default()
+            .with_compression(compression)
+            .encode(&canvas, &mut png)
+            .unwrap();
+        let rows = decode(&png, 3, 5);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0][..6], [0, 0, 51, 64, 0, 51]);
+        assert_eq!(rows[1][..3], [0, 128, 51]);
+        assert_eq!(rows[2][12..], [255, 255, 255]);
+    }
+}
+
+#[test]
+fn encoding_an_rgba8_png() {
+    let mut png = Vec::new();
+    PngEncoder::default()
+        .with_color_type(PngColorType::Rgba)
+        .encode(&test_canvas(), &mut png)
+        .unwrap();
+    assert_eq!(chunks(&png)[0].1[8..10], [8, 6]);
+    let rows = decode(&png, 4, 5);
+    assert_eq!(rows[0][..8], [0, 0, 51, 255, 64, 0, 51, 255]);
+    assert_eq!(rows[2][16..], [255, 255, 255, 255]);
+}
+
+#[test]
+fn encoding_a_16_bit_png() {
+    let mut png = Vec::new();
+    PngEncoder::new(
+        PngColorType::Rgba,
+        PngBitDepth::Sixteen,
+        Compression::Deflate,
+    )
+    .encode(&test_canvas(), &mut png)
+    .unwrap();
+    assert_eq!(chunks(&png)[0].1[8..10], [16, 6]);
+    let rows = decode(&png, 8, 5);
+    assert_eq!(
+        rows[0][8..16],
+        [0x40, 0x00, 0x00, 0x00, 0x33, 0x33, 0xFF, 0xFF]
+    );
+    assert_eq!(rows[2][32..], [0xFF; 8]);
+}
+
+#[test]
+fn an_empty_canvas_cannot_be_encoded_as_a_png() {
+    let mut png = Vec::new();
+    assert!(PngEncoder::default()
+        .encode(&Canvas::new(0, 3), &mut png)
+        .is_err());
+}
+
+#[test]
+fn encoders_can_be_chosen_at_runtime() {
+    let canvas = Canvas::new(2, 2);
+    let encoders: [&dyn ImageEncoder; 3] = [
+        &PngEncoder::default(),
+        &PpmEncoder::plain(),
+        &PpmEncoder::binary(PpmDepth::Eight),
+    ];
+    let outputs: Vec<(&str, Vec<u8>)> = encoders
+        .iter()
+        .map(|encoder| {
+            let mut data = Vec::new();
+            encoder.encode(&canvas, &mut data).unwrap();
+            (encoder.extension(), data)
+        })
+        .collect();
+    assert_eq!(outputs[0].0, "png");
+    assert_eq!(outputs[0].1[..8], PNG_SIGNATURE);
+    assert_eq!(outputs[1].0, "ppm");
+    assert_eq!(outputs[1].1, b"P3\n2 2\n255\n0 0 0 0 0 0\n0 0 0 0 0 0\n");
+    assert_eq!(outputs[2].1, b"P6\n2 2\n255\n\0\0\0\0\0\0\0\0\0\0\0\0");
+    assert_eq!(decode(&outputs[0].1, 3, 2), vec![vec![0; 6]; 2]);
+    assert_eq!(
+        *Canvas::from_ppm_bytes(&outputs[2].1)
+            .unwrap()
+            .pixel_at(1, 1),
+        BLACK
+    );
+}
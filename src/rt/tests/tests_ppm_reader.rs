@@ -0,0 +1,319 @@
+#[cfg(test)]
+use {
+    crate::rt::{
+        canvas::Canvas,
+        color::{Color, BLACK, WHITE},
+        ppm::PpmError,
+        ppm_reader::PpmReader,
+        ppm_writer::PpmDepth,
+    },
+    std::{error::Error, io::Error as IoError},
+};
+
+#[cfg(test)]
+fn binary_ppm(header: &str, data: &[u8]) -> Vec<u8> {
+    let mut ppm = header.as_bytes().to_vec();
+    ppm.extend_from_slice(data);
+    ppm
+}
+
+#[cfg(test)]
+fn pfm(header: &str, values: &[f32], little_endian: bool) -> Vec<u8> {
+    let mut pfm = header.as_bytes().to_vec();
+    for value in values {
+        if little_endian {
+            pfm.extend_from_slice(&value.to_le_bytes());
+        } else {
+            pfm.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+    pfm
+}
+
+#[test]
+fn reading_an_image_with_an_unsupported_magic_number() {
+    for data in [&b"P5\n1 1\n255\n\0"[..], b"", b"P7"] {
+        assert!(matches!(
+            PpmReader::new(data).read(),
+            Err(PpmError::UnsupportedFormat(_))
+        ));
+    }
+}
+
+#[test]
+fn reading_a_file_with_the_wrong_magic_number() {
+    let ppm = "P32\n1 1\n255\n0 0 0\n";
+    assert!(matches!(
+        Canvas::from_ppm(ppm),
+        Err(PpmError::UnsupportedFormat(magic)) if magic == "P32"
+    ));
+}
+
+#[test]
+fn reading_a_ppm_returns_a_canvas_of_the_right_size() {
+    let ppm = "P3\n10 2\n255\n".to_string() + &"0 0 0\n".repeat(20);
+    let canvas = Canvas::from_ppm(&ppm).unwrap();
+    assert_eq!(canvas.width(), 10);
+    assert_eq!(canvas.height(), 2);
+}
+
+#[test]
+fn reading_pixel_data_from_a_ppm_file() {
+    let ppm = "P3\n4 3\n255\n\
+               255 127 0  0 127 255  127 255 0  255 255 255\n\
+               0 0 0  255 0 0  0 255 0  0 0 255\n\
+               255 255 0  0 255 255  255 0 255  127 127 127\n";
+    let canvas = Canvas::from_ppm(ppm).unwrap();
+    let cases = [
+        (0, 0, Color::new(1.0, 0.498, 0.0)),
+        (1, 0, Color::new(0.0, 0.498, 1.0)),
+        (2, 0, Color::new(0.498, 1.0, 0.0)),
+        (3, 0, Color::new(1.0, 1.0, 1.0)),
+        (0, 1, Color::new(0.0, 0.0, 0.0)),
+        (1, 1, Color::new(1.0, 0.0, 0.0)),
+        (2, 1, Color::new(0.0, 1.0, 0.0)),
+        (3, 1, Color::new(0.0, 0.0, 1.0)),
+        (0, 2, Color::new(1.0, 1.0, 0.0)),
+        (1, 2, Color::new(0.0, 1.0, 1.0)),
+        (2, 2, Color::new(1.0, 0.0, 1.0)),
+        (3, 2, Color::new(0.498, 0.498, 0.498)),
+    ];
+    for (x, y, expected) in cases {
+        let pixel = canvas.pixel_at(x, y);
+        assert!((pixel.red() - expected.red()).abs() < 0.001);
+        assert!((pixel.green() - expected.green()).abs() < 0.001);
+        assert!((pixel.blue() - expected.blue()).abs() < 0.001);
+    }
+}
+
+#[test]
+fn ppm_parsing_ignores_comment_lines() {
+    let ppm = "P3\n# this is a comment\n2 1\n# this, too\n255\n# another comment\n\
+               255 255 255\n# oh, no, comments in the pixel data!\n255 0 255\n";
+    let canvas = Canvas::from_ppm(ppm).unwrap();
+    assert_eq!(canvas.pixel_at(0, 0), &Color::new(1.0, 1.0, 1.0));
+    assert_eq!(canvas.pixel_at(1, 0), &Color::new(1.0, 0.0, 1.0));
+}
+
+#[test]
+fn ppm_parsing_allows_an_rgb_triple_to_span_lines() {
+    let ppm = "P3\n1 1\n255\n51\n153\n\n204\n";
+    let canvas = Canvas::from_ppm(ppm).unwrap();
+    assert_eq!(canvas.pixel_at(0, 0), &Color::new(0.2, 0.6, 0.8));
+}
+
+#[test]
+fn ppm_parsing_respects_the_scale_setting() {
+    let ppm = "P3\n2 2\n100\n100 100 100  50 50 50\n75 50 25  0 0 0\n";
+    let canvas = Canvas::from_ppm(ppm).unwrap();
+    assert_eq!(canvas.pixel_at(0, 1), &Color::new(0.75, 0.5, 0.25));
+}
+
+#[test]
+fn reading_a_ppm_with_missing_pixel_data() {
+    let ppm = "P3\n2 2\n255\n0 0 0  0 0 0\n";
+    assert!(matches!(
+        Canvas::from_ppm(ppm),
+        Err(PpmError::MissingPixelData {
+            expected: 12,
+            found: 6
+        })
+    ));
+}
+
+#[test]
+fn reading_a_ppm_with_invalid_values() {
+    assert!(matches!(
+        Canvas::from_ppm("P3\n2 x\n255\n"),
+        Err(PpmError::InvalidHeader(value)) if value == "x"
+    ));
+    assert!(matches!(
+        Canvas::from_ppm("P3\n1 1\n255\n0 256 0\n"),
+        Err(PpmError::InvalidColorValue(value)) if value == "256"
+    ));
+}
+
+#[test]
+fn reading_a_plain_ppm_with_any_whitespace_layout() {
+    let ppm = b"P3\t2\r\n1   255\n\n255\t0 0\r\n  0\n0\n255";
+    let canvas = PpmReader::new(ppm).read().unwrap();
+    assert_eq!(canvas.pixel_at(0, 0), &Color::new(1.0, 0.0, 0.0));
+    assert_eq!(canvas.pixel_at(1, 0), &Color::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn comments_can_follow_a_token_directly() {
+    let ppm = b"P3#comment\n1 1#another one\n255\n255 0 0#last one";
+    let canvas = PpmReader::new(ppm).read().unwrap();
+    assert_eq!(canvas.pixel_at(0, 0), &Color::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn reading_an_8_bit_binary_ppm() {
+    let ppm = binary_ppm("P6\n# a comment\n2 1\n255\n", &[255, 0, 51, 0, 255, 204]);
+    let canvas = PpmReader::new(&ppm).read().unwrap();
+    assert_eq!(canvas.width(), 2);
+    assert_eq!(canvas.height(), 1);
+    assert_eq!(canvas.pixel_at(0, 0), &Color::new(1.0, 0.0, 0.2));
+    assert_eq!(canvas.pixel_at(1, 0), &Color::new(0.0, 1.0, 0.8));
+}
+
+#[test]
+fn binary_ppm_data_starting_with_whitespace_bytes() {
+    // 10 and 32 are the codes of '\n' and ' ': only the first whitespace after the header is skipped.
+    let ppm = binary_ppm("P6 1 1 10\n", &[10, 5, 0]);
+    let canvas = PpmReader::new(&ppm).read().unwrap();
+    assert_eq!(canvas.pixel_at(0, 0), &Color::new(1.0, 0.5, 0.0));
+}
+
+#[test]
+fn reading_a_16_bit_binary_ppm() {
+    let ppm = binary_ppm("P6\n1 1\n1000\n", &[0x03, 0xE8, 0x01, 0xF4, 0x00, 0x00]);
+    let canvas = PpmReader::new(&ppm).read().unwrap();
+    assert_eq!(canvas.pixel_at(0, 0), &Color::new(1.0, 0.5, 0.0));
+}
+
+#[test]
+fn binary_ppm_values_cannot_exceed_the_maximum_color_value() {
+    let ppm = binary_ppm("P6\n1 1\n100\n", &[101, 0, 0]);
+    assert!(matches!(
+        PpmReader::new(&ppm).read(),
+        Err(PpmError::InvalidColorValue(value)) if value == "101"
+    ));
+}
+
+#[test]
+fn reading_a_binary_ppm_with_missing_pixel_data() {
+    let ppm = binary_ppm("P6\n2 1\n65535\n", &[0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert!(matches!(
+        PpmReader::new(&ppm).read(),
+        Err(PpmError::MissingPixelData {
+            expected: 6,
+            found: 4
+        })
+    ));
+}
+
+#[test]
+fn the_maximum_color_value_must_fit_in_16_bits() {
+    for max in ["0", "65536", "-1", "abc"] {
+        let ppm = format!("P3\n1 1\n{max}\n0 0 0\n");
+        assert!(matches!(
+            PpmReader::new(ppm.as_bytes()).read(),
+            Err(PpmError::InvalidHeader(value)) if value == max
+        ));
+    }
+}
+
+#[test]
+fn binary_ppms_read_back_what_was_written() {
+    let mut canvas = Canvas::new(3, 2);
+    canvas.write_pixel(0, 0, WHITE);
+    canvas.write_pixel(1, 0, Color::new(0.2, 0.4, 0.6));
+    canvas.write_pixel(2, 1, Color::new(1.0, 0.0, 0.8));
+    for depth in [PpmDepth::Eight, PpmDepth::Sixteen] {
+        let mut data = Vec::new();
+        canvas.write_ppm_binary(&mut data, depth).unwrap();
+        assert_eq!(Canvas::from_ppm_bytes(&data).unwrap(), canvas);
+    }
+}
+
+#[test]
+fn reading_a_color_pfm() {
+    // Rows go from the bottom to the top of the image.
+    let values = [0.0, 0.0, 1.0, 2.5, 0.5, 0.25, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    for (scale, little_endian) in [("-1.0", true), ("1.0", false)] {
+        let data = pfm(&format!("PF\n2 2\n{scale}\n"), &values, little_endian);
+        let canvas = PpmReader::new(&data).read().unwrap();
+        assert_eq!(canvas.pixel_at(0, 0), &Color::new(1.0, 0.0, 0.0));
+        assert_eq!(canvas.pixel_at(1, 0), &Color::new(0.0, 1.0, 0.0));
+        assert_eq!(canvas.pixel_at(0, 1), &Color::new(0.0, 0.0, 1.0));
+        assert_eq!(canvas.pixel_at(1, 1), &Color::new(2.5, 0.5, 0.25));
+    }
+}
+
+#[test]
+fn reading_a_grayscale_pfm() {
+    let data = pfm("Pf\n2 1\n-1\n", &[0.5, 3.0], true);
+    let canvas = PpmReader::new(&data).read().unwrap();
+    assert_eq!(canvas.pixel_at(0, 0), &Color::new(0.5, 0.5, 0.5));
+    assert_eq!(canvas.pixel_at(1, 0), &Color::new(3.0, 3.0, 3.0));
+}
+
+#[test]
+fn reading_a_pfm_with_an_invalid_scale() {
+    for scale in ["0", "0.0", "x", "inf"] {
+        let data = pfm(&format!("PF\n1 1\n{scale}\n"), &[0.0, 0.0, 0.0], true);
+        assert!(matches!(
+            PpmReader::new(&data).read(),
+            Err(PpmError::InvalidHeader(value)) if value == scale
+        ));
+    }
+}
+
+#[test]
+fn reading_a_pfm_with_missing_pixel_data() {
+    let data = pfm("PF\n1 2\n-1\n", &[0.0, 0.0, 0.0, 0.0], true);
+    assert!(matches!(
+        PpmReader::new(&data).read(),
+        Err(PpmError::MissingPixelData {
+            expected: 6,
+            found: 4
+        })
+    ));
+}
+
+#[test]
+fn reading_an_empty_canvas_is_not_allowed() {
+    assert!(matches!(
+        PpmReader::new(b"P6\n0 1\n255\n").read(),
+        Err(PpmError::InvalidHeader(value)) if value == "0"
+    ));
+    for pfm in [&b"PF\n0 2\n-1.0\n"[..], b"Pf\n2 0\n-1.0\n"] {
+        assert!(matches!(
+            PpmReader::new(pfm).read(),
+            Err(PpmError::InvalidHeader(value)) if value == "0"
+        ));
+    }
+    assert_eq!(
+        PpmReader::new(b"P3 1 1 1 0 0 0")
+            .read()
+            .unwrap()
+            .pixel_at(0, 0),
+        &BLACK
+    );
+}
+
+#[test]
+fn image_sizes_that_overflow_are_invalid_headers() {
+    let huge = usize::MAX / 2;
+    for header in [
+        format!("P3\n{huge} 2\n255\n"),
+        format!("P6\n{huge} 1\n255\n"),
+        format!("PF\n3 {huge}\n-1.0\n"),
+    ] {
+        assert!(matches!(
+            PpmReader::new(header.as_bytes()).read(),
+            Err(PpmError::InvalidHeader(_))
+        ));
+    }
+}
+
+#[test]
+fn a_large_header_without_data_is_missing_pixel_data() {
+    assert!(matches!(
+        PpmReader::new(b"P3\n100000 100000\n255\n0 0 0\n").read(),
+        Err(PpmError::MissingPixelData {
+            expected: 30_000_000_000,
+            found: 3
+        })
+    ));
+}
+
+#[test]
+fn reading_a_missing_file_keeps_the_io_error_as_the_source() {
+    let error = Canvas::from_ppm_file("does/not/exist.ppm").unwrap_err();
+    assert!(matches!(error, PpmError::Io(_)));
+    assert!(error.source().unwrap().is::<IoError>());
+    assert!(PpmError::InvalidHeader("P3".to_string()).source().is_none());
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+use {
+    crate::rt::{
+        canvas::Canvas,
+        color::{Color, BLACK, WHITE},
+        ppm_writer::{PpmDepth, PpmWriter},
+    },
+    std::io::ErrorKind,
+};
+
+#[test]
+fn ppm_depths() {
+    assert_eq!(PpmDepth::default(), PpmDepth::Eight);
+    assert_eq!(PpmDepth::Eight.max_color_value(), 255);
+    assert_eq!(PpmDepth::Sixteen.max_color_value(), 65535);
+    assert_eq!(PpmDepth::Eight.quantize(0.5), 128);
+    assert_eq!(PpmDepth::Eight.quantize(-0.5), 0);
+    assert_eq!(PpmDepth::Eight.quantize(1.5), 255);
+    assert_eq!(PpmDepth::Sixteen.quantize(0.5), 32768);
+    assert_eq!(PpmDepth::Sixteen.quantize(1.0), 65535);
+}
+
+#[test]
+fn constructing_a_binary_ppm_header() {
+    let writer = PpmWriter::new(Vec::new(), 5, 3, PpmDepth::Eight).unwrap();
+    assert_eq!(writer.rows_written(), 0);
+    let writer = PpmWriter::new(Vec::new(), 5, 0, PpmDepth::Eight).unwrap();
+    assert_eq!(writer.finish().unwrap(), b"P6\n5 0\n255\n");
+    let writer = PpmWriter::new(Vec::new(), 5, 0, PpmDepth::Sixteen).unwrap();
+    assert_eq!(writer.finish().unwrap(), b"P6\n5 0\n65535\n");
+}
+
+#[test]
+fn streaming_rows_of_an_8_bit_ppm() {
+    let mut writer = PpmWriter::new(Vec::new(), 2, 2, PpmDepth::Eight).unwrap();
+    writer
+        .write_row(&[Color::new(1.5, 0.0, 0.0), Color::new(0.0, 0.5, 0.0)])
+        .unwrap();
+    writer
+        .write_row(&[Color::new(-0.5, 0.0, 1.0), WHITE])
+        .unwrap();
+    assert_eq!(writer.rows_written(), 2);
+    let data = writer.finish().unwrap();
+    let mut expected = b"P6\n2 2\n255\n".to_vec();
+    expected.extend_from_slice(&[255, 0, 0, 0, 128, 0, 0, 0, 255, 255, 255, 255]);
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn streaming_rows_of_a_16_bit_ppm() {
+    let mut writer = PpmWriter::new(Vec::new(), 1, 1, PpmDepth::Sixteen).unwrap();
+    writer.write_row(&[Color::new(1.0, 0.5, 0.0)]).unwrap();
+    let data = writer.finish().unwrap();
+    let mut expected = b"P6\n1 1\n65535\n".to_vec();
+    // Values are big-endian.
+    expected.extend_from_slice(&[0xFF, 0xFF, 0x80, 0x00, 0x00, 0x00]);
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn writing_a_row_of_the_wrong_length_fails() {
+    let mut writer = PpmWriter::new(Vec::new(), 2, 1, PpmDepth::Eight).unwrap();
+    let error = writer.write_row(&[BLACK]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    assert_eq!(writer.rows_written(), 0);
+}
+
+#[test]
+fn writing_too_many_rows_fails() {
+    let mut writer = PpmWriter::new(Vec::new(), 1, 1, PpmDepth::Eight).unwrap();
+    writer.write_row(&[BLACK]).unwrap();
+    let error = writer.write_row(&[BLACK]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn finishing_an_incomplete_image_fails() {
+    let mut writer = PpmWriter::new(Vec::new(), 1, 2, PpmDepth::Eight).unwrap();
+    writer.write_row(&[BLACK]).unwrap();
+    let error = writer.finish().unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn writing_a_canvas_as_a_binary_ppm() {
+    let mut canvas = Canvas::new(3, 2);
+    canvas.write_pixel(0, 0, Color::new(1.0, 0.0, 0.0));
+    canvas.write_pixel(2, 1, Color::new(0.0, 0.2, 1.0));
+    let mut data = Vec::new();
+    canvas.write_ppm_binary(&mut data, PpmDepth::Eight).unwrap();
+
+    let header = b"P6\n3 2\n255\n";
+    assert_eq!(&data[..header.len()], header);
+    let pixels = &data[header.len()..];
+    assert_eq!(pixels.len(), 3 * 2 * 3);
+    assert_eq!(&pixels[..3], &[255, 0, 0]);
+    assert_eq!(&pixels[15..], &[0, 51, 255]);
+    assert!(pixels[3..15].iter().all(|value| *value == 0));
+}
+
+#[test]
+fn a_16_bit_binary_ppm_uses_two_bytes_per_value() {
+    let canvas = Canvas::new(4, 3);
+    let mut data = Vec::new();
+    canvas
+        .write_ppm_binary(&mut data, PpmDepth::Sixteen)
+        .unwrap();
+    assert_eq!(data.len(), b"P6\n4 3\n65535\n".len() + 4 * 3 * 3 * 2);
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+use crate::{
+    approx_eq::ApproxEq,
+    rt::{
+        intersection::Intersection,
+        intersections::Intersections,
+        object::Object,
+        ray::{Intersect, Ray},
+        shape::Shape,
+        smooth_triangle::SmoothTriangle,
+        triangle::Triangle,
+    },
+    tuple::{point::Point, vector::Vector},
+};
+
+#[cfg(test)]
+fn default_smooth_triangle() -> SmoothTriangle {
+    SmoothTriangle::new(
+        Point::new(0.0, 1.0, 0.0),
+        Point::new(-1.0, 0.0, 0.0),
+        Point::new(1.0, 0.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+        Vector::new(-1.0, 0.0, 0.0),
+        Vector::new(1.0, 0.0, 0.0),
+    )
+}
+
+#[test]
+fn constructing_a_smooth_triangle() {
+    let tri = default_smooth_triangle();
+    assert_eq!(tri.p1(), &Point::new(0.0, 1.0, 0.0));
+    assert_eq!(tri.p2(), &Point::new(-1.0, 0.0, 0.0));
+    assert_eq!(tri.p3(), &Point::new(1.0, 0.0, 0.0));
+    assert_eq!(tri.n1(), &Vector::new(0.0, 1.0, 0.0));
+    assert_eq!(tri.n2(), &Vector::new(-1.0, 0.0, 0.0));
+    assert_eq!(tri.n3(), &Vector::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn an_intersection_can_encapsulate_u_and_v() {
+    let s = Object::Triangle(Triangle::new(
+        Point::new(0.0, 1.0, 0.0),
+        Point::new(-1.0, 0.0, 0.0),
+        Point::new(1.0, 0.0, 0.0),
+    ));
+    let i = Intersection::with_uv(3.5, &s, 0.2, 0.4);
+    assert_eq!(i.u(), 0.2);
+    assert_eq!(i.v(), 0.4);
+}
+
+#[test]
+fn an_intersection_with_a_smooth_triangle_stores_u_and_v() {
+    let tri = Object::SmoothTriangle(default_smooth_triangle());
+    let r = Ray::new(Point::new(-0.2, 0.3, -2.0), Vector::new(0.0, 0.0, 1.0));
+    let xs = r.intersect(&tri).expect("No intersection found!");
+    assert!(xs[0].u().approx_eq(0.45));
+    assert!(xs[0].v().approx_eq(0.25));
+}
+
+#[test]
+fn a_smooth_triangle_uses_u_and_v_to_interpolate_the_normal() {
+    let tri = Object::SmoothTriangle(default_smooth_triangle());
+    let i = Intersection::with_uv(1.0, &tri, 0.45, 0.25);
+    let n = tri.normal_at(&Point::new(0.0, 0.0, 0.0), &i);
+    assert_eq!(n, Vector::new(-0.5547, 0.83205, 0.0));
+}
+
+#[test]
+fn preparing_the_normal_on_a_smooth_triangle() {
+    let tri = Object::SmoothTriangle(default_smooth_triangle());
+    let i = Intersection::with_uv(1.0, &tri, 0.45, 0.25);
+    let r = Ray::new(Point::new(-0.2, 0.3, -2.0), Vector::new(0.0, 0.0, 1.0));
+    let xs = Intersections::new(vec![i.clone()]);
+    let comps = i.prepare_computations(&r, Some(&xs));
+    assert_eq!(comps.normal_vector(), &Vector::new(-0.5547, 0.83205, 0.0));
+}
@@ -95,32 +95,39 @@ fn intersecting_a_scaled_sphere_with_a_ray() {
 #[test]
 fn the_normal_on_a_sphere_at_a_point_on_the_x_axis() {
     let s = Object::Sphere(Sphere::default());
-    let n = s.normal_at(&Point::new(1.0, 0.0, 0.0));
+    let i = Intersection::new(0.0, &s);
+    let n = s.normal_at(&Point::new(1.0, 0.0, 0.0), &i);
     assert_eq!(n, Vector::new(1.0, 0.0, 0.0));
 }
 
 #[test]
 fn the_normal_on_a_sphere_at_a_point_on_the_y_axis() {
     let s = Object::Sphere(Sphere::default());
-    let n = s.normal_at(&Point::new(0.0, 1.0, 0.0));
+    let i = Intersection::new(0.0, &s);
+    let n = s.normal_at(&Point::new(0.0, 1.0, 0.0), &i);
     assert_eq!(n, Vector::new(0.0, 1.0, 0.0));
 }
 
 #[test]
 fn the_normal_on_a_sphere_at_a_point_on_the_z_axis() {
     let s = Object::Sphere(Sphere::default());
-    let n = s.normal_at(&Point::new(0.0, 0.0, 1.0));
+    let i = Intersection::new(0.0, &s);
+    let n = s.normal_at(&Point::new(0.0, 0.0, 1.0), &i);
     assert_eq!(n, Vector::new(0.0, 0.0, 1.0));
 }
 
 #[test]
 fn the_normal_on_a_sphere_at_a_nonaxial_point() {
     let s = Object::Sphere(Sphere::default());
-    let n = s.normal_at(&Point::new(
-        3.0_f64.sqrt() / 3.0,
-        3.0_f64.sqrt() / 3.0,
-        3.0_f64.sqrt() / 3.0,
-    ));
+    let i = Intersection::new(0.0, &s);
+    let n = s.normal_at(
+        &Point::new(
+            3.0_f64.sqrt() / 3.0,
+            3.0_f64.sqrt() / 3.0,
+            3.0_f64.sqrt() / 3.0,
+        ),
+        &i,
+    );
     assert_eq!(
         n,
         Vector::new(
@@ -135,7 +142,8 @@ fn the_normal_on_a_sphere_at_a_nonaxial_point() {
 fn computing_the_normal_on_a_translated_sphere() {
     let t = Matrix::<4>::translation(0.0, 1.0, 0.0);
     let s = Object::Sphere(Sphere::with_transform(t));
-    let n = s.normal_at(&Point::new(0.0, 1.70711, -0.70711));
+    let i = Intersection::new(0.0, &s);
+    let n = s.normal_at(&Point::new(0.0, 1.70711, -0.70711), &i);
     assert_eq!(n, Vector::new(0.0, 0.70711, -0.70711));
 }
 
@@ -143,11 +151,11 @@ fn computing_the_normal_on_a_translated_sphere() {
 fn computing_the_normal_on_a_transformed_sphere() {
     let m = Matrix::<4>::scaling(1.0, 0.5, 1.0) * Matrix::<4>::rotation_z(PI / 5.0);
     let s = Object::Sphere(Sphere::with_transform(m));
-    let n = s.normal_at(&Point::new(
-        0.0,
-        2.0_f64.sqrt() / 2.0,
-        -2.0_f64.sqrt() / 2.0,
-    ));
+    let i = Intersection::new(0.0, &s);
+    let n = s.normal_at(
+        &Point::new(0.0, 2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0),
+        &i,
+    );
     assert_eq!(n, Vector::new(0.0, 0.97014, -0.24254));
 }
 
@@ -0,0 +1,88 @@
+#[cfg(test)]
+use crate::{
+    rt::{
+        intersection::Intersection,
+        object::Object,
+        ray::{Intersect, Ray},
+        shape::Shape,
+        triangle::Triangle,
+    },
+    tuple::{point::Point, vector::Vector},
+};
+
+#[cfg(test)]
+fn default_triangle() -> Triangle {
+    Triangle::new(
+        Point::new(0.0, 1.0, 0.0),
+        Point::new(-1.0, 0.0, 0.0),
+        Point::new(1.0, 0.0, 0.0),
+    )
+}
+
+#[test]
+fn constructing_a_triangle() {
+    let (p1, p2, p3) = (
+        Point::new(0.0, 1.0, 0.0),
+        Point::new(-1.0, 0.0, 0.0),
+        Point::new(1.0, 0.0, 0.0),
+    );
+    let t = Triangle::new(p1.clone(), p2.clone(), p3.clone());
+    assert_eq!(t.p1(), &p1);
+    assert_eq!(t.p2(), &p2);
+    assert_eq!(t.p3(), &p3);
+    assert_eq!(t.e1(), &Vector::new(-1.0, -1.0, 0.0));
+    assert_eq!(t.e2(), &Vector::new(1.0, -1.0, 0.0));
+    assert_eq!(t.normal(), &Vector::new(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn finding_the_normal_on_a_triangle() {
+    let t = Object::Triangle(default_triangle());
+    let i = Intersection::new(0.0, &t);
+    let (n1, n2, n3) = (
+        t.normal_at(&Point::new(0.0, 0.5, 0.0), &i),
+        t.normal_at(&Point::new(-0.5, 0.75, 0.0), &i),
+        t.normal_at(&Point::new(0.5, 0.25, 0.0), &i),
+    );
+    let expected = Vector::new(0.0, 0.0, -1.0);
+    assert_eq!(n1, expected);
+    assert_eq!(n2, expected);
+    assert_eq!(n3, expected);
+}
+
+#[test]
+fn intersecting_a_ray_parallel_to_the_triangle() {
+    let t = Object::Triangle(default_triangle());
+    let r = Ray::new(Point::new(0.0, -1.0, -2.0), Vector::new(0.0, 1.0, 0.0));
+    assert!(r.intersect(&t).is_none());
+}
+
+#[test]
+fn a_ray_misses_the_p1_p3_edge() {
+    let t = Object::Triangle(default_triangle());
+    let r = Ray::new(Point::new(1.0, 1.0, -2.0), Vector::new(0.0, 0.0, 1.0));
+    assert!(r.intersect(&t).is_none());
+}
+
+#[test]
+fn a_ray_misses_the_p1_p2_edge() {
+    let t = Object::Triangle(default_triangle());
+    let r = Ray::new(Point::new(-1.0, 1.0, -2.0), Vector::new(0.0, 0.0, 1.0));
+    assert!(r.intersect(&t).is_none());
+}
+
+#[test]
+fn a_ray_misses_the_p2_p3_edge() {
+    let t = Object::Triangle(default_triangle());
+    let r = Ray::new(Point::new(0.0, -1.0, -2.0), Vector::new(0.0, 0.0, 1.0));
+    assert!(r.intersect(&t).is_none());
+}
+
+#[test]
+fn a_ray_strikes_a_triangle() {
+    let t = Object::Triangle(default_triangle());
+    let r = Ray::new(Point::new(0.0, 0.5, -2.0), Vector::new(0.0, 0.0, 1.0));
+    let xs = r.intersect(&t).expect("No intersection found!");
+    assert_eq!(xs.len(), 1);
+    assert_eq!(xs[0].t(), 2.0);
+}
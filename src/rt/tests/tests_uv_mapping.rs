@@ -0,0 +1,107 @@
+#[cfg(test)]
+use {
+    crate::{
+        approx_eq::ApproxEq,
+        rt::uv_mapping::{CubeFace, UvMapping},
+        tuple::point::Point,
+    },
+    std::f64::consts::FRAC_1_SQRT_2,
+};
+
+#[cfg(test)]
+fn assert_maps_to(mapped: (f64, f64), expected: (f64, f64)) {
+    assert!(
+        mapped.0.approx_eq(expected.0) && mapped.1.approx_eq(expected.1),
+        "{mapped:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn using_a_spherical_mapping_on_a_3d_point() {
+    let cases = [
+        (Point::new(0.0, 0.0, -1.0), (0.0, 0.5)),
+        (Point::new(1.0, 0.0, 0.0), (0.25, 0.5)),
+        (Point::new(0.0, 0.0, 1.0), (0.5, 0.5)),
+        (Point::new(-1.0, 0.0, 0.0), (0.75, 0.5)),
+        (Point::new(0.0, 1.0, 0.0), (0.5, 1.0)),
+        (Point::new(0.0, -1.0, 0.0), (0.5, 0.0)),
+        (Point::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.0), (0.25, 0.75)),
+    ];
+    for (point, expected) in cases {
+        assert_maps_to(UvMapping::Spherical.map(&point), expected);
+    }
+}
+
+#[test]
+fn using_a_planar_mapping_on_a_3d_point() {
+    let cases = [
+        (Point::new(0.25, 0.0, 0.5), (0.25, 0.5)),
+        (Point::new(0.25, 0.0, -0.25), (0.25, 0.75)),
+        (Point::new(0.25, 0.5, -0.25), (0.25, 0.75)),
+        (Point::new(1.25, 0.0, 0.5), (0.25, 0.5)),
+        (Point::new(0.25, 0.0, -1.75), (0.25, 0.25)),
+        (Point::new(1.0, 0.0, -1.0), (0.0, 0.0)),
+        (Point::new(0.0, 0.0, 0.0), (0.0, 0.0)),
+    ];
+    for (point, expected) in cases {
+        assert_maps_to(UvMapping::Planar.map(&point), expected);
+    }
+}
+
+#[test]
+fn using_a_cylindrical_mapping_on_a_3d_point() {
+    let cases = [
+        (Point::new(0.0, 0.0, -1.0), (0.0, 0.0)),
+        (Point::new(0.0, 0.5, -1.0), (0.0, 0.5)),
+        (Point::new(0.0, 1.0, -1.0), (0.0, 0.0)),
+        (Point::new(FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2), (0.125, 0.5)),
+        (Point::new(1.0, 0.5, 0.0), (0.25, 0.5)),
+        (Point::new(FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), (0.375, 0.5)),
+        (Point::new(0.0, -0.25, 1.0), (0.5, 0.75)),
+        (Point::new(-FRAC_1_SQRT_2, 0.5, FRAC_1_SQRT_2), (0.625, 0.5)),
+        (Point::new(-1.0, 1.25, 0.0), (0.75, 0.25)),
+        (
+            Point::new(-FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2),
+            (0.875, 0.5),
+        ),
+    ];
+    for (point, expected) in cases {
+        assert_maps_to(UvMapping::Cylindrical.map(&point), expected);
+    }
+}
+
+#[test]
+fn identifying_the_face_of_a_cube_from_a_point() {
+    let cases = [
+        (Point::new(-1.0, 0.5, -0.25), CubeFace::Left),
+        (Point::new(1.1, -0.75, 0.8), CubeFace::Right),
+        (Point::new(0.1, 0.6, 0.9), CubeFace::Front),
+        (Point::new(-0.7, 0.0, -2.0), CubeFace::Back),
+        (Point::new(0.5, 1.0, 0.9), CubeFace::Up),
+        (Point::new(-0.2, -1.3, 1.1), CubeFace::Down),
+    ];
+    for (point, face) in cases {
+        assert_eq!(CubeFace::of(&point), face);
+    }
+}
+
+#[test]
+fn uv_mapping_the_faces_of_a_cube() {
+    let cases = [
+        (CubeFace::Front, Point::new(-0.5, 0.5, 1.0), (0.25, 0.75)),
+        (CubeFace::Front, Point::new(0.5, -0.5, 1.0), (0.75, 0.25)),
+        (CubeFace::Back, Point::new(0.5, 0.5, -1.0), (0.25, 0.75)),
+        (CubeFace::Back, Point::new(-0.5, -0.5, -1.0), (0.75, 0.25)),
+        (CubeFace::Left, Point::new(-1.0, 0.5, -0.5), (0.25, 0.75)),
+        (CubeFace::Left, Point::new(-1.0, -0.5, 0.5), (0.75, 0.25)),
+        (CubeFace::Right, Point::new(1.0, 0.5, 0.5), (0.25, 0.75)),
+        (CubeFace::Right, Point::new(1.0, -0.5, -0.5), (0.75, 0.25)),
+        (CubeFace::Up, Point::new(-0.5, 1.0, -0.5), (0.25, 0.75)),
+        (CubeFace::Up, Point::new(0.5, 1.0, 0.5), (0.75, 0.25)),
+        (CubeFace::Down, Point::new(-0.5, -1.0, 0.5), (0.25, 0.75)),
+        (CubeFace::Down, Point::new(0.5, -1.0, -0.5), (0.75, 0.25)),
+    ];
+    for (face, point, expected) in cases {
+        assert_maps_to(face.uv(&point), expected);
+    }
+}
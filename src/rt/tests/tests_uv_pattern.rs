@@ -0,0 +1,219 @@
+#[cfg(test)]
+use crate::{
+    rt::{
+        canvas::Canvas,
+        color::{Color, BLACK, WHITE},
+        pattern::Pattern,
+        uv_mapping::UvMapping,
+        uv_pattern::{TextureFilter, UvPattern},
+    },
+    tuple::point::Point,
+};
+
+#[cfg(test)]
+const RED: Color = Color::new(1.0, 0.0, 0.0);
+#[cfg(test)]
+const YELLOW: Color = Color::new(1.0, 1.0, 0.0);
+#[cfg(test)]
+const BROWN: Color = Color::new(1.0, 0.5, 0.0);
+#[cfg(test)]
+const GREEN: Color = Color::new(0.0, 1.0, 0.0);
+#[cfg(test)]
+const CYAN: Color = Color::new(0.0, 1.0, 1.0);
+#[cfg(test)]
+const BLUE: Color = Color::new(0.0, 0.0, 1.0);
+#[cfg(test)]
+const PURPLE: Color = Color::new(1.0, 0.0, 1.0);
+
+#[cfg(test)]
+fn align_check(main: Color, ul: Color, ur: Color, bl: Color, br: Color) -> UvPattern {
+    UvPattern::AlignCheck {
+        main,
+        upper_left: ul,
+        upper_right: ur,
+        bottom_left: bl,
+        bottom_right: br,
+    }
+}
+
+/// A 2x2 canvas: black and red on the top row, green and white on the bottom row.
+#[cfg(test)]
+fn two_by_two_canvas() -> Canvas {
+    let mut canvas = Canvas::new(2, 2);
+    canvas.write_pixel(0, 0, BLACK);
+    canvas.write_pixel(1, 0, RED);
+    canvas.write_pixel(0, 1, GREEN);
+    canvas.write_pixel(1, 1, WHITE);
+    canvas
+}
+
+#[test]
+fn checkers_pattern_in_2d() {
+    let checkers = UvPattern::checkers(2.0, 2.0, BLACK, WHITE);
+    let cases = [
+        (0.0, 0.0, BLACK),
+        (0.5, 0.0, WHITE),
+        (0.0, 0.5, WHITE),
+        (0.5, 0.5, BLACK),
+        (1.0, 1.0, BLACK),
+    ];
+    for (u, v, expected) in cases {
+        assert_eq!(checkers.at(u, v), expected);
+    }
+}
+
+#[test]
+fn using_a_texture_map_pattern_with_a_spherical_map() {
+    let checkers = UvPattern::checkers(16.0, 8.0, BLACK, WHITE);
+    let pattern = Pattern::texture_map(checkers, UvMapping::Spherical, None);
+    let cases = [
+        (Point::new(0.4315, 0.4670, 0.7719), WHITE),
+        (Point::new(-0.9654, 0.2552, -0.0534), BLACK),
+        (Point::new(0.1039, 0.7090, 0.6975), WHITE),
+        (Point::new(-0.4986, -0.7856, -0.3663), BLACK),
+        (Point::new(-0.0317, -0.9395, 0.3411), BLACK),
+        (Point::new(0.4809, -0.7721, 0.4154), BLACK),
+        (Point::new(0.0285, -0.9612, -0.2745), BLACK),
+        (Point::new(-0.5734, -0.2162, -0.7903), WHITE),
+        (Point::new(0.7688, -0.1470, 0.6223), BLACK),
+        (Point::new(-0.7652, 0.2175, 0.6060), BLACK),
+    ];
+    for (point, expected) in cases {
+        assert_eq!(pattern.at(&point), expected);
+    }
+}
+
+#[test]
+fn layout_of_the_align_check_pattern() {
+    let pattern = align_check(WHITE, RED, YELLOW, GREEN, CYAN);
+    let cases = [
+        (0.5, 0.5, WHITE),
+        (0.1, 0.9, RED),
+        (0.9, 0.9, YELLOW),
+        (0.1, 0.1, GREEN),
+        (0.9, 0.1, CYAN),
+    ];
+    for (u, v, expected) in cases {
+        assert_eq!(pattern.at(u, v), expected);
+    }
+}
+
+#[test]
+fn finding_the_colors_on_a_mapped_cube() {
+    let faces = [
+        align_check(YELLOW, CYAN, RED, BLUE, BROWN),
+        align_check(CYAN, RED, YELLOW, BROWN, GREEN),
+        align_check(RED, YELLOW, PURPLE, GREEN, WHITE),
+        align_check(GREEN, PURPLE, CYAN, WHITE, BLUE),
+        align_check(BROWN, CYAN, PURPLE, RED, YELLOW),
+        align_check(PURPLE, BROWN, GREEN, BLUE, WHITE),
+    ];
+    let pattern = Pattern::cube_map(faces, None);
+    let cases = [
+        // Left
+        (Point::new(-1.0, 0.0, 0.0), YELLOW),
+        (Point::new(-1.0, 0.9, -0.9), CYAN),
+        (Point::new(-1.0, 0.9, 0.9), RED),
+        (Point::new(-1.0, -0.9, -0.9), BLUE),
+        (Point::new(-1.0, -0.9, 0.9), BROWN),
+        // Front
+        (Point::new(0.0, 0.0, 1.0), CYAN),
+        (Point::new(-0.9, 0.9, 1.0), RED),
+        (Point::new(0.9, 0.9, 1.0), YELLOW),
+        (Point::new(-0.9, -0.9, 1.0), BROWN),
+        (Point::new(0.9, -0.9, 1.0), GREEN),
+        // Right
+        (Point::new(1.0, 0.0, 0.0), RED),
+        (Point::new(1.0, 0.9, 0.9), YELLOW),
+        (Point::new(1.0, 0.9, -0.9), PURPLE),
+        (Point::new(1.0, -0.9, 0.9), GREEN),
+        (Point::new(1.0, -0.9, -0.9), WHITE),
+        // Back
+        (Point::new(0.0, 0.0, -1.0), GREEN),
+        (Point::new(0.9, 0.9, -1.0), PURPLE),
+        (Point::new(-0.9, 0.9, -1.0), CYAN),
+        (Point::new(0.9, -0.9, -1.0), WHITE),
+        (Point::new(-0.9, -0.9, -1.0), BLUE),
+        // Up
+        (Point::new(0.0, 1.0, 0.0), BROWN),
+        (Point::new(-0.9, 1.0, -0.9), CYAN),
+        (Point::new(0.9, 1.0, -0.9), PURPLE),
+        (Point::new(-0.9, 1.0, 0.9), RED),
+        (Point::new(0.9, 1.0, 0.9), YELLOW),
+        // Down
+        (Point::new(0.0, -1.0, 0.0), PURPLE),
+        (Point::new(-0.9, -1.0, 0.9), BROWN),
+        (Point::new(0.9, -1.0, 0.9), GREEN),
+        (Point::new(-0.9, -1.0, -0.9), BLUE),
+        (Point::new(0.9, -1.0, -0.9), WHITE),
+    ];
+    for (point, expected) in cases {
+        assert_eq!(pattern.at(&point), expected);
+    }
+}
+
+#[test]
+fn an_image_texture_with_nearest_filtering() {
+    let image = UvPattern::image(two_by_two_canvas(), TextureFilter::Nearest);
+    let cases = [
+        (0.0, 1.0, BLACK),
+        (1.0, 1.0, RED),
+        (0.0, 0.0, GREEN),
+        (1.0, 0.0, WHITE),
+        (0.4, 0.6, BLACK),
+        (0.6, 0.6, RED),
+        (0.6, 0.4, WHITE),
+    ];
+    for (u, v, expected) in cases {
+        assert_eq!(image.at(u, v), expected);
+    }
+}
+
+#[test]
+fn an_empty_image_texture_is_black() {
+    for filter in [TextureFilter::Nearest, TextureFilter::Bilinear] {
+        for canvas in [Canvas::new(0, 0), Canvas::new(3, 0), Canvas::new(0, 3)] {
+            assert_eq!(UvPattern::image(canvas, filter).at(0.5, 0.5), BLACK);
+        }
+    }
+}
+
+#[test]
+fn an_image_texture_with_bilinear_filtering() {
+    let image = UvPattern::image(two_by_two_canvas(), TextureFilter::Bilinear);
+    // Pixel centers keep their color.
+    assert_eq!(image.at(0.0, 1.0), BLACK);
+    assert_eq!(image.at(1.0, 0.0), WHITE);
+    // Halfway between black and red.
+    assert_eq!(image.at(0.5, 1.0), Color::new(0.5, 0.0, 0.0));
+    // The average of the four pixels.
+    assert_eq!(image.at(0.5, 0.5), Color::new(0.5, 0.5, 0.25));
+}
+
+/// A 10 x 10 image whose pixels are grays going from 0.0 to 0.9 along its diagonals, like the fixture of the
+/// bonus chapter on texture mapping.
+#[cfg(test)]
+fn diagonal_gradient_canvas() -> Canvas {
+    let mut canvas = Canvas::new(10, 10);
+    for y in 0..10 {
+        for x in 0..10 {
+            let value = f64::from(u8::try_from((x + y) % 10).unwrap()) / 10.0;
+            canvas.write_pixel(x, y, Color::new(value, value, value));
+        }
+    }
+    canvas
+}
+
+#[test]
+fn a_texture_sampled_from_a_larger_image() {
+    let image = UvPattern::image(diagonal_gradient_canvas(), TextureFilter::Nearest);
+    let cases = [
+        (0.0, 0.0, Color::new(0.9, 0.9, 0.9)),
+        (0.3, 0.0, Color::new(0.2, 0.2, 0.2)),
+        (0.6, 0.3, Color::new(0.1, 0.1, 0.1)),
+        (1.0, 1.0, Color::new(0.9, 0.9, 0.9)),
+    ];
+    for (u, v, expected) in cases {
+        assert_eq!(image.at(u, v), expected);
+    }
+}
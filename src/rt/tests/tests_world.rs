@@ -1,27 +1,36 @@
 #[cfg(test)]
-use crate::{
-    rt::{
-        color::{Color, BLACK, WHITE},
-        intersection::Intersection,
-        intersections::Intersections,
-        material::Material,
-        matrix::Matrix,
-        pattern::test_pattern,
-        plane::Plane,
-        point_light::PointLight,
-        ray::{Intersect, Ray},
-        shape::Shape,
-        sphere::Sphere,
-        world::{World, MAX_REFLECTION_DEPTH},
+use {
+    crate::{
+        rt::{
+            area_light::AreaLight,
+            color::{Color, BLACK, WHITE},
+            directional_light::DirectionalLight,
+            group::Group,
+            intersection::Intersection,
+            intersections::Intersections,
+            light::Light,
+            light_source::{LightSample, LightSource},
+            material::Material,
+            matrix::Matrix,
+            pattern::{test_pattern, Pattern},
+            plane::Plane,
+            point_light::PointLight,
+            ray::{Intersect, Ray},
+            shape::Shape,
+            sphere::Sphere,
+            spot_light::SpotLight,
+            world::{World, MAX_REFLECTION_DEPTH},
+        },
+        tuple::{point::Point, vector::Vector},
     },
-    tuple::{point::Point, vector::Vector},
+    std::f64::consts::PI,
 };
 
 #[test]
 fn creating_a_world() {
     let world = World::empty();
     assert_eq!(world.objects(), None);
-    assert_eq!(world.light(), None);
+    assert!(world.lights().is_empty());
 }
 
 #[test]
@@ -42,10 +51,10 @@ fn the_default_world() {
     let transform = Matrix::<4>::scaling(0.5, 0.5, 0.5);
     let s2 = Sphere::with_transform(transform);
 
-    let light = PointLight::new(Point::new(-10.0, 10.0, -10.0), WHITE);
+    let light = Light::Point(PointLight::new(Point::new(-10.0, 10.0, -10.0), WHITE));
     let world = World::default();
 
-    assert_eq!(world.light(), Some(&light));
+    assert_eq!(world.lights(), &[light]);
     assert!(world.objects().unwrap().contains(&s1.into()));
     assert!(world.objects().unwrap().contains(&s2.into()));
 }
@@ -79,7 +88,9 @@ fn shading_an_intersection() {
 
 #[test]
 fn shading_an_intersection_from_the_inside() {
-    let w = World::with_light(Some(PointLight::new(Point::new(0.0, 0.25, 0.0), WHITE)));
+    let w = World::with_lights(vec![
+        PointLight::new(Point::new(0.0, 0.25, 0.0), WHITE).into()
+    ]);
     let r = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
     let shape = &w.objects().unwrap()[1];
     let i = Intersection::new(0.5, &shape);
@@ -140,29 +151,348 @@ fn the_color_with_an_intersection_behind_the_ray() {
 #[test]
 fn there_is_no_shadow_when_nothing_is_collinear_with_point_and_light() {
     let w = World::default();
+    let light_position = Point::new(-10.0, 10.0, -10.0);
     let p = Point::new(0.0, 10.0, 0.0);
-    assert_eq!(w.is_shadowed(&p), false);
+    let sample = LightSample::towards(&p, &light_position, WHITE);
+    assert_eq!(w.light_transmission(&sample, &p), WHITE);
 }
 
 #[test]
 fn the_shadow_when_an_object_is_between_the_point_and_the_light() {
     let w = World::default();
+    let light_position = Point::new(-10.0, 10.0, -10.0);
     let p = Point::new(10.0, -10.0, 10.0);
-    assert_eq!(w.is_shadowed(&p), true);
+    let sample = LightSample::towards(&p, &light_position, WHITE);
+    assert_eq!(w.light_transmission(&sample, &p), BLACK);
 }
 
 #[test]
 fn there_is_no_shadow_when_an_object_is_behind_the_light() {
     let w = World::default();
+    let light_position = Point::new(-10.0, 10.0, -10.0);
     let p = Point::new(-20.0, 20.0, -20.0);
-    assert_eq!(w.is_shadowed(&p), false);
+    let sample = LightSample::towards(&p, &light_position, WHITE);
+    assert_eq!(w.light_transmission(&sample, &p), WHITE);
 }
 
 #[test]
 fn there_is_no_shadow_when_an_object_is_behind_the_point() {
     let w = World::default();
+    let light_position = Point::new(-10.0, 10.0, -10.0);
     let p = Point::new(-2.0, 2.0, -2.0);
-    assert_eq!(w.is_shadowed(&p), false);
+    let sample = LightSample::towards(&p, &light_position, WHITE);
+    assert_eq!(w.light_transmission(&sample, &p), WHITE);
+}
+
+#[test]
+fn point_lights_evaluate_the_light_intensity_at_a_given_point() {
+    let w = World::default();
+    let light = &w.lights()[0];
+    let cases = [
+        (Point::new(0.0, 1.0001, 0.0), 1.0),
+        (Point::new(-1.0001, 0.0, 0.0), 1.0),
+        (Point::new(0.0, 0.0, -1.0001), 1.0),
+        (Point::new(0.0, 0.0, 1.0001), 0.0),
+        (Point::new(1.0001, 0.0, 0.0), 0.0),
+        (Point::new(0.0, -1.0001, 0.0), 0.0),
+        (Point::new(0.0, 0.0, 0.0), 0.0),
+    ];
+    for (point, intensity) in cases {
+        assert_eq!(
+            w.light_intensity_at(light, &point),
+            Color::new(intensity, intensity, intensity)
+        );
+    }
+}
+
+#[test]
+fn area_lights_evaluate_a_fractional_light_intensity() {
+    let w = World::default();
+    let light = AreaLight::new(
+        Point::new(-0.5, -0.5, -5.0),
+        Vector::new(1.0, 0.0, 0.0),
+        2,
+        Vector::new(0.0, 1.0, 0.0),
+        2,
+        WHITE,
+    )
+    .into();
+    let cases = [
+        (Point::new(0.0, 0.0, 2.0), 0.0),
+        (Point::new(1.0, -1.0, 2.0), 0.25),
+        (Point::new(1.5, 0.0, 2.0), 0.5),
+        (Point::new(1.25, 1.25, 3.0), 0.75),
+        (Point::new(0.0, 0.0, -2.0), 1.0),
+    ];
+    for (point, intensity) in cases {
+        assert_eq!(
+            w.light_intensity_at(&light, &point),
+            Color::new(intensity, intensity, intensity)
+        );
+    }
+}
+
+#[test]
+fn shade_hit_with_an_area_light_produces_a_penumbra() {
+    let mut w = World::default();
+    w.set_lights(vec![AreaLight::new(
+        Point::new(-0.5, -0.5, -5.0),
+        Vector::new(1.0, 0.0, 0.0),
+        2,
+        Vector::new(0.0, 1.0, 0.0),
+        2,
+        WHITE,
+    )
+    .with_jitter(7)
+    .into()]);
+    let light = &w.lights()[0];
+    // Partially hidden by the outer sphere of the default world.
+    let intensity = w.light_intensity_at(light, &Point::new(1.5, 0.0, 2.0));
+    assert!(intensity.red() > 0.0 && intensity.red() < 1.0);
+}
+
+#[test]
+fn the_illumination_of_a_point_keeps_the_samples_of_the_light() {
+    let w = World::default();
+    let light = &w.lights()[0];
+    let object = &w.objects().unwrap()[0];
+    let point = Point::new(-1.0, 0.0, 0.0);
+    let (eye, normal) = (Vector::new(-1.0, 0.0, 0.0), Vector::new(-1.0, 0.0, 0.0));
+    let illumination = w.illumination(light, &point);
+    assert_eq!(illumination.samples(), light.samples(&point));
+    assert_eq!(
+        illumination.transmission(),
+        &w.light_intensity_at(light, &point)
+    );
+    assert_eq!(
+        object.material().lighting_with(
+            &object.material().color_at(object, &point),
+            light,
+            &eye,
+            &normal,
+            &illumination
+        ),
+        object.material().lighting(
+            object,
+            light,
+            &point,
+            &eye,
+            &normal,
+            *illumination.transmission()
+        )
+    );
+}
+
+#[test]
+fn adding_and_removing_lights() {
+    let mut w = World::default();
+    let fill = PointLight::new(Point::new(10.0, 10.0, -10.0), Color::new(0.5, 0.5, 0.5));
+    w.add_light(fill);
+    assert_eq!(w.lights().len(), 2);
+    let removed = w.remove_light(0).unwrap();
+    assert_eq!(
+        removed,
+        PointLight::new(Point::new(-10.0, 10.0, -10.0), WHITE).into()
+    );
+    assert_eq!(
+        w.lights(),
+        &[PointLight::new(Point::new(10.0, 10.0, -10.0), Color::new(0.5, 0.5, 0.5)).into()]
+    );
+    assert_eq!(w.remove_light(1), None);
+}
+
+#[test]
+fn shading_without_lights_only_keeps_reflections_and_refractions() {
+    let w = World::with_lights(Vec::new());
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let shape = &w.objects().unwrap()[0];
+    let i = Intersection::new(4.0, shape);
+    let comps = i.prepare_computations(&r, None);
+    assert_eq!(w.shade_hit(&comps, MAX_REFLECTION_DEPTH), BLACK);
+}
+
+#[test]
+fn shade_hit_sums_the_contribution_of_every_light() {
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let key = PointLight::new(Point::new(-10.0, 10.0, -10.0), WHITE);
+    let rim = || PointLight::new(Point::new(10.0, 10.0, 10.0), Color::new(0.5, 0.5, 0.5));
+
+    let single = World::default();
+    let mut both = World::default();
+    both.add_light(rim());
+    let only_rim = World::with_lights(vec![rim().into()]);
+
+    let color = |w: &World| {
+        let i = Intersection::new(4.0, &w.objects().unwrap()[0]);
+        w.shade_hit(&i.prepare_computations(&r, None), MAX_REFLECTION_DEPTH)
+    };
+    assert_eq!(single.lights(), &[key.into()]);
+    assert_eq!(color(&both), color(&single) + color(&only_rim));
+}
+
+#[test]
+fn a_directional_light_casts_parallel_shadows() {
+    let w = World::with_lights(vec![DirectionalLight::new(
+        &Vector::new(0.0, -1.0, 0.0),
+        WHITE,
+    )
+    .into()]);
+    let light = &w.lights()[0];
+    // Anything below the spheres is in their shadow, however far it is.
+    assert_eq!(
+        w.light_intensity_at(light, &Point::new(0.0, -1.5, 0.0)),
+        BLACK
+    );
+    assert_eq!(
+        w.light_intensity_at(light, &Point::new(0.9, -100.0, 0.0)),
+        BLACK
+    );
+    // The light comes from straight above, even far away from the spheres.
+    assert_eq!(
+        w.light_intensity_at(light, &Point::new(1.1, -1.5, 0.0)),
+        WHITE
+    );
+}
+
+#[test]
+fn shade_hit_with_a_spot_light() {
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let shade = |light: SpotLight| {
+        let w = World::with_lights(vec![light.into()]);
+        let i = Intersection::new(4.0, &w.objects().unwrap()[0]);
+        w.shade_hit(&i.prepare_computations(&r, None), MAX_REFLECTION_DEPTH)
+    };
+    // Pointing at the sphere, the spot light behaves like a point light.
+    let towards_the_sphere = shade(SpotLight::new(
+        Point::new(-10.0, 10.0, -10.0),
+        &Vector::new(1.0, -1.0, 1.0),
+        PI / 8.0,
+        PI / 4.0,
+        WHITE,
+    ));
+    assert_eq!(towards_the_sphere, Color::new(0.38066, 0.47583, 0.2855));
+    // Pointing away from the sphere, only the ambient contribution remains.
+    let away_from_the_sphere = shade(SpotLight::new(
+        Point::new(-10.0, 10.0, -10.0),
+        &Vector::new(-1.0, 1.0, -1.0),
+        PI / 8.0,
+        PI / 4.0,
+        WHITE,
+    ));
+    assert_eq!(away_from_the_sphere, Color::new(0.08, 0.1, 0.06));
+}
+
+#[test]
+fn objects_that_do_not_cast_shadows_let_the_light_through() {
+    let mut w = World::default();
+    for object in w.objects_mut().unwrap() {
+        object.set_casts_shadow(false);
+    }
+    let light_position = Point::new(-10.0, 10.0, -10.0);
+    let p = Point::new(10.0, -10.0, 10.0);
+    let sample = LightSample::towards(&p, &light_position, WHITE);
+    assert_eq!(w.light_transmission(&sample, &p), WHITE);
+}
+
+#[test]
+fn objects_cast_shadows_by_default() {
+    let mut s = Sphere::default();
+    assert!(s.casts_shadow());
+    s.set_casts_shadow(false);
+    assert!(!s.casts_shadow());
+    assert_eq!(s.material(), &Material::default());
+}
+
+#[test]
+fn objects_sharing_a_material_choose_their_shadows_separately() {
+    let mut w = World::default();
+    let material = w.objects().unwrap()[0].material().clone();
+    w.objects_mut().unwrap()[1].set_material(material);
+    w.objects_mut().unwrap()[0].set_casts_shadow(false);
+    let light_position = Point::new(-10.0, 10.0, -10.0);
+    let p = Point::new(10.0, -10.0, 10.0);
+    let sample = LightSample::towards(&p, &light_position, WHITE);
+    // The inner sphere still blocks the light.
+    assert_eq!(w.light_transmission(&sample, &p), BLACK);
+    w.objects_mut().unwrap()[1].set_casts_shadow(false);
+    assert_eq!(w.light_transmission(&sample, &p), WHITE);
+}
+
+#[test]
+fn children_of_a_group_that_does_not_cast_shadows_let_the_light_through() {
+    let group = Group::new(Matrix::<4>::identity(), vec![Sphere::default().into()]);
+    let mut w = World::with_objects(Some(vec![group.into()]));
+    let p = Point::new(0.0, -10.0, 0.0);
+    let sample = LightSample::towards(&p, &Point::new(0.0, 10.0, 0.0), WHITE);
+    assert_eq!(w.light_transmission(&sample, &p), BLACK);
+    w.objects_mut().unwrap()[0].set_casts_shadow(false);
+    assert_eq!(w.light_transmission(&sample, &p), WHITE);
+}
+
+#[test]
+fn transparent_objects_cast_partial_shadows() {
+    let mut glass = Sphere::glassy();
+    glass.material_mut().set_transparency(0.8);
+    let w = World::new(
+        Some(vec![glass.into()]),
+        vec![PointLight::new(Point::new(0.0, 10.0, 0.0), WHITE).into()],
+    );
+    let p = Point::new(0.0, -5.0, 0.0);
+    let light = &w.lights()[0];
+    // The shadow ray enters and leaves the sphere, but the light is only filtered once.
+    assert_eq!(w.light_intensity_at(light, &p), Color::new(0.8, 0.8, 0.8));
+}
+
+#[test]
+fn the_light_is_filtered_by_every_transparent_occluder() {
+    let mut first = Sphere::glassy();
+    first.material_mut().set_transparency(0.5);
+    let mut second = Sphere::glassy();
+    second.material_mut().set_transparency(0.5);
+    second.set_transform(Matrix::<4>::translation(0.0, 3.0, 0.0));
+    let w = World::new(
+        Some(vec![first.into(), second.into()]),
+        vec![PointLight::new(Point::new(0.0, 10.0, 0.0), WHITE).into()],
+    );
+    let p = Point::new(0.0, -5.0, 0.0);
+    assert_eq!(
+        w.light_intensity_at(&w.lights()[0], &p),
+        Color::new(0.25, 0.25, 0.25)
+    );
+}
+
+#[test]
+fn an_opaque_occluder_blocks_the_light_behind_a_transparent_one() {
+    let glass = Sphere::glassy();
+    let opaque = Sphere::with_transform(Matrix::<4>::translation(0.0, 3.0, 0.0));
+    let w = World::new(
+        Some(vec![glass.into(), opaque.into()]),
+        vec![PointLight::new(Point::new(0.0, 10.0, 0.0), WHITE).into()],
+    );
+    let p = Point::new(0.0, -5.0, 0.0);
+    assert_eq!(w.light_intensity_at(&w.lights()[0], &p), BLACK);
+}
+
+#[test]
+fn colored_shadows_are_tinted_by_transparent_occluders() {
+    let mut glass = Sphere::glassy();
+    glass.material_mut().set_transparency(0.5);
+    glass.material_mut().set_color(Color::new(1.0, 0.2, 0.2));
+    let mut w = World::new(
+        Some(vec![glass.into()]),
+        vec![PointLight::new(Point::new(0.0, 10.0, 0.0), WHITE).into()],
+    );
+    let p = Point::new(0.0, -5.0, 0.0);
+    assert!(!w.colored_shadows());
+    assert_eq!(
+        w.light_intensity_at(&w.lights()[0], &p),
+        Color::new(0.5, 0.5, 0.5)
+    );
+    w.set_colored_shadows(true);
+    assert_eq!(
+        w.light_intensity_at(&w.lights()[0], &p),
+        Color::new(0.5, 0.1, 0.1)
+    );
 }
 
 #[test]
@@ -173,10 +503,7 @@ fn shade_hit_is_given_an_intersection_in_shadow() {
     );
     let w = World::new(
         Some(vec![s1.into(), s2.into()]),
-        Some(PointLight::new(
-            Point::new(0.0, 0.0, -10.0),
-            Color::new(1.0, 1.0, 1.0),
-        )),
+        vec![PointLight::new(Point::new(0.0, 0.0, -10.0), Color::new(1.0, 1.0, 1.0)).into()],
     );
     let r = Ray::new(Point::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, 1.0));
     // Intersection with s2 in the world
@@ -241,10 +568,11 @@ fn shade_hit_with_a_reflective_material() {
 #[test]
 fn color_at_with_mutually_reflective_surfaces() {
     let mut w = World::default();
-    w.set_light(PointLight::new(
+    w.set_lights(vec![PointLight::new(
         Point::new(0.0, 0.0, 0.0),
         Color::new(1.0, 1.0, 1.0),
-    ));
+    )
+    .into()]);
     let mut lower = Plane::default();
     lower.material_mut().set_reflective(1.0);
     lower.set_transform(Matrix::translation(0.0, -1.0, 0.0));
@@ -397,7 +725,13 @@ fn shade_hit_with_a_transparent_material() {
     )]);
     let comps = xs[0].prepare_computations(&r, Some(&xs));
     let color = w.shade_hit(&comps, 5);
-    assert_eq!(color, Color::new(0.93642, 0.68642, 0.68642));
+    // Values from the book, where the transparent floor casts a full shadow on the ball
+    // assert_eq!(color, Color::new(0.93642, 0.68642, 0.68642));
+    // Values computed by this implementation, where half of the light goes through the floor
+    assert_eq!(
+        color,
+        Color::new(1.1254654220575104, 0.6864250822069577, 0.6864250822069577)
+    );
 }
 
 #[test]
@@ -426,5 +760,31 @@ fn shade_hit_with_a_reflective_transparent_material() {
     )]);
     let comps = xs[0].prepare_computations(&r, Some(&xs));
     let color = w.shade_hit(&comps, 5);
-    assert_eq!(color, Color::new(0.93391, 0.69643, 0.69243));
+    // Values from the book, where the transparent floor casts a full shadow on the ball
+    // assert_eq!(color, Color::new(0.93391, 0.69643, 0.69243));
+    // Values computed by this implementation, where half of the light goes through the floor
+    assert_eq!(
+        color,
+        Color::new(1.115002451456782, 0.696434003918285, 0.6924304352337541)
+    );
+}
+
+#[test]
+fn the_pattern_of_an_object_follows_the_transform_of_its_group() {
+    let mut material = Material::default();
+    material.set_pattern(Pattern::stripe(WHITE, BLACK, None));
+    material.set_ambient(1.0);
+    material.set_diffuse(0.0);
+    material.set_specular(0.0);
+    let group = Group::new(
+        Matrix::<4>::translation(1.0, 0.0, 0.0),
+        vec![Sphere::with_material(material).into()],
+    );
+    let w = World::new(
+        Some(vec![group.into()]),
+        vec![PointLight::new(Point::new(-10.0, 10.0, -10.0), WHITE).into()],
+    );
+    // The hit is at x = 1.5 in world space, but at x = 0.5 in the space of the sphere.
+    let r = Ray::new(Point::new(1.5, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    assert_eq!(w.color_at(&r, MAX_REFLECTION_DEPTH), WHITE);
 }
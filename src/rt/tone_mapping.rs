@@ -0,0 +1,92 @@
+use super::color::Color;
+
+/// How colors brighter than 1.0 are brought into the range that images can display.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ToneMapping {
+    /// Leaves colors unchanged: integer formats clip them to [0, 1], float formats keep them.
+    #[default]
+    None,
+    /// Clips each channel to [0, 1], in every format.
+    Clamp,
+    /// Maps the luminance L to L / (1 + L), which compresses highlights smoothly but never reaches white.
+    Reinhard,
+    /// Reinhard operator that maps the luminance `white` (and anything brighter) to white.
+    ExtendedReinhard(WhiteLuminance),
+    /// Krzysztof Narkowicz's fit of the ACES filmic curve, applied to each channel: contrasted, with
+    /// highlights that roll off to white.
+    AcesFilmic,
+}
+
+impl ToneMapping {
+    /// Creates an extended Reinhard operator that maps the luminance `white` (and anything brighter) to
+    /// white.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `white` is not finite and positive.
+    pub fn extended_reinhard(white: f64) -> Self {
+        Self::ExtendedReinhard(WhiteLuminance::new(white))
+    }
+
+    pub fn apply(self, color: Color) -> Color {
+        match self {
+            Self::None => color,
+            Self::Clamp => Self::per_channel(color, |value| value.clamp(0.0, 1.0)),
+            Self::Reinhard => {
+                Self::scale_luminance(color, |luminance| luminance / (1.0 + luminance))
+            }
+            Self::ExtendedReinhard(white) => Self::scale_luminance(color, |luminance| {
+                let white = white.value();
+                (luminance * (1.0 + luminance / (white * white)) / (1.0 + luminance)).min(1.0)
+            }),
+            Self::AcesFilmic => Self::per_channel(color, |value| {
+                let value = value.max(0.0);
+                (value * value.mul_add(2.51, 0.03) / value.mul_add(value.mul_add(2.43, 0.59), 0.14))
+                    .clamp(0.0, 1.0)
+            }),
+        }
+    }
+
+    fn per_channel(color: Color, map: impl Fn(f64) -> f64) -> Color {
+        Color::new(map(color.red()), map(color.green()), map(color.blue()))
+    }
+
+    /// Scales `color` so that its luminance becomes `map(luminance)`, which keeps its hue.
+    fn scale_luminance(color: Color, map: impl Fn(f64) -> f64) -> Color {
+        let luminance = luminance(&color);
+        if luminance <= 0.0 {
+            return color;
+        }
+        color * (map(luminance) / luminance)
+    }
+}
+
+/// Luminance mapped to white by `ToneMapping::ExtendedReinhard`, checked when it is created so that the
+/// operator never divides by 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WhiteLuminance(f64);
+
+impl WhiteLuminance {
+    /// # Panics
+    ///
+    /// Will panic if `white` is not finite and positive.
+    pub fn new(white: f64) -> Self {
+        assert!(
+            white.is_finite() && white > 0.0,
+            "The white luminance must be finite and positive"
+        );
+        Self(white)
+    }
+
+    pub const fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+/// Relative luminance of a linear color, with the Rec. 709 (sRGB) primaries.
+pub fn luminance(color: &Color) -> f64 {
+    0.0722_f64.mul_add(
+        color.blue(),
+        0.2126_f64.mul_add(color.red(), 0.7152 * color.green()),
+    )
+}
@@ -0,0 +1,110 @@
+use {
+    super::{
+        bounding_box::BoundingBox, intersection::Intersection, material::Material, matrix::Matrix,
+        shape::Shape,
+    },
+    crate::tuple::{point::Point, vector::Vector},
+};
+
+/// A flat triangle defined by its three vertices. Since it has no curvature,
+/// the edges and normal are computed once, when the triangle is created.
+#[derive(PartialEq, Debug)]
+pub struct Triangle {
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    e1: Vector,
+    e2: Vector,
+    normal: Vector,
+    transform: Matrix<4>,
+    material: Material,
+    casts_shadow: bool,
+}
+
+impl Triangle {
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Self {
+        let e1 = &p2 - &p1;
+        let e2 = &p3 - &p1;
+        let normal = e2.cross(&e1).normalized();
+
+        Self {
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal,
+            transform: Matrix::<4>::identity(),
+            material: Material::default(),
+            casts_shadow: true,
+        }
+    }
+
+    pub const fn p1(&self) -> &Point {
+        &self.p1
+    }
+
+    pub const fn p2(&self) -> &Point {
+        &self.p2
+    }
+
+    pub const fn p3(&self) -> &Point {
+        &self.p3
+    }
+
+    pub const fn e1(&self) -> &Vector {
+        &self.e1
+    }
+
+    pub const fn e2(&self) -> &Vector {
+        &self.e2
+    }
+
+    pub const fn normal(&self) -> &Vector {
+        &self.normal
+    }
+}
+
+impl Shape for Triangle {
+    fn transform(&self) -> &Matrix<4> {
+        &self.transform
+    }
+
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn material_mut(&mut self) -> &mut Material {
+        &mut self.material
+    }
+
+    fn set_transform(&mut self, transform: Matrix<4>) {
+        self.transform = transform;
+    }
+
+    fn casts_shadow(&self) -> bool {
+        self.casts_shadow
+    }
+
+    fn set_casts_shadow(&mut self, casts_shadow: bool) {
+        self.casts_shadow = casts_shadow;
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn normal_at(&self, _: &Point, _: &Intersection) -> Vector {
+        let world_normal = self.transform.inverse().unwrap().transpose() * &self.normal;
+
+        world_normal.normalized()
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        for point in [&self.p1, &self.p2, &self.p3] {
+            bounds.add_point(point);
+        }
+        bounds
+    }
+}
@@ -0,0 +1,96 @@
+use {
+    crate::{approx_eq::ApproxEq, tuple::point::Point},
+    std::f64::consts::PI,
+};
+
+/// How a point on the surface of an object is converted to (`u`, `v`) texture coordinates, both in [0, 1].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UvMapping {
+    /// Wraps the texture around a unit sphere, like a map of the earth.
+    Spherical,
+    /// Tiles the texture on the xz plane, repeating it every unit.
+    Planar,
+    /// Wraps the texture around a unit cylinder, repeating it every unit along y.
+    Cylindrical,
+}
+
+impl UvMapping {
+    pub fn map(self, point: &Point) -> (f64, f64) {
+        match self {
+            Self::Spherical => Self::spherical(point),
+            Self::Planar => Self::planar(point),
+            Self::Cylindrical => Self::cylindrical(point),
+        }
+    }
+
+    fn spherical(point: &Point) -> (f64, f64) {
+        // The azimuthal angle, in (-π, π], increasing clockwise when viewed from above.
+        let theta = point.x().atan2(point.z());
+        let radius = (point.x() * point.x() + point.y() * point.y() + point.z() * point.z()).sqrt();
+        // The polar angle, in [0, π].
+        let phi = (point.y() / radius).acos();
+        let raw_u = theta / (2.0 * PI);
+        // Flip u so that it increases counterclockwise when viewed from above.
+        let u = 1.0 - (raw_u + 0.5);
+        // Flip v so that 0 is the south pole and 1 the north pole.
+        let v = 1.0 - phi / PI;
+        (u, v)
+    }
+
+    fn planar(point: &Point) -> (f64, f64) {
+        (point.x().rem_euclid(1.0), point.z().rem_euclid(1.0))
+    }
+
+    fn cylindrical(point: &Point) -> (f64, f64) {
+        let theta = point.x().atan2(point.z());
+        let raw_u = theta / (2.0 * PI);
+        let u = 1.0 - (raw_u + 0.5);
+        (u, point.y().rem_euclid(1.0))
+    }
+}
+
+/// The faces of a cube, used to texture each face of a unit cube with its own pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CubeFace {
+    Left,
+    Front,
+    Right,
+    Back,
+    Up,
+    Down,
+}
+
+impl CubeFace {
+    /// Returns the face of the unit cube `point` belongs to, i.e. the one along its largest coordinate.
+    pub fn of(point: &Point) -> Self {
+        let (x, y, z) = (point.x(), point.y(), point.z());
+        let coord = x.abs().max(y.abs()).max(z.abs());
+        if coord.approx_eq(x) {
+            Self::Right
+        } else if coord.approx_eq(-x) {
+            Self::Left
+        } else if coord.approx_eq(y) {
+            Self::Up
+        } else if coord.approx_eq(-y) {
+            Self::Down
+        } else if coord.approx_eq(z) {
+            Self::Front
+        } else {
+            Self::Back
+        }
+    }
+
+    /// Maps `point` to texture coordinates on this face, (0, 0) being the bottom left corner of the face when
+    /// looking at it from outside the cube, with the up face's top edge towards -z and the down face's towards +z.
+    pub fn uv(self, point: &Point) -> (f64, f64) {
+        let (across, upward) = match self {
+            Self::Front => (point.x() + 1.0, point.y() + 1.0),
+            Self::Back => (1.0 - point.x(), point.y() + 1.0),
+            Self::Left => (point.z() + 1.0, point.y() + 1.0),
+            Self::Right => (1.0 - point.z(), point.y() + 1.0),
+            Self::Up => (point.x() + 1.0, 1.0 - point.z()),
+            Self::Down => (point.x() + 1.0, point.z() + 1.0),
+        };
+        (across.rem_euclid(2.0) / 2.0, upward.rem_euclid(2.0) / 2.0)
+    }
+}
@@ -0,0 +1,127 @@
+use super::{
+    canvas::Canvas,
+    color::{Color, BLACK},
+};
+
+/// How a texture image is sampled between the centers of its pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextureFilter {
+    /// Takes the color of the closest pixel.
+    #[default]
+    Nearest,
+    /// Interpolates linearly between the four closest pixels.
+    Bilinear,
+}
+
+/// A 2D pattern, evaluated at (`u`, `v`) texture coordinates in [0, 1].
+/// `v` goes upward, so (0, 0) is the bottom left corner of the texture.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UvPattern {
+    /// A checkerboard with `width` squares along u and `height` squares along v.
+    Checkers {
+        width: f64,
+        height: f64,
+        a: Color,
+        b: Color,
+    },
+    /// A `main` color, with a different color in each corner.
+    /// Useful to check how a texture is oriented on a surface.
+    AlignCheck {
+        main: Color,
+        upper_left: Color,
+        upper_right: Color,
+        bottom_left: Color,
+        bottom_right: Color,
+    },
+    /// An image, whose top left pixel is at (0, 1). An empty image is black everywhere.
+    Image {
+        canvas: Canvas,
+        filter: TextureFilter,
+    },
+}
+
+impl UvPattern {
+    pub const fn checkers(width: f64, height: f64, a: Color, b: Color) -> Self {
+        Self::Checkers {
+            width,
+            height,
+            a,
+            b,
+        }
+    }
+
+    pub const fn image(canvas: Canvas, filter: TextureFilter) -> Self {
+        Self::Image { canvas, filter }
+    }
+
+    pub fn at(&self, u: f64, v: f64) -> Color {
+        match self {
+            Self::Checkers {
+                width,
+                height,
+                a,
+                b,
+            } => {
+                if ((u * width).floor() + (v * height).floor()).rem_euclid(2.0) == 0.0 {
+                    *a
+                } else {
+                    *b
+                }
+            }
+            Self::AlignCheck {
+                main,
+                upper_left,
+                upper_right,
+                bottom_left,
+                bottom_right,
+            } => match (u, v) {
+                (u, v) if u < 0.2 && v > 0.8 => *upper_left,
+                (u, v) if u > 0.8 && v > 0.8 => *upper_right,
+                (u, v) if u < 0.2 && v < 0.2 => *bottom_left,
+                (u, v) if u > 0.8 && v < 0.2 => *bottom_right,
+                _ => *main,
+            },
+            Self::Image { canvas, filter } => Self::sample_image(canvas, *filter, u, v),
+        }
+    }
+
+    fn sample_image(canvas: &Canvas, filter: TextureFilter, u: f64, v: f64) -> Color {
+        let (Some(max_x), Some(max_y)) = (
+            Self::last_texel(canvas.width()),
+            Self::last_texel(canvas.height()),
+        ) else {
+            return BLACK;
+        };
+        let (max_x, max_y) = (f64::from(max_x), f64::from(max_y));
+        // Flip v, since the rows of the canvas go downward.
+        let x = u.clamp(0.0, 1.0) * max_x;
+        let y = (1.0 - v.clamp(0.0, 1.0)) * max_y;
+        match filter {
+            TextureFilter::Nearest => Self::texel(canvas, x.round(), y.round()),
+            TextureFilter::Bilinear => {
+                let (x0, y0) = (x.floor(), y.floor());
+                let (x1, y1) = ((x0 + 1.0).min(max_x), (y0 + 1.0).min(max_y));
+                let (tx, ty) = (x - x0, y - y0);
+                let top =
+                    Self::texel(canvas, x0, y0) * (1.0 - tx) + Self::texel(canvas, x1, y0) * tx;
+                let bottom =
+                    Self::texel(canvas, x0, y1) * (1.0 - tx) + Self::texel(canvas, x1, y1) * tx;
+                top * (1.0 - ty) + bottom * ty
+            }
+        }
+    }
+
+    /// Returns the index of the last texel along a side of `size` texels, or `None` if the side is empty
+    /// (or has more than `u32::MAX` texels).
+    fn last_texel(size: usize) -> Option<u32> {
+        size.checked_sub(1)
+            .and_then(|last| u32::try_from(last).ok())
+    }
+
+    /// Returns the pixel of `canvas` at (`x`, `y`), which must be whole numbers within the canvas.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn texel(canvas: &Canvas, x: f64, y: f64) -> Color {
+        // The coordinates are clamped to [0, last texel] and rounded by the caller, so the casts are exact.
+        *canvas.pixel_at(x as usize, y as usize)
+    }
+}
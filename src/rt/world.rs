@@ -1,16 +1,22 @@
-use crate::{
-    rt::{
-        color::{Color, BLACK, WHITE},
-        computation::Computation,
-        material::Material,
-        matrix::Matrix,
-        object::Object,
-        point_light::PointLight,
-        ray::{Intersect, Ray},
-        shape::Shape,
-        sphere::Sphere,
+use {
+    crate::{
+        rt::{
+            bvh::Bvh,
+            color::{Color, BLACK, WHITE},
+            computation::Computation,
+            light::Light,
+            light_source::{Illumination, LightSample, LightSource},
+            material::Material,
+            matrix::Matrix,
+            object::Object,
+            point_light::PointLight,
+            ray::{Intersect, Ray},
+            shape::Shape,
+            sphere::Sphere,
+        },
+        tuple::point::Point,
     },
-    tuple::point::Point,
+    std::{ptr, sync::OnceLock},
 };
 
 /// Maximum number of times `reflected_color` can be called before stopping
@@ -19,43 +25,61 @@ use crate::{
 /// where two surfaces reflect each other.
 pub const MAX_REFLECTION_DEPTH: u8 = 6;
 
-#[derive(PartialEq)]
 pub struct World {
     objects: Option<Vec<Object>>,
-    light: Option<PointLight>,
+    lights: Vec<Light>,
+    /// Whether light going through transparent objects is tinted by their color.
+    colored_shadows: bool,
+    /// Built on the first intersection, and reset whenever the objects are modified.
+    bvh: OnceLock<Bvh>,
+}
+
+impl PartialEq for World {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.objects == rhs.objects && self.lights == rhs.lights
+    }
 }
 
 impl World {
-    const fn default_light() -> PointLight {
-        PointLight::new(Point::new(-10.0, 10.0, -10.0), WHITE)
+    const fn default_light() -> Light {
+        Light::Point(PointLight::new(Point::new(-10.0, 10.0, -10.0), WHITE))
     }
 
     /// Creates a new world.
-    pub const fn new(objects: Option<Vec<Object>>, light: Option<PointLight>) -> Self {
-        Self { objects, light }
+    pub const fn new(objects: Option<Vec<Object>>, lights: Vec<Light>) -> Self {
+        Self {
+            objects,
+            lights,
+            colored_shadows: false,
+            bvh: OnceLock::new(),
+        }
     }
 
     /// Creates an empty world.
     pub const fn empty() -> Self {
         Self {
             objects: None,
-            light: None,
+            lights: Vec::new(),
+            colored_shadows: false,
+            bvh: OnceLock::new(),
         }
     }
 
-    /// Creates a default world with a specified light.
-    pub fn with_light(light: Option<PointLight>) -> Self {
+    /// Creates a default world with a specified set of lights.
+    pub fn with_lights(lights: Vec<Light>) -> Self {
         Self {
-            light,
+            lights,
             ..Self::default()
         }
     }
 
     /// Creates a default world with a specified set of objects.
-    pub const fn with_objects(objects: Option<Vec<Object>>) -> Self {
+    pub fn with_objects(objects: Option<Vec<Object>>) -> Self {
         Self {
             objects,
-            light: Some(Self::default_light()),
+            lights: vec![Self::default_light()],
+            colored_shadows: false,
+            bvh: OnceLock::new(),
         }
     }
 
@@ -64,18 +88,51 @@ impl World {
     }
 
     pub fn objects_mut(&mut self) -> Option<&mut [Object]> {
+        self.bvh.take();
         self.objects.as_deref_mut()
     }
 
-    pub const fn light(&self) -> Option<&PointLight> {
-        self.light.as_ref()
+    /// Returns the bounding volume hierarchy built over the objects, if there are any.
+    pub fn bvh(&self) -> Option<&Bvh> {
+        self.objects
+            .as_deref()
+            .map(|objects| self.bvh.get_or_init(|| Bvh::new(objects)))
+    }
+
+    pub fn lights(&self) -> &[Light] {
+        &self.lights
     }
 
-    pub fn set_light(&mut self, light: PointLight) {
-        self.light = Some(light);
+    pub fn lights_mut(&mut self) -> &mut [Light] {
+        &mut self.lights
+    }
+
+    /// Replaces all the lights of the world.
+    pub fn set_lights(&mut self, lights: Vec<Light>) {
+        self.lights = lights;
+    }
+
+    pub fn add_light(&mut self, light: impl Into<Light>) {
+        self.lights.push(light.into());
+    }
+
+    /// Removes the light at `index` and returns it, or returns `None` if there is no such light.
+    pub fn remove_light(&mut self, index: usize) -> Option<Light> {
+        (index < self.lights.len()).then(|| self.lights.remove(index))
+    }
+
+    pub const fn colored_shadows(&self) -> bool {
+        self.colored_shadows
+    }
+
+    /// When enabled, light going through transparent objects is tinted by their color
+    /// (e.g. a red glass sphere casts a red shadow).
+    pub fn set_colored_shadows(&mut self, colored_shadows: bool) {
+        self.colored_shadows = colored_shadows;
     }
 
     pub fn add_object(&mut self, object: Object) {
+        self.bvh.take();
         if let Some(objects) = &mut self.objects {
             objects.push(object);
         } else {
@@ -137,18 +194,23 @@ impl World {
     }
 
     pub fn shade_hit(&self, computations: &Computation, remaining_calls: u8) -> Color {
-        let surface = computations.intersection().object().material().lighting(
-            computations.intersection().object(),
-            self.light.as_ref().expect("World should have a light!"),
-            computations.over_point(),
-            computations.eye_vector(),
-            computations.normal_vector(),
-            self.is_shadowed(computations.over_point()),
-        );
+        // Each light contributes to the color of the surface, with its own shadows.
+        let hit = computations.intersection();
+        let material = hit.object().material();
+        let color = material.color_at_intersection(hit, computations.over_point());
+        let surface = self.lights.iter().fold(BLACK, |surface, light| {
+            surface
+                + material.lighting_with(
+                    &color,
+                    light,
+                    computations.eye_vector(),
+                    computations.normal_vector(),
+                    &self.illumination(light, computations.over_point()),
+                )
+        });
         let reflected = self.reflected_color(computations, remaining_calls);
         let refracted = self.refracted_color(computations, remaining_calls);
 
-        let material = computations.intersection().object().material();
         if material.reflective() > 0.0 && material.transparency() > 0.0 {
             let reflectance = computations.schlick();
             surface + reflected * reflectance + refracted * (1.0 - reflectance)
@@ -157,22 +219,66 @@ impl World {
         }
     }
 
-    pub fn is_shadowed(&self, point: &Point) -> bool {
-        let point_to_light = self
-            .light
-            .as_ref()
-            .expect("This world has no light!")
-            .position()
-            - point;
-        let distance = point_to_light.magnitude();
-        let direction = point_to_light.normalized();
-        let ray = Ray::new(point.clone(), direction);
-        if let Some(intersections) = ray.intersect(self) {
-            if let Some(hit) = intersections.hit() {
-                return hit.t() < distance;
+    /// Returns the fraction of the light of `light` that reaches `point` on each channel, averaged over its samples,
+    /// from `BLACK` when `point` is completely in shadow to `WHITE` when it is fully lit.
+    pub fn light_intensity_at(&self, light: &Light, point: &Point) -> Color {
+        *self.illumination(light, point).transmission()
+    }
+
+    /// Returns the samples of `light` at `point`, with the fraction of their light that reaches `point`
+    /// (see `light_intensity_at`), so that shading does not compute the samples again.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `light` has more than `u32::MAX` samples.
+    pub fn illumination(&self, light: &Light, point: &Point) -> Illumination {
+        let samples = light.samples(point);
+        let sample_count = u32::try_from(samples.len()).expect("Too many light samples");
+        let transmission = samples.iter().fold(BLACK, |intensity, sample| {
+            intensity + self.light_transmission(sample, point)
+        }) * (1.0 / f64::from(sample_count));
+        Illumination::new(samples, transmission)
+    }
+
+    /// Returns the fraction of the light `sample` that reaches `point` on each channel.
+    ///
+    /// Objects that do not cast shadows (or belong to a group that does not) are ignored. Every other object between `point` and the light
+    /// lets through a fraction of the light equal to its transparency (tinted by its color if colored shadows are
+    /// enabled), so opaque objects block the light completely.
+    pub fn light_transmission(&self, sample: &LightSample, point: &Point) -> Color {
+        let ray = Ray::new(point.clone(), sample.direction().clone());
+        let Some(intersections) = ray.intersect(self) else {
+            return WHITE;
+        };
+        // Objects are usually intersected twice (entering and leaving them),
+        // but the light must only be filtered once by each of them.
+        let mut occluders: Vec<&Object> = Vec::new();
+        let mut transmission = WHITE;
+        for intersection in intersections
+            .iter()
+            .filter(|i| i.t() > 0.0 && i.t() < sample.distance())
+        {
+            let object = intersection.object();
+            let casts_shadow = object.casts_shadow()
+                && intersection
+                    .parents()
+                    .iter()
+                    .all(|parent| parent.casts_shadow());
+            if !casts_shadow || occluders.iter().any(|o| ptr::eq(*o, object)) {
+                continue;
+            }
+            let material = object.material();
+            if material.transparency() == 0.0 {
+                return BLACK;
+            }
+            transmission = transmission * material.transparency();
+            if self.colored_shadows {
+                transmission = transmission
+                    * material.color_at_intersection(intersection, &ray.position(intersection.t()));
             }
+            occluders.push(object);
         }
-        false
+        transmission
     }
 
     pub fn reflected_color(&self, computations: &Computation, remaining_calls: u8) -> Color {
@@ -210,7 +316,9 @@ impl Default for World {
 
         Self {
             objects: Some(vec![s1.into(), s2.into()]),
-            light: Some(Self::default_light()),
+            lights: vec![Self::default_light()],
+            colored_shadows: false,
+            bvh: OnceLock::new(),
         }
     }
 }
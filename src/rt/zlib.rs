@@ -0,0 +1,270 @@
+/// How a zlib stream stores its data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    /// Uncompressed (stored) deflate blocks: fast, but the output is slightly larger than the input.
+    Stored,
+    /// A deflate block using LZ77 matching and the fixed Huffman codes.
+    #[default]
+    Deflate,
+}
+
+/// Largest amount of data in a stored deflate block.
+const MAX_STORED_BLOCK: usize = u16::MAX as usize;
+/// Size of the LZ77 window: matches can refer to data up to this many bytes back.
+const WINDOW_SIZE: usize = 1 << 15;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+/// Number of earlier positions with the same hash that are tried when looking for a match.
+const MAX_CHAIN: usize = 64;
+const HASH_BITS: u32 = 15;
+
+const LENGTH_BASES: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA_BITS: [u32; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASES: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA_BITS: [u32; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Compresses `data` into a zlib stream (RFC 1950): a two-byte header, deflate blocks (RFC 1951) and the
+/// Adler-32 checksum of `data`.
+pub fn compress(data: &[u8], compression: Compression) -> Vec<u8> {
+    let mut writer = BitWriter::with_capacity(data.len() / 2 + 16);
+    // 32K window, no preset dictionary, and a check value making the header a multiple of 31.
+    let header: [u8; 2] = match compression {
+        Compression::Stored => [0x78, 0x01],
+        Compression::Deflate => [0x78, 0x9C],
+    };
+    writer.bytes.extend_from_slice(&header);
+    match compression {
+        Compression::Stored => write_stored_blocks(&mut writer, data),
+        Compression::Deflate => write_fixed_block(&mut writer, data),
+    }
+    let mut bytes = writer.finish();
+    bytes.extend_from_slice(&adler32(data).to_be_bytes());
+    bytes
+}
+
+/// Adler-32 checksum, used by zlib streams.
+pub fn adler32(data: &[u8]) -> u32 {
+    const MODULO: u32 = 65521;
+    // 5552 is the largest number of bytes that can be summed before the sums overflow a `u32`.
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for chunk in data.chunks(5552) {
+        for byte in chunk {
+            a += u32::from(*byte);
+            b += a;
+        }
+        a %= MODULO;
+        b %= MODULO;
+    }
+    b << 16 | a
+}
+
+fn write_stored_blocks(writer: &mut BitWriter, data: &[u8]) {
+    // Even empty data needs a final (empty) block.
+    let block_count = data.len().div_ceil(MAX_STORED_BLOCK).max(1);
+    for index in 0..block_count {
+        let start = index * MAX_STORED_BLOCK;
+        let block = &data[start..data.len().min(start + MAX_STORED_BLOCK)];
+        writer.write_bits(u32::from(index == block_count - 1), 1);
+        writer.write_bits(0b00, 2);
+        writer.align();
+        let length = u16::try_from(block.len()).expect("Stored blocks are at most 65535 bytes");
+        writer.bytes.extend_from_slice(&length.to_le_bytes());
+        writer.bytes.extend_from_slice(&(!length).to_le_bytes());
+        writer.bytes.extend_from_slice(block);
+    }
+}
+
+/// Writes `data` as a single final block compressed with the fixed Huffman codes.
+fn write_fixed_block(writer: &mut BitWriter, data: &[u8]) {
+    writer.write_bits(1, 1);
+    writer.write_bits(0b01, 2);
+
+    let mut matcher = Matcher::new();
+    let mut position = 0;
+    while position < data.len() {
+        if let Some((length, distance)) = matcher.longest_match(data, position) {
+            write_length(writer, length);
+            write_distance(writer, distance);
+            for offset in 0..length {
+                matcher.insert(data, position + offset);
+            }
+            position += length;
+        } else {
+            write_literal(writer, u16::from(data[position]));
+            matcher.insert(data, position);
+            position += 1;
+        }
+    }
+    write_literal(writer, 256);
+}
+
+/// Writes a literal/length symbol with its fixed Huffman code.
+fn write_literal(writer: &mut BitWriter, symbol: u16) {
+    let symbol = u32::from(symbol);
+    let (code, length) = match symbol {
+        0..=143 => (0x30 + symbol, 8),
+        144..=255 => (0x190 + symbol - 144, 9),
+        256..=279 => (symbol - 256, 7),
+        _ => (0xC0 + symbol - 280, 8),
+    };
+    writer.write_huffman(code, length);
+}
+
+fn write_length(writer: &mut BitWriter, length: usize) {
+    let index = LENGTH_BASES
+        .iter()
+        .rposition(|base| usize::from(*base) <= length)
+        .expect("Match lengths are at least 3");
+    write_literal(
+        writer,
+        257 + u16::try_from(index).expect("There are 29 length codes"),
+    );
+    writer.write_bits(
+        u32::try_from(length - usize::from(LENGTH_BASES[index]))
+            .expect("Length extra bits are at most 5 bits"),
+        LENGTH_EXTRA_BITS[index],
+    );
+}
+
+fn write_distance(writer: &mut BitWriter, distance: usize) {
+    let index = DISTANCE_BASES
+        .iter()
+        .rposition(|base| usize::from(*base) <= distance)
+        .expect("Match distances are at least 1");
+    // Distance codes are all 5 bits long with the fixed Huffman codes.
+    writer.write_huffman(
+        u32::try_from(index).expect("There are 30 distance codes"),
+        5,
+    );
+    writer.write_bits(
+        u32::try_from(distance - usize::from(DISTANCE_BASES[index]))
+            .expect("Distance extra bits are at most 13 bits"),
+        DISTANCE_EXTRA_BITS[index],
+    );
+}
+
+/// Finds LZ77 matches through hash chains of the positions whose next 3 bytes have the same hash.
+struct Matcher {
+    /// Last position inserted for each hash, plus one (0 means none).
+    head: Vec<usize>,
+    /// Previous position with the same hash as each position of the window, plus one.
+    previous: Vec<usize>,
+}
+
+impl Matcher {
+    fn new() -> Self {
+        Self {
+            head: vec![0; 1 << HASH_BITS],
+            previous: vec![0; WINDOW_SIZE],
+        }
+    }
+
+    fn hash(data: &[u8], position: usize) -> usize {
+        let bytes = u32::from(data[position]) << 16
+            | u32::from(data[position + 1]) << 8
+            | u32::from(data[position + 2]);
+        (bytes.wrapping_mul(0x9E37_79B1) >> (32 - HASH_BITS)) as usize
+    }
+
+    fn insert(&mut self, data: &[u8], position: usize) {
+        if position + MIN_MATCH > data.len() {
+            return;
+        }
+        let hash = Self::hash(data, position);
+        self.previous[position % WINDOW_SIZE] = self.head[hash];
+        self.head[hash] = position + 1;
+    }
+
+    /// Returns the length and distance of the longest match for the data at `position`, if any.
+    fn longest_match(&self, data: &[u8], position: usize) -> Option<(usize, usize)> {
+        if position + MIN_MATCH > data.len() {
+            return None;
+        }
+        let max_length = MAX_MATCH.min(data.len() - position);
+        let mut best: Option<(usize, usize)> = None;
+        let mut candidate = self.head[Self::hash(data, position)];
+        for _ in 0..MAX_CHAIN {
+            let Some(start) = candidate.checked_sub(1) else {
+                break;
+            };
+            let distance = position - start;
+            if distance > WINDOW_SIZE {
+                break;
+            }
+            let length = data[start..]
+                .iter()
+                .zip(&data[position..position + max_length])
+                .take_while(|(a, b)| a == b)
+                .count();
+            if length >= MIN_MATCH && best.is_none_or(|(best_length, _)| length > best_length) {
+                best = Some((length, distance));
+                if length == max_length {
+                    break;
+                }
+            }
+            let next = self.previous[start % WINDOW_SIZE];
+            // Older positions of the window have been overwritten by newer ones.
+            if next >= candidate {
+                break;
+            }
+            candidate = next;
+        }
+        best
+    }
+}
+
+/// Writes bits least significant first, as deflate streams expect.
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(capacity),
+            buffer: 0,
+            count: 0,
+        }
+    }
+
+    fn write_bits(&mut self, value: u32, count: u32) {
+        self.buffer |= u64::from(value) << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            // Only the lowest byte of the buffer is complete.
+            self.bytes.push(self.buffer.to_le_bytes()[0]);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Huffman codes are stored most significant bit first.
+    fn write_huffman(&mut self, code: u32, length: u32) {
+        self.write_bits(code.reverse_bits() >> (32 - length), length);
+    }
+
+    /// Pads the current byte with zeros.
+    fn align(&mut self) {
+        if self.count > 0 {
+            self.write_bits(0, 8 - self.count);
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        self.align();
+        self.bytes
+    }
+}